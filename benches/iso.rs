@@ -0,0 +1,94 @@
+//! Benchmarks for the ISO date records.
+//!
+//! Run with `cargo bench --bench iso`.
+//!
+//! The `pre-patch` benchmarks run a copy of the implementation an optimization
+//! replaced, so the speedup is reported by a single run. To compare against an
+//! older commit instead, run `cargo bench --bench iso -- --save-baseline before` on
+//! that commit, then `cargo bench --bench iso -- --baseline before` on this one.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use temporal_rs::{
+    iso::{balance_iso_year_month, IsoDate, IsoDateTime, IsoTime, PartialIsoDateTime},
+    options::{ArithmeticOverflow, Unit},
+    DateDuration, PlainDate, TemporalResult,
+};
+
+/// The day and week paths of `IsoDate::until` before the epoch days fast path,
+/// which balanced and regulated the start date before taking the difference in
+/// epoch days.
+fn pre_patch_until(
+    one: &IsoDate,
+    other: &IsoDate,
+    largest_unit: Unit,
+) -> TemporalResult<DateDuration> {
+    if one == other {
+        return Ok(DateDuration::default());
+    }
+    let (year, month) = balance_iso_year_month(one.year, i32::from(one.month));
+    let constrained = PartialIsoDateTime {
+        year: Some(year),
+        month: Some(month),
+        day: Some(one.day),
+        ..Default::default()
+    }
+    .to_iso_date(ArithmeticOverflow::Constrain)?;
+    let days = i64::from(other.to_epoch_days()) - i64::from(constrained.to_epoch_days());
+    let (weeks, days) = if largest_unit == Unit::Week {
+        (days / 7, days % 7)
+    } else {
+        (0, days)
+    };
+    DateDuration::new(0, 0, weeks, days)
+}
+
+/// Compares the day and week fast paths of `IsoDate::until` with the pre-patch
+/// implementation over a ten year span.
+fn date_until(c: &mut Criterion) {
+    let earlier = IsoDate::new_checked(2015, 3, 17);
+    let later = IsoDate::new_checked(2025, 8, 4);
+
+    for (name, unit) in [("day", Unit::Day), ("week", Unit::Week)] {
+        assert_eq!(
+            earlier.until(&later, unit),
+            pre_patch_until(&earlier, &later, unit)
+        );
+        let mut group = c.benchmark_group(format!("IsoDate::until ({name}, 10 years)"));
+        group.bench_function("fast path", |b| {
+            b.iter(|| black_box(&earlier).until(black_box(&later), unit))
+        });
+        group.bench_function("pre-patch", |b| {
+            b.iter(|| pre_patch_until(black_box(&earlier), black_box(&later), unit))
+        });
+        group.finish();
+    }
+
+    // The month path still goes through the candidate year and month loops.
+    c.bench_function("IsoDate::until (month, 10 years)", |b| {
+        b.iter(|| black_box(&earlier).until(black_box(&later), Unit::Month))
+    });
+}
+
+/// Compares validating a date against the `Temporal` limits directly from its epoch
+/// days, as `PlainDate::try_new_iso` does, with the epoch nanoseconds check of
+/// `IsoDateTime::new` at noon, which date validation used previously.
+fn date_limits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("date limits");
+    group.bench_function("PlainDate::try_new_iso (epoch days)", |b| {
+        b.iter(|| PlainDate::try_new_iso(black_box(2025), black_box(8), black_box(4)))
+    });
+    group.bench_function("IsoDateTime::new at noon (epoch nanoseconds)", |b| {
+        b.iter(|| {
+            IsoDateTime::new(
+                IsoDate::new_checked(black_box(2025), black_box(8), black_box(4)),
+                IsoTime::NOON,
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, date_until, date_limits);
+criterion_main!(benches);
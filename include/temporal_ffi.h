@@ -0,0 +1,166 @@
+#ifndef TEMPORAL_FFI_H
+#define TEMPORAL_FFI_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The operation succeeded.
+ */
+#define TEMPORAL_OK 0
+
+/**
+ * A value was out of range, e.g. an invalid date or a date outside of the `Temporal` limits.
+ */
+#define TEMPORAL_ERROR_RANGE -1
+
+/**
+ * A string could not be parsed.
+ */
+#define TEMPORAL_ERROR_SYNTAX -2
+
+/**
+ * A value had an invalid type.
+ */
+#define TEMPORAL_ERROR_TYPE -3
+
+/**
+ * Any other error.
+ */
+#define TEMPORAL_ERROR_GENERIC -4
+
+/**
+ * A required pointer argument was null.
+ */
+#define TEMPORAL_ERROR_NULL_POINTER -5
+
+/**
+ * The provided buffer is too small for the result.
+ */
+#define TEMPORAL_ERROR_BUFFER_TOO_SMALL -6
+
+/**
+ * A C-compatible ISO calendar date.
+ */
+typedef struct {
+  int32_t year;
+  uint8_t month;
+  uint8_t day;
+} TemporalDateC;
+
+/**
+ * A C-compatible wall-clock time.
+ */
+typedef struct {
+  uint8_t hour;
+  uint8_t minute;
+  uint8_t second;
+  uint16_t millisecond;
+  uint16_t microsecond;
+  uint16_t nanosecond;
+} TemporalTimeC;
+
+/**
+ * A C-compatible ISO date and time.
+ */
+typedef struct {
+  TemporalDateC date;
+  TemporalTimeC time;
+} TemporalDateTimeC;
+
+#ifdef __cplusplus
+extern "C" {
+#endif  // __cplusplus
+
+/**
+ * Creates a validated date and writes it to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `TemporalDateC`.
+ */
+int32_t temporal_date_new(int32_t year, uint8_t month, uint8_t day, TemporalDateC *out);
+
+/**
+ * Adds `days` to `date` and writes the resulting date to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `TemporalDateC`.
+ */
+int32_t temporal_date_add_days(TemporalDateC date, int64_t days, TemporalDateC *out);
+
+/**
+ * Writes the number of days from `start` until `end` to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `int64_t`.
+ */
+int32_t temporal_date_days_between(TemporalDateC start, TemporalDateC end, int64_t *out);
+
+/**
+ * Writes `date` as a NUL-terminated ISO 8601 string, e.g. `2024-03-15`, to `buffer`.
+ *
+ * The length of the string, excluding the NUL terminator, is written to `written`
+ * if it is not null, including when `capacity` is too small for the string.
+ *
+ * # Safety
+ *
+ * `buffer` must be null or valid for writes of `capacity` bytes, and `written` must
+ * be null or valid for writes of `size_t`.
+ */
+int32_t temporal_date_format(TemporalDateC date, char *buffer, size_t capacity, size_t *written);
+
+/**
+ * Parses a NUL-terminated ISO 8601 date string, e.g. `2024-03-15`, and writes the
+ * date to `out`.
+ *
+ * # Safety
+ *
+ * `source` must be null or a valid NUL-terminated string, and `out` must be null or
+ * valid for writes of `TemporalDateC`.
+ */
+int32_t temporal_date_parse(const char *source, TemporalDateC *out);
+
+/**
+ * Creates a validated time and writes it to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `TemporalTimeC`.
+ */
+int32_t temporal_time_new(uint8_t hour,
+                          uint8_t minute,
+                          uint8_t second,
+                          uint16_t millisecond,
+                          uint16_t microsecond,
+                          uint16_t nanosecond,
+                          TemporalTimeC *out);
+
+/**
+ * Writes the UTC date and time of `epoch_millis` milliseconds since the Unix epoch
+ * to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `TemporalDateTimeC`.
+ */
+int32_t temporal_datetime_from_epoch_millis(int64_t epoch_millis, TemporalDateTimeC *out);
+
+/**
+ * Writes the milliseconds since the Unix epoch of `date_time`, read as UTC, to `out`.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes of `int64_t`.
+ */
+int32_t temporal_datetime_to_epoch_millis(TemporalDateTimeC date_time, int64_t *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TEMPORAL_FFI_H */
@@ -184,16 +184,27 @@ impl Calendar {
         partial: &PartialDate,
         overflow: ArithmeticOverflow,
     ) -> TemporalResult<PlainDate> {
+        let iso = self.date_from_fields(partial, overflow)?;
+        Ok(PlainDate::new_unchecked(iso, self.clone()))
+    }
+
+    /// `CalendarDateFromFields`
+    ///
+    /// Resolves the provided fields into an `IsoDate` for this calendar.
+    pub fn date_from_fields(
+        &self,
+        fields: &PartialDate,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate> {
         let resolved_fields =
-            ResolvedCalendarFields::try_from_partial(partial, overflow, ResolutionType::Date)?;
+            ResolvedCalendarFields::try_from_partial(fields, overflow, ResolutionType::Date)?;
 
         if self.is_iso() {
             // Resolve month and monthCode;
-            return PlainDate::new_with_overflow(
+            return IsoDate::new_with_overflow(
                 resolved_fields.era_year.year,
                 resolved_fields.month_code.to_month_integer(),
                 resolved_fields.day,
-                self.clone(),
                 overflow,
             );
         }
@@ -208,15 +219,38 @@ impl Calendar {
             )
             .map_err(TemporalError::from_icu4x)?;
         let iso = self.0.to_iso(&calendar_date);
-        PlainDate::new_with_overflow(
+        IsoDate::new_with_overflow(
             Iso.extended_year(&iso),
             Iso.month(&iso).ordinal,
             Iso.day_of_month(&iso).0,
-            self.clone(),
             overflow,
         )
     }
 
+    /// `CalendarPlainMonthDayFromFields`
+    ///
+    /// Resolves the provided fields into the reference `IsoDate` of a month day.
+    pub fn month_day_from_fields(
+        &self,
+        fields: &PartialDate,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate> {
+        self.month_day_from_partial(fields, overflow)
+            .map(|month_day| month_day.iso)
+    }
+
+    /// `CalendarPlainYearMonthFromFields`
+    ///
+    /// Resolves the provided fields into the reference `IsoDate` of a year month.
+    pub fn year_month_from_fields(
+        &self,
+        fields: &PartialDate,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoDate> {
+        self.year_month_from_partial(fields, overflow)
+            .map(|year_month| year_month.iso)
+    }
+
     /// `CalendarPlainMonthDayFromFields`
     pub fn month_day_from_partial(
         &self,
@@ -632,10 +666,43 @@ impl From<PlainYearMonth> for Calendar {
 
 #[cfg(test)]
 mod tests {
-    use crate::{iso::IsoDate, options::Unit};
+    use crate::{
+        iso::IsoDate,
+        options::{ArithmeticOverflow, Unit},
+    };
     use core::str::FromStr;
 
-    use super::Calendar;
+    use super::{Calendar, PartialDate};
+
+    #[test]
+    fn date_from_fields_constrain() {
+        let fields = PartialDate {
+            year: Some(2024),
+            month: Some(2),
+            day: Some(30),
+            ..Default::default()
+        };
+        let calendar = Calendar::default();
+
+        let result = calendar
+            .date_from_fields(&fields, ArithmeticOverflow::Constrain)
+            .unwrap();
+        assert_eq!(result, IsoDate::new_unchecked(2024, 2, 29));
+
+        let _err = calendar
+            .date_from_fields(&fields, ArithmeticOverflow::Reject)
+            .unwrap_err();
+
+        let result = calendar
+            .year_month_from_fields(&fields, ArithmeticOverflow::Constrain)
+            .unwrap();
+        assert_eq!((result.year, result.month), (2024, 2));
+
+        let result = calendar
+            .month_day_from_fields(&fields, ArithmeticOverflow::Constrain)
+            .unwrap();
+        assert_eq!((result.month, result.day), (2, 29));
+    }
 
     #[test]
     fn calendar_from_str_is_case_insensitive() {
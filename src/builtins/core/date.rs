@@ -926,6 +926,54 @@ mod tests {
         assert_eq!(result.days(), 9719,);
     }
 
+    #[test]
+    fn date_until_with_built_settings() {
+        let earlier = PlainDate::from_str("1969-07-24").unwrap();
+        let later = PlainDate::from_str("1996-03-03").unwrap();
+
+        // The default settings should match a plain `diff_iso_date` by days.
+        let expected = earlier.iso.diff_iso_date(&later.iso, Unit::Day).unwrap();
+        let result = earlier
+            .until(&later, DifferenceSettings::builder().build())
+            .unwrap();
+        assert_eq!(*result.date(), expected);
+
+        let settings = DifferenceSettings::builder()
+            .with_largest_unit(Unit::Year)
+            .build();
+        let expected = earlier.iso.diff_iso_date(&later.iso, Unit::Year).unwrap();
+        let result = earlier.until(&later, settings).unwrap();
+        assert_eq!(*result.date(), expected);
+        assert_eq!(result.years(), 26);
+        assert_eq!(result.months(), 7);
+        assert_eq!(result.days(), 8);
+    }
+
+    #[test]
+    fn year_month_and_month_day_round_trip() {
+        for date in ["2024-02-29", "2023-12-31", "1970-01-01", "-000001-06-15"] {
+            let date = PlainDate::from_str(date).unwrap();
+
+            let year_month = date.to_plain_year_month().unwrap();
+            assert_eq!(year_month.iso_year(), date.iso_year());
+            assert_eq!(year_month.iso_month(), date.iso_month());
+            let result = year_month
+                .to_plain_date(Some(PartialDate::new().with_day(Some(date.day()))))
+                .unwrap();
+            assert_eq!(result, date);
+
+            // ISO month-days use 1972 as their reference year.
+            let month_day = date.to_plain_month_day().unwrap();
+            assert_eq!(month_day.iso_year(), 1972);
+            assert_eq!(month_day.month_code(), date.month_code());
+            assert_eq!(month_day.day(), date.day());
+            let result = month_day
+                .to_plain_date(Some(PartialDate::new().with_year(Some(date.year()))))
+                .unwrap();
+            assert_eq!(result, date);
+        }
+    }
+
     #[test]
     fn simple_date_since() {
         let earlier = PlainDate::from_str("1969-07-24").unwrap();
@@ -999,6 +1047,18 @@ mod tests {
         assert_eq!(with_day.day(), 17);
     }
 
+    #[test]
+    fn year_zero_round_trip() {
+        let date = PlainDate::from_str("0000-02-29").unwrap();
+        assert_eq!(date.year(), 0);
+        assert_eq!(date.month(), 2);
+        assert_eq!(date.day(), 29);
+        assert_eq!(date.to_ixdtf_string(DisplayCalendar::Auto), "0000-02-29");
+
+        let date = PlainDate::try_new_iso(9999, 12, 31).unwrap();
+        assert_eq!(date.to_ixdtf_string(DisplayCalendar::Auto), "9999-12-31");
+    }
+
     // test toZonedDateTime
     #[cfg(feature = "tzdb")]
     #[test]
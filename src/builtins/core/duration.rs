@@ -7,18 +7,24 @@ use crate::{
         ArithmeticOverflow, RelativeTo, ResolvedRoundingOptions, RoundingIncrement,
         RoundingOptions, ToStringRoundingOptions, Unit,
     },
-    parsers::{FormattableDateDuration, FormattableDuration, FormattableTimeDuration, Precision},
+    parsers::{
+        parse_iso_duration, FormattableDateDuration, FormattableDuration, FormattableTimeDuration,
+        Precision,
+    },
     primitive::FiniteF64,
     provider::TimeZoneProvider,
     temporal_assert, Sign, TemporalError, TemporalResult, TemporalUnwrap, NS_PER_DAY,
 };
-use alloc::format;
 use alloc::string::String;
-use core::{cmp::Ordering, num::NonZeroU128, str::FromStr};
-use ixdtf::parsers::{records::TimeDurationRecord, IsoDurationParser};
+use core::{
+    cmp::Ordering,
+    num::NonZeroU128,
+    ops::{Add, Sub},
+    str::FromStr,
+};
 use normalized::NormalizedDurationRecord;
 
-use self::normalized::NormalizedTimeDuration;
+pub use self::normalized::NormalizedTimeDuration;
 
 mod date;
 pub(crate) mod normalized;
@@ -70,13 +76,22 @@ impl PartialDuration {
 ///
 /// `Duration` is made up of a `DateDuration` and `TimeDuration` as primarily
 /// defined by Abtract Operation 7.5.1-5.
+///
+/// `PartialEq`, `Eq`, and `Hash` compare the fields, so `PT2H` is not equal to
+/// `PT120M`. Use [`Duration::eq_approx`] to compare durations by their value.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Duration {
     date: DateDuration,
     time: TimeDuration,
 }
 
+impl core::fmt::Debug for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Duration({self})")
+    }
+}
+
 impl core::fmt::Display for Duration {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(
@@ -169,6 +184,17 @@ impl Duration {
         self.date().fields().iter().all(|x| x == &0)
     }
 
+    /// Returns the total nanoseconds of this `Duration` with 24-hour days, or `None`
+    /// if the `Duration` has calendar units.
+    #[inline]
+    fn total_24_hour_day_nanoseconds(&self) -> Option<i128> {
+        if self.years() != 0 || self.months() != 0 || self.weeks() != 0 {
+            return None;
+        }
+        let time = NormalizedTimeDuration::from_time_duration(self.time());
+        Some(i128::from(self.days()) * i128::from(NS_PER_DAY) + time.0)
+    }
+
     /// Returns the `Unit` corresponding to the largest non-zero field.
     #[inline]
     pub(crate) fn default_largest_unit(&self) -> Unit {
@@ -184,6 +210,18 @@ impl Duration {
 // ==== Public Duration API ====
 
 impl Duration {
+    /// A zero `Duration`, i.e. `PT0S`.
+    pub const ZERO: Self = Self::new_unchecked(
+        DateDuration::new_unchecked(0, 0, 0, 0),
+        TimeDuration::new_unchecked(0, 0, 0, 0, 0, 0),
+    );
+
+    /// The largest valid `Duration` expressed in seconds, i.e. `PT9007199254740991.999999999S`.
+    pub const MAX_SAFE: Self = Self::new_unchecked(
+        DateDuration::new_unchecked(0, 0, 0, 0),
+        TimeDuration::new_unchecked(0, 0, (TWO_POWER_FIFTY_THREE - 1) as i64, 999, 999, 999),
+    );
+
     /// Creates a new validated `Duration`.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -237,6 +275,22 @@ impl Duration {
         }
     }
 
+    /// Creates a validated `Duration` from a `DateDuration` and `TimeDuration`.
+    pub fn from_parts(date: DateDuration, time: TimeDuration) -> TemporalResult<Self> {
+        Self::new(
+            date.years,
+            date.months,
+            date.weeks,
+            date.days,
+            time.hours,
+            time.minutes,
+            time.seconds,
+            time.milliseconds,
+            time.microseconds,
+            time.nanoseconds,
+        )
+    }
+
     /// Creates a `Duration` from a provided `PartialDuration`.
     pub fn from_partial_duration(partial: PartialDuration) -> TemporalResult<Self> {
         if partial == PartialDuration::default() {
@@ -257,111 +311,68 @@ impl Duration {
         )
     }
 
+    /// Parses an ISO 8601 duration string, e.g. `P1Y2M3W4DT5H6M7.89S`, into a `Duration`.
+    ///
+    /// The full grammar is supported, including a leading sign (`-P1Y`), week
+    /// designators, and a fractional value on the smallest time designator.
+    /// Malformed strings, such as `PT` or repeated or out-of-order designators,
+    /// are rejected, as are fields that exceed the maximum safe integer.
+    pub fn from_iso_duration_string(s: &str) -> TemporalResult<Self> {
+        Self::from_utf8(s.as_bytes())
+    }
+
     // Converts a UTF-8 encoded string into a `Duration`.
     pub fn from_utf8(s: &[u8]) -> TemporalResult<Self> {
-        let parse_record = IsoDurationParser::from_utf8(s)
-            .parse()
-            .map_err(|e| TemporalError::range().with_message(format!("{e}")))?;
-
-        let (hours, minutes, seconds, millis, micros, nanos) = match parse_record.time {
-            Some(TimeDurationRecord::Hours { hours, fraction }) => {
-                let unadjusted_fraction =
-                    fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0) as u64;
-                let fractional_hours_ns = unadjusted_fraction * 3600;
-                let minutes = fractional_hours_ns.div_euclid(60 * 1_000_000_000);
-                let fractional_minutes_ns = fractional_hours_ns.rem_euclid(60 * 1_000_000_000);
-
-                let seconds = fractional_minutes_ns.div_euclid(1_000_000_000);
-                let fractional_seconds = fractional_minutes_ns.rem_euclid(1_000_000_000);
-
-                let milliseconds = fractional_seconds.div_euclid(1_000_000);
-                let rem = fractional_seconds.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds,
-                    microseconds,
-                    nanoseconds,
-                )
-            }
-            // Minutes variant is defined as { hours: u32, minutes: u32, fraction: u64 }
-            Some(TimeDurationRecord::Minutes {
-                hours,
-                minutes,
-                fraction,
-            }) => {
-                let unadjusted_fraction =
-                    fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0) as u64;
-                let fractional_minutes_ns = unadjusted_fraction * 60;
-                let seconds = fractional_minutes_ns.div_euclid(1_000_000_000);
-                let fractional_seconds = fractional_minutes_ns.rem_euclid(1_000_000_000);
-
-                let milliseconds = fractional_seconds.div_euclid(1_000_000);
-                let rem = fractional_seconds.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds,
-                    microseconds,
-                    nanoseconds,
-                )
-            }
-            // Seconds variant is defined as { hours: u32, minutes: u32, seconds: u32, fraction: u32 }
-            Some(TimeDurationRecord::Seconds {
-                hours,
-                minutes,
-                seconds,
-                fraction,
-            }) => {
-                let ns = fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0);
-                let milliseconds = ns.div_euclid(1_000_000);
-                let rem = ns.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds as u64,
-                    microseconds as u64,
-                    nanoseconds as u64,
-                )
-            }
-            None => (0, 0, 0, 0, 0, 0),
-        };
+        let record = parse_iso_duration(s)?;
+        let sign = record.sign as i64;
+        Self::new(
+            record.years as i64 * sign,
+            record.months as i64 * sign,
+            record.weeks as i64 * sign,
+            record.days as i64 * sign,
+            record.hours as i64 * sign,
+            record.minutes as i64 * sign,
+            record.seconds as i64 * sign,
+            record.milliseconds as i64 * sign,
+            i128::from(record.microseconds) * i128::from(sign),
+            i128::from(record.nanoseconds) * i128::from(sign),
+        )
+    }
 
-        let (years, months, weeks, days) = if let Some(date) = parse_record.date {
-            (date.years, date.months, date.weeks, date.days)
-        } else {
-            (0, 0, 0, 0)
-        };
+    /// Returns whether this `Duration` is equal to `other` within `tolerance_ns` nanoseconds.
+    ///
+    /// Durations with calendar units are only approximately equal if they are equal.
+    #[inline]
+    #[must_use]
+    pub fn eq_approx(&self, other: &Duration, tolerance_ns: i128) -> bool {
+        match (
+            self.total_24_hour_day_nanoseconds(),
+            other.total_24_hour_day_nanoseconds(),
+        ) {
+            (Some(one), Some(two)) => (one - two).abs() <= tolerance_ns,
+            _ => self == other,
+        }
+    }
 
-        let sign = parse_record.sign as i64;
+    /// Returns the `DateDuration` and `TimeDuration` components of this `Duration`.
+    #[inline]
+    #[must_use]
+    pub fn split(&self) -> (DateDuration, TimeDuration) {
+        (self.date, self.time)
+    }
 
-        Self::new(
-            years as i64 * sign,
-            months as i64 * sign,
-            weeks as i64 * sign,
-            days as i64 * sign,
-            hours as i64 * sign,
-            minutes as i64 * sign,
-            seconds as i64 * sign,
-            millis as i64 * sign,
-            micros as i128 * sign as i128,
-            nanos as i128 * sign as i128,
-        )
+    /// Returns whether any of the years, months, weeks, or days fields are non-zero.
+    #[inline]
+    #[must_use]
+    pub fn has_date_components(&self) -> bool {
+        !self.date.is_zero()
+    }
+
+    /// Returns whether any of the time fields are non-zero.
+    #[inline]
+    #[must_use]
+    pub fn has_time_components(&self) -> bool {
+        !self.time.is_zero()
     }
 
     /// Return if the Durations values are within their valid ranges.
@@ -514,6 +525,53 @@ impl Duration {
     }
 }
 
+// ==== `std::time::Duration` conversions ====
+
+#[cfg(feature = "std")]
+impl Duration {
+    /// Creates a `Duration` from a [`std::time::Duration`].
+    ///
+    /// The seconds are balanced into the `hours`, `minutes`, and `seconds` fields, and
+    /// the subsecond nanoseconds into the `milliseconds`, `microseconds`, and `nanoseconds`
+    /// fields. All date fields are zero.
+    pub fn from_std_duration(duration: std::time::Duration) -> TemporalResult<Self> {
+        let seconds = duration.as_secs();
+        let subseconds = duration.subsec_nanos();
+        Self::new(
+            0,
+            0,
+            0,
+            0,
+            (seconds / 3600) as i64,
+            (seconds % 3600 / 60) as i64,
+            (seconds % 60) as i64,
+            i64::from(subseconds / 1_000_000),
+            i128::from(subseconds / 1_000 % 1_000),
+            i128::from(subseconds % 1_000),
+        )
+    }
+
+    /// Converts this `Duration` into a [`std::time::Duration`].
+    ///
+    /// Returns a `RangeError` if this `Duration` has non-zero date fields or is
+    /// negative, as a [`std::time::Duration`] is always non-negative.
+    pub fn to_std_duration(&self) -> TemporalResult<std::time::Duration> {
+        if !self.is_time_duration() {
+            return Err(TemporalError::range()
+                .with_message("Duration with date units cannot be converted to std Duration."));
+        }
+        if self.sign() == Sign::Negative {
+            return Err(TemporalError::range()
+                .with_message("Negative Duration cannot be converted to std Duration."));
+        }
+        let nanoseconds = NormalizedTimeDuration::from_time_duration(self.time()).0;
+        let seconds = u64::try_from(nanoseconds / 1_000_000_000)
+            .map_err(|_| TemporalError::range().with_message("Duration exceeds std Duration."))?;
+        let subseconds = (nanoseconds % 1_000_000_000) as u32;
+        Ok(std::time::Duration::new(seconds, subseconds))
+    }
+}
+
 // ==== Public Duration methods ====
 
 impl Duration {
@@ -544,12 +602,15 @@ impl Duration {
     }
 
     /// Returns the absolute value of `Duration`.
+    ///
+    /// A `Duration` is negated if its first non-zero field is negative.
     #[inline]
     #[must_use]
     pub fn abs(&self) -> Self {
-        Self {
-            date: self.date().abs(),
-            time: self.time().abs(),
+        if self.sign() == Sign::Negative {
+            self.negated()
+        } else {
+            *self
         }
     }
 
@@ -602,6 +663,235 @@ impl Duration {
         self.add(&other.negated())
     }
 
+    /// Returns the field-wise sum of this `Duration` and `other`.
+    ///
+    /// Unlike [`Duration::add`], calendar units are allowed and no fields are
+    /// balanced, e.g. `PT50M` plus `PT20M` is `PT70M`. Errors if the result is not a
+    /// valid `Duration`, including when its fields would have mixed signs.
+    pub fn checked_add(&self, other: &Self) -> TemporalResult<Self> {
+        let overflow = || TemporalError::range().with_message("Duration field overflowed.");
+        Self::new(
+            self.years()
+                .checked_add(other.years())
+                .ok_or_else(overflow)?,
+            self.months()
+                .checked_add(other.months())
+                .ok_or_else(overflow)?,
+            self.weeks()
+                .checked_add(other.weeks())
+                .ok_or_else(overflow)?,
+            self.days().checked_add(other.days()).ok_or_else(overflow)?,
+            self.hours()
+                .checked_add(other.hours())
+                .ok_or_else(overflow)?,
+            self.minutes()
+                .checked_add(other.minutes())
+                .ok_or_else(overflow)?,
+            self.seconds()
+                .checked_add(other.seconds())
+                .ok_or_else(overflow)?,
+            self.milliseconds()
+                .checked_add(other.milliseconds())
+                .ok_or_else(overflow)?,
+            self.microseconds()
+                .checked_add(other.microseconds())
+                .ok_or_else(overflow)?,
+            self.nanoseconds()
+                .checked_add(other.nanoseconds())
+                .ok_or_else(overflow)?,
+        )
+    }
+
+    /// Returns the field-wise difference of this `Duration` and `other`.
+    ///
+    /// See [`Duration::checked_add`].
+    #[inline]
+    pub fn checked_sub(&self, other: &Self) -> TemporalResult<Self> {
+        self.checked_add(&other.negated())
+    }
+
+    /// Returns this `Duration` with each field multiplied by `factor`.
+    ///
+    /// The fractional part of a field is carried into the next smaller unit using a
+    /// fixed ratio, e.g. `P1Y` scaled by 1.5 is `P1Y6M` and `P1W` scaled by 0.5 is
+    /// `P3DT12H`, and any fractional nanoseconds are truncated. As months do not have
+    /// a fixed length, a fractional month is a `RangeError`.
+    #[inline]
+    pub fn scale_by(&self, factor: f64) -> TemporalResult<Self> {
+        Self::ZERO.add_scaled(self, factor)
+    }
+
+    /// Returns this `Duration` scaled by one half.
+    #[inline]
+    pub fn half(&self) -> TemporalResult<Self> {
+        self.scale_by(0.5)
+    }
+
+    /// Returns this `Duration` scaled by two.
+    #[inline]
+    pub fn double(&self) -> TemporalResult<Self> {
+        self.scale_by(2.0)
+    }
+
+    /// Returns the result of adding `other` scaled by `factor` to this `Duration`.
+    ///
+    /// Each field is scaled and added before any fractional part is carried, which
+    /// avoids the rounding of an intermediate scaled `Duration`. See
+    /// [`Duration::scale_by`] for how fractional fields are handled.
+    pub fn add_scaled(&self, other: &Self, factor: f64) -> TemporalResult<Self> {
+        // The ratio of each unit down to seconds to the next smaller unit, if it is fixed.
+        const RATIOS: [Option<f64>; 6] = [
+            Some(12.0),
+            None,
+            Some(7.0),
+            Some(24.0),
+            Some(60.0),
+            Some(60.0),
+        ];
+        // The nanoseconds in each subsecond unit.
+        const SUBSECOND_UNITS: [i128; 3] = [1_000_000, 1_000, 1];
+        const MAX_SAFE_INTEGER: f64 = (TWO_POWER_FIFTY_THREE - 1) as f64;
+
+        if !factor.is_finite() {
+            return Err(TemporalError::range().with_message("scale factor must be finite."));
+        }
+        let out_of_range =
+            || TemporalError::range().with_message("scaled Duration field is out of range.");
+
+        // The units down to seconds are `i64` fields, which are scaled as floating point
+        // and must be safe integers to be cast losslessly.
+        let base = self.fields_as_f64();
+        let scaled = other.fields_as_f64();
+        let mut fields = [0i64; 7];
+        let mut carry = 0.0;
+        for (i, field) in fields.iter_mut().enumerate() {
+            let value = base[i] + scaled[i] * factor + carry;
+            if !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) {
+                return Err(TemporalError::range()
+                    .with_message("scaled Duration field is not a safe integer."));
+            }
+            *field = value as i64;
+            let remainder = value - *field as f64;
+            carry = match RATIOS.get(i) {
+                Some(Some(ratio)) => remainder * ratio,
+                Some(None) if remainder != 0.0 => {
+                    return Err(TemporalError::range()
+                        .with_message("scaled Duration cannot have fractional months."))
+                }
+                Some(None) => 0.0,
+                // The fractional seconds are carried as nanoseconds below.
+                None => remainder,
+            };
+        }
+
+        // The subsecond units are carried in integer nanoseconds, as the microseconds
+        // and nanoseconds may be larger than a safe integer. The integer part of
+        // `factor` is applied exactly, and any fractional nanoseconds are truncated.
+        let mut carry_ns = (carry * 1e9) as i128;
+        let base_subseconds = [
+            i128::from(self.milliseconds()),
+            self.microseconds(),
+            self.nanoseconds(),
+        ];
+        let scaled_subseconds = [
+            i128::from(other.milliseconds()),
+            other.microseconds(),
+            other.nanoseconds(),
+        ];
+        let (whole_factor, fractional_factor) = (factor.trunc(), factor.fract());
+        if whole_factor.abs() >= i128::MAX as f64 {
+            return Err(out_of_range());
+        }
+        let mut subseconds = [0i128; 3];
+        for (i, unit) in SUBSECOND_UNITS.into_iter().enumerate() {
+            let scaled_ns = scaled_subseconds[i]
+                .checked_mul(unit)
+                .ok_or_else(out_of_range)?;
+            let total_ns = base_subseconds[i]
+                .checked_mul(unit)
+                .and_then(|base| base.checked_add(carry_ns))
+                .and_then(|total| total.checked_add(scaled_ns.checked_mul(whole_factor as i128)?))
+                .and_then(|total| total.checked_add((scaled_ns as f64 * fractional_factor) as i128))
+                .ok_or_else(out_of_range)?;
+            subseconds[i] = total_ns / unit;
+            carry_ns = total_ns % unit;
+        }
+
+        let [years, months, weeks, days, hours, minutes, seconds] = fields;
+        let [milliseconds, microseconds, nanoseconds] = subseconds;
+        Self::new(
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds.try_into().map_err(|_| out_of_range())?,
+            microseconds,
+            nanoseconds,
+        )
+    }
+
+    /// Returns the linear interpolation from `start` to `end` at `t`, where `t` is
+    /// clamped to `0.0..=1.0`.
+    ///
+    /// If neither `Duration` has date units, the total nanoseconds are interpolated
+    /// and balanced up to the larger of their largest units. Otherwise, each field is
+    /// interpolated independently and truncated, which is only an approximation for
+    /// years, months, and weeks.
+    pub fn interpolate(start: &Self, end: &Self, t: f64) -> TemporalResult<Self> {
+        if !t.is_finite() {
+            return Err(TemporalError::range().with_message("interpolation factor must be finite."));
+        }
+        let t = t.clamp(0.0, 1.0);
+
+        if start.date().is_zero() && end.date().is_zero() {
+            let start_ns = NormalizedTimeDuration::from_time_duration(start.time()).0;
+            let end_ns = NormalizedTimeDuration::from_time_duration(end.time()).0;
+            let nanoseconds = start_ns + ((end_ns - start_ns) as f64 * t) as i128;
+            let norm = NormalizedTimeDuration::from_nanosecond_difference(nanoseconds, 0)?;
+            let largest_unit = start.default_largest_unit().max(end.default_largest_unit());
+            let (days, time) = TimeDuration::from_normalized(norm, largest_unit)?;
+            return Ok(Duration::from_day_and_time(days, &time));
+        }
+
+        let start_fields = start.fields_as_f64();
+        let end_fields = end.fields_as_f64();
+        let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] =
+            core::array::from_fn(|i| start_fields[i] + (end_fields[i] - start_fields[i]) * t);
+        // NOTE: Each value is between its start and end fields, so the casts are lossless
+        // other than the truncation.
+        Self::new(
+            years as i64,
+            months as i64,
+            weeks as i64,
+            days as i64,
+            hours as i64,
+            minutes as i64,
+            seconds as i64,
+            milliseconds as i64,
+            microseconds as i128,
+            nanoseconds as i128,
+        )
+    }
+
+    /// Returns the fields of this `Duration` from years to nanoseconds as `f64`s.
+    fn fields_as_f64(&self) -> [f64; 10] {
+        [
+            self.years() as f64,
+            self.months() as f64,
+            self.weeks() as f64,
+            self.days() as f64,
+            self.hours() as f64,
+            self.minutes() as f64,
+            self.seconds() as f64,
+            self.milliseconds() as f64,
+            self.microseconds() as f64,
+            self.nanoseconds() as f64,
+        ]
+    }
+
     #[inline]
     pub fn round_with_provider(
         &self,
@@ -1098,114 +1388,63 @@ impl From<DateDuration> for Duration {
     }
 }
 
+// ==== Comparison trait impls ====
+//
+// NOTE: Durations without calendar units are ordered by their total
+// nanoseconds with 24-hour days. Durations with calendar units depend on a
+// reference point to be compared, so they are unordered unless equal. To stay
+// consistent with the field-wise `PartialEq`, durations with the same value but
+// different fields, e.g. `PT2H` and `PT120M`, are also unordered.
+
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (
+            self.total_24_hour_day_nanoseconds(),
+            other.total_24_hour_day_nanoseconds(),
+        ) {
+            _ if self == other => Some(Ordering::Equal),
+            (Some(one), Some(two)) if one != two => Some(one.cmp(&two)),
+            _ => None,
+        }
+    }
+}
+
+impl Add for &Duration {
+    type Output = Duration;
+
+    /// Adds two `Duration`s field-wise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is not a valid `Duration`. See [`Duration::checked_add`]
+    /// for a fallible alternative.
+    fn add(self, rhs: Self) -> Duration {
+        self.checked_add(rhs)
+            .expect("Duration addition must produce a valid Duration.")
+    }
+}
+
+impl Sub for &Duration {
+    type Output = Duration;
+
+    /// Subtracts two `Duration`s field-wise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is not a valid `Duration`. See [`Duration::checked_sub`]
+    /// for a fallible alternative.
+    fn sub(self, rhs: Self) -> Duration {
+        self.checked_sub(rhs)
+            .expect("Duration subtraction must produce a valid Duration.")
+    }
+}
+
 // ==== FromStr trait impl ====
 
 impl FromStr for Duration {
     type Err = TemporalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parse_record = IsoDurationParser::from_str(s)
-            .parse()
-            .map_err(|e| TemporalError::range().with_message(format!("{e}")))?;
-
-        let (hours, minutes, seconds, millis, micros, nanos) = match parse_record.time {
-            Some(TimeDurationRecord::Hours { hours, fraction }) => {
-                let unadjusted_fraction =
-                    fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0) as u64;
-                let fractional_hours_ns = unadjusted_fraction * 3600;
-                let minutes = fractional_hours_ns.div_euclid(60 * 1_000_000_000);
-                let fractional_minutes_ns = fractional_hours_ns.rem_euclid(60 * 1_000_000_000);
-
-                let seconds = fractional_minutes_ns.div_euclid(1_000_000_000);
-                let fractional_seconds = fractional_minutes_ns.rem_euclid(1_000_000_000);
-
-                let milliseconds = fractional_seconds.div_euclid(1_000_000);
-                let rem = fractional_seconds.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds,
-                    microseconds,
-                    nanoseconds,
-                )
-            }
-            // Minutes variant is defined as { hours: u32, minutes: u32, fraction: u64 }
-            Some(TimeDurationRecord::Minutes {
-                hours,
-                minutes,
-                fraction,
-            }) => {
-                let unadjusted_fraction =
-                    fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0) as u64;
-                let fractional_minutes_ns = unadjusted_fraction * 60;
-                let seconds = fractional_minutes_ns.div_euclid(1_000_000_000);
-                let fractional_seconds = fractional_minutes_ns.rem_euclid(1_000_000_000);
-
-                let milliseconds = fractional_seconds.div_euclid(1_000_000);
-                let rem = fractional_seconds.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds,
-                    microseconds,
-                    nanoseconds,
-                )
-            }
-            // Seconds variant is defined as { hours: u32, minutes: u32, seconds: u32, fraction: u32 }
-            Some(TimeDurationRecord::Seconds {
-                hours,
-                minutes,
-                seconds,
-                fraction,
-            }) => {
-                let ns = fraction.and_then(|x| x.to_nanoseconds()).unwrap_or(0);
-                let milliseconds = ns.div_euclid(1_000_000);
-                let rem = ns.rem_euclid(1_000_000);
-
-                let microseconds = rem.div_euclid(1_000);
-                let nanoseconds = rem.rem_euclid(1_000);
-
-                (
-                    hours,
-                    minutes,
-                    seconds,
-                    milliseconds as u64,
-                    microseconds as u64,
-                    nanoseconds as u64,
-                )
-            }
-            None => (0, 0, 0, 0, 0, 0),
-        };
-
-        let (years, months, weeks, days) = if let Some(date) = parse_record.date {
-            (date.years, date.months, date.weeks, date.days)
-        } else {
-            (0, 0, 0, 0)
-        };
-
-        let sign = parse_record.sign as i64;
-
-        Self::new(
-            years as i64 * sign,
-            months as i64 * sign,
-            weeks as i64 * sign,
-            days as i64 * sign,
-            hours as i64 * sign,
-            minutes as i64 * sign,
-            seconds as i64 * sign,
-            millis as i64 * sign,
-            micros as i128 * sign as i128,
-            nanos as i128 * sign as i128,
-        )
+        Self::from_utf8(s.as_bytes())
     }
 }
@@ -1,7 +1,7 @@
 //! Implementation of a `DateDuration`
 
 use crate::{
-    iso::iso_date_to_epoch_days, options::ArithmeticOverflow, Duration, PlainDate, Sign,
+    iso::iso_date_epoch_days, options::ArithmeticOverflow, Duration, PlainDate, Sign,
     TemporalError, TemporalResult,
 };
 
@@ -14,7 +14,7 @@ use super::duration_sign;
 /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-date-duration-records
 /// [field spec]: https://tc39.es/proposal-temporal/#sec-properties-of-temporal-duration-instances
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 pub struct DateDuration {
     /// `DateDuration`'s internal year value.
     pub years: i64,
@@ -89,12 +89,19 @@ impl DateDuration {
         duration_sign(self.fields().as_slice())
     }
 
+    /// Returns whether all fields of this `DateDuration` are zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.sign() == Sign::Zero
+    }
+
     /// DateDurationDays
     pub(crate) fn days(&self, relative_to: &PlainDate) -> TemporalResult<i64> {
         // 1. Let yearsMonthsWeeksDuration be ! AdjustDateDurationRecord(dateDuration, 0).
         let ymw_duration = self.adjust(0, None, None)?;
         // 2. If DateDurationSign(yearsMonthsWeeksDuration) = 0, return dateDuration.[[Days]].
-        if ymw_duration.sign() == Sign::Zero {
+        if ymw_duration.is_zero() {
             return Ok(self.days);
         }
         // 3. Let later be ? CalendarDateAdd(plainRelativeTo.[[Calendar]], plainRelativeTo.[[ISODate]], yearsMonthsWeeksDuration, constrain).
@@ -106,13 +113,13 @@ impl DateDuration {
             Some(ArithmeticOverflow::Constrain),
         )?;
         // 4. Let epochDays1 be ISODateToEpochDays(plainRelativeTo.[[ISODate]].[[Year]], plainRelativeTo.[[ISODate]].[[Month]] - 1, plainRelativeTo.[[ISODate]].[[Day]]).
-        let epoch_days_1 = iso_date_to_epoch_days(
+        let epoch_days_1 = iso_date_epoch_days(
             relative_to.iso_year(),
             i32::from(relative_to.iso_month()), // this function takes 1 based month number
             i32::from(relative_to.iso_day()),
         );
         // 5. Let epochDays2 be ISODateToEpochDays(later.[[Year]], later.[[Month]] - 1, later.[[Day]]).
-        let epoch_days_2 = iso_date_to_epoch_days(
+        let epoch_days_2 = iso_date_epoch_days(
             later.iso_year(),
             i32::from(later.iso_month()), // this function takes 1 based month number
             i32::from(later.iso_day()),
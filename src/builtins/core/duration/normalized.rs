@@ -36,8 +36,10 @@ const NANOSECONDS_PER_HOUR: i128 = 60 * NANOSECONDS_PER_MINUTE;
 // nanoseconds.abs() <= MAX_TIME_DURATION
 
 /// A Normalized `TimeDuration` that represents the current `TimeDuration` in nanoseconds.
+///
+/// A `NormalizedTimeDuration` is created with [`TimeDuration::to_normalized`].
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Ord)]
-pub(crate) struct NormalizedTimeDuration(pub(crate) i128);
+pub struct NormalizedTimeDuration(pub(crate) i128);
 
 impl NormalizedTimeDuration {
     /// Equivalent: 7.5.20 NormalizeTimeDuration ( hours, minutes, seconds, milliseconds, microseconds, nanoseconds )
@@ -95,6 +97,28 @@ impl NormalizedTimeDuration {
         Sign::from(self.0.cmp(&0) as i8)
     }
 
+    /// Returns whether this `NormalizedTimeDuration` is zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Unpacks this `NormalizedTimeDuration` into a balanced `TimeDuration` with
+    /// hours as the largest unit.
+    ///
+    /// ```rust
+    /// use temporal_rs::TimeDuration;
+    ///
+    /// let time = TimeDuration::new(0, 90, 59, 999, 999, 1_001).unwrap();
+    /// let balanced = time.to_normalized().to_time_duration().unwrap();
+    /// assert_eq!(balanced, TimeDuration::new(1, 31, 0, 0, 0, 1).unwrap());
+    /// ```
+    #[inline]
+    pub fn to_time_duration(self) -> TemporalResult<TimeDuration> {
+        TimeDuration::from_normalized(self, Unit::Hour).map(|(_, time)| time)
+    }
+
     // NOTE(nekevss): non-euclid is required here for negative rounding.
     /// Return the seconds value of the `NormalizedTimeDuration`.
     pub(crate) fn seconds(&self) -> i64 {
@@ -246,7 +270,7 @@ impl NormalizedDurationRecord {
     ///
     /// Equivalent: `CreateNormalizedDurationRecord` & `CombineDateAndNormalizedTimeDuration`.
     pub(crate) fn new(date: DateDuration, norm: NormalizedTimeDuration) -> TemporalResult<Self> {
-        if date.sign() != Sign::Zero && norm.sign() != Sign::Zero && date.sign() != norm.sign() {
+        if !date.is_zero() && !norm.is_zero() && date.sign() != norm.sign() {
             return Err(TemporalError::range().with_message(
                 "DateDuration and NormalizedTimeDuration must agree if both are not zero.",
             ));
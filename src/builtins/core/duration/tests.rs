@@ -1,13 +1,14 @@
-use core::str::FromStr;
+use core::{cmp::Ordering, str::FromStr};
 
 use crate::{
     options::{RoundingOptions, ToStringRoundingOptions, Unit},
     parsers::Precision,
     partial::PartialDuration,
     provider::NeverProvider,
+    Sign,
 };
 
-use super::Duration;
+use super::{DateDuration, Duration, TimeDuration};
 
 #[test]
 fn partial_duration_empty() {
@@ -190,6 +191,247 @@ fn duration_from_str() {
     assert_eq!(duration.nanoseconds(), 940);
 }
 
+#[test]
+fn duration_from_str_components() {
+    const DESIGNATORS: [(&str, bool); 7] = [
+        ("Y", false),
+        ("M", false),
+        ("W", false),
+        ("D", false),
+        ("H", true),
+        ("M", true),
+        ("S", true),
+    ];
+
+    // Every non-empty combination of the seven components, with each value set to
+    // its position plus one.
+    for mask in 1u8..128 {
+        let mut source = alloc::string::String::from("P");
+        for (index, (designator, is_time)) in DESIGNATORS.iter().enumerate() {
+            if mask & (1 << index) == 0 {
+                continue;
+            }
+            if *is_time && !source.contains('T') {
+                source.push('T');
+            }
+            source.push_str(&alloc::format!("{}{designator}", index + 1));
+        }
+
+        let duration = Duration::from_str(&source).unwrap();
+        let fields = [
+            duration.years(),
+            duration.months(),
+            duration.weeks(),
+            duration.days(),
+            duration.hours(),
+            duration.minutes(),
+            duration.seconds(),
+        ];
+        for (index, field) in fields.into_iter().enumerate() {
+            let expected = if mask & (1 << index) == 0 {
+                0
+            } else {
+                index as i64 + 1
+            };
+            assert_eq!(field, expected, "{source}");
+        }
+
+        let negative = Duration::from_str(&alloc::format!("-{source}")).unwrap();
+        assert_eq!(negative.sign(), Sign::Negative);
+        assert_eq!(negative.negated(), duration);
+    }
+
+    let duration = Duration::from_str("PT1.5H").unwrap();
+    assert_eq!((duration.hours(), duration.minutes()), (1, 30));
+    let duration = Duration::from_str("P1DT2.25M").unwrap();
+    assert_eq!(
+        (duration.days(), duration.minutes(), duration.seconds()),
+        (1, 2, 15)
+    );
+    let duration = Duration::from_str("-PT0.5S").unwrap();
+    assert_eq!(duration.milliseconds(), -500);
+    let duration = Duration::from_str("+P1W").unwrap();
+    assert_eq!(duration.weeks(), 1);
+}
+
+#[test]
+fn duration_display_round_trip() {
+    let cases = [
+        (Duration::default(), "PT0S"),
+        (
+            Duration::new(1, 2, 3, 4, 5, 6, 7, 0, 0, 0).unwrap(),
+            "P1Y2M3W4DT5H6M7S",
+        ),
+        (
+            Duration::new(0, 0, 0, 0, 0, 0, 0, 1, 2, 3).unwrap(),
+            "PT0.001002003S",
+        ),
+        (
+            Duration::new(0, 0, 0, 0, 0, 0, 5, 500, 0, 0).unwrap(),
+            "PT5.5S",
+        ),
+        (Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap(), "P1D"),
+        (Duration::new(0, 0, 0, 0, 1, 0, 0, 0, 0, 0).unwrap(), "PT1H"),
+        (
+            Duration::new(-1, 0, -2, 0, 0, -3, 0, -4, 0, 0).unwrap(),
+            "-P1Y2WT3M0.004S",
+        ),
+        (
+            Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 0, -1).unwrap(),
+            "-PT0.000000001S",
+        ),
+    ];
+    for (duration, expected) in cases {
+        let result = alloc::format!("{duration}");
+        assert_eq!(result, expected);
+        assert_eq!(Duration::from_str(&result).unwrap(), duration);
+    }
+}
+
+#[test]
+fn duration_negated_and_abs() {
+    let positive = Duration::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10).unwrap();
+    let negative = Duration::new(-1, -2, -3, -4, -5, -6, -7, -8, -9, -10).unwrap();
+    assert_eq!(positive.negated(), negative);
+    assert_eq!(negative.negated(), positive);
+    assert_eq!(positive.negated().negated(), positive);
+    assert_eq!(positive.abs(), positive);
+    assert_eq!(negative.abs(), positive);
+
+    let zero = Duration::default();
+    assert_eq!(zero.negated(), zero);
+    assert_eq!(zero.abs(), zero);
+
+    // A duration's fields must share a sign, so `Duration::new` rejects mixed signs,
+    // but every field is still negated, and the first non-zero field decides `abs`.
+    assert!(Duration::new(1, 0, 0, -1, 0, 0, 0, 0, 0, 0).is_err());
+    let mixed = Duration::new_unchecked(
+        DateDuration::new_unchecked(-1, 0, 0, 2),
+        TimeDuration::new_unchecked(-3, 0, 4, 0, 0, 0),
+    );
+    let flipped = Duration::new_unchecked(
+        DateDuration::new_unchecked(1, 0, 0, -2),
+        TimeDuration::new_unchecked(3, 0, -4, 0, 0, 0),
+    );
+    assert_eq!(mixed.negated(), flipped);
+    assert_eq!(mixed.abs(), flipped);
+    assert_eq!(flipped.abs(), flipped);
+
+    let sparse = Duration::from_str("-P1YT0.5S").unwrap();
+    assert_eq!(alloc::format!("{}", sparse.negated()), "P1YT0.5S");
+    assert_eq!(sparse.abs(), sparse.negated());
+}
+
+#[test]
+fn duration_sign_by_field() {
+    assert!(Duration::default().is_zero());
+    assert_eq!(Duration::default().sign(), Sign::Zero);
+
+    for position in 0..10 {
+        let mut fields = [0i64; 10];
+        fields[position] = 1;
+        let positive = Duration::new(
+            fields[0],
+            fields[1],
+            fields[2],
+            fields[3],
+            fields[4],
+            fields[5],
+            fields[6],
+            fields[7],
+            i128::from(fields[8]),
+            i128::from(fields[9]),
+        )
+        .unwrap();
+        assert!(!positive.is_zero());
+        assert_eq!(positive.sign(), Sign::Positive);
+        assert_eq!(positive.sign() as i8, 1);
+        assert_eq!(positive.negated().sign(), Sign::Negative);
+        assert_eq!(positive.negated().sign() as i8, -1);
+    }
+}
+
+#[test]
+fn duration_checked_add_and_sub() {
+    fn with_field(position: usize, value: i128) -> Duration {
+        let mut fields = [0i128; 10];
+        fields[position] = value;
+        let [y, mo, w, d, h, mi, s, ms, us, ns] = fields;
+        Duration::new(
+            y as i64, mo as i64, w as i64, d as i64, h as i64, mi as i64, s as i64, ms as i64, us,
+            ns,
+        )
+        .unwrap()
+    }
+
+    let duration = Duration::from_str("P1Y2M3W4DT5H6M7.008009010S").unwrap();
+    assert_eq!(duration.checked_add(&Duration::ZERO).unwrap(), duration);
+    assert_eq!(Duration::ZERO.checked_add(&duration).unwrap(), duration);
+    assert_eq!(duration.checked_sub(&Duration::ZERO).unwrap(), duration);
+    assert_eq!(&duration + &Duration::ZERO, duration);
+    assert!(duration.checked_sub(&duration).unwrap().is_zero());
+
+    let sum = &duration + &Duration::from_str("P1YT60M").unwrap();
+    assert_eq!(alloc::format!("{sum}"), "P2Y2M3W4DT5H66M7.00800901S");
+    let difference = &Duration::from_str("-PT1H").unwrap() - &Duration::from_str("PT30M").unwrap();
+    assert_eq!(alloc::format!("{difference}"), "-PT1H30M");
+
+    // Mixed signs are not a valid `Duration`.
+    assert!(Duration::from_str("P1D")
+        .unwrap()
+        .checked_add(&Duration::from_str("-PT1H").unwrap())
+        .is_err());
+
+    // The largest valid value of each field on its own.
+    let maximums: [i128; 10] = [
+        4_294_967_294,
+        4_294_967_294,
+        4_294_967_294,
+        104_249_991_374,
+        2_501_999_792_983,
+        150_119_987_579_016,
+        9_007_199_254_740_991,
+        9_007_199_254_740_991_999,
+        9_007_199_254_740_991_999_999,
+        9_007_199_254_740_991_999_999_999,
+    ];
+    for (position, maximum) in maximums.into_iter().enumerate() {
+        let max = with_field(position, maximum);
+        let one = with_field(position, 1);
+        assert!(max.checked_add(&one).is_err(), "field {position}");
+        assert!(max.negated().checked_sub(&one).is_err(), "field {position}");
+        assert_eq!(
+            with_field(position, maximum - 1).checked_add(&one).unwrap(),
+            max
+        );
+    }
+}
+
+#[test]
+fn duration_from_str_invalid() {
+    for source in [
+        "",
+        "P",
+        "PT",
+        "1Y",
+        "P1",
+        "P1YT",
+        "PXY",
+        "P1H",
+        "PT1D",
+        "P1.5Y",
+        "PT1.5H30M",
+        "P1M1Y",
+        "P1Y1Y",
+        "PT1S1S",
+        "PT1.1234567891S",
+        "--P1D",
+    ] {
+        assert!(Duration::from_str(source).is_err(), "{source}");
+        assert!(Duration::from_utf8(source.as_bytes()).is_err(), "{source}");
+    }
+}
+
 // Temporal/Duration/max.js
 #[test]
 fn duration_max() {
@@ -284,3 +526,354 @@ fn duration_round_out_of_range_norm_conversion() {
     assert!(err.is_err())
 }
 */
+
+#[cfg(feature = "std")]
+#[test]
+fn std_duration_conversions() {
+    let std_duration = std::time::Duration::new(90_061, 123_456_789);
+    let duration = Duration::from_std_duration(std_duration).unwrap();
+    assert_eq!(duration.days(), 0);
+    assert_eq!(duration.hours(), 25);
+    assert_eq!(duration.minutes(), 1);
+    assert_eq!(duration.seconds(), 1);
+    assert_eq!(duration.milliseconds(), 123);
+    assert_eq!(duration.microseconds(), 456);
+    assert_eq!(duration.nanoseconds(), 789);
+    // Nanosecond precision is preserved on the round trip.
+    assert_eq!(duration.to_std_duration().unwrap(), std_duration);
+
+    // Max seconds boundary.
+    let std_duration = std::time::Duration::new(9_007_199_254_740_991, 999_999_999);
+    let duration = Duration::from_std_duration(std_duration).unwrap();
+    assert_eq!(duration.to_std_duration().unwrap(), std_duration);
+    let std_duration = std::time::Duration::from_secs(9_007_199_254_740_992);
+    assert!(Duration::from_std_duration(std_duration).is_err());
+    assert!(Duration::from_std_duration(std::time::Duration::MAX).is_err());
+
+    let duration = Duration::from_std_duration(std::time::Duration::ZERO).unwrap();
+    assert!(duration.is_zero());
+    assert_eq!(
+        duration.to_std_duration().unwrap(),
+        std::time::Duration::ZERO
+    );
+
+    // Date units and negative durations are rejected.
+    let duration = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap();
+    assert!(duration.to_std_duration().is_err());
+    let duration = Duration::new(0, 0, 0, 0, 0, 0, -1, 0, 0, 0).unwrap();
+    assert!(duration.to_std_duration().is_err());
+}
+
+#[test]
+fn duration_equality_and_ordering() {
+    let two_hours = Duration::new(0, 0, 0, 0, 2, 0, 0, 0, 0, 0).unwrap();
+    let minutes = Duration::new(0, 0, 0, 0, 0, 120, 0, 0, 0, 0).unwrap();
+    // Equality is field-wise, and durations with equal values but different
+    // fields are unordered.
+    assert_ne!(two_hours, minutes);
+    assert_eq!(two_hours.partial_cmp(&minutes), None);
+    assert!(two_hours.eq_approx(&minutes, 0));
+    assert_eq!(two_hours, Duration::from_str("PT2H").unwrap());
+    assert_eq!(two_hours.partial_cmp(&two_hours), Some(Ordering::Equal));
+
+    let one_day = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap();
+    let hours = Duration::new(0, 0, 0, 0, 24, 0, 0, 0, 0, 0).unwrap();
+    assert_ne!(one_day, hours);
+    assert!(one_day.eq_approx(&hours, 0));
+    assert!(two_hours < one_day);
+    assert!(minutes < hours);
+
+    // Mixed sign durations are not equal.
+    assert_ne!(two_hours, two_hours.negated());
+    assert!(two_hours.negated() < two_hours);
+
+    // Durations with calendar units are only ordered when equal.
+    let one_month = Duration::new(0, 1, 0, 0, 0, 0, 0, 0, 0, 0).unwrap();
+    let days = Duration::new(0, 0, 0, 30, 0, 0, 0, 0, 0, 0).unwrap();
+    assert_ne!(one_month, days);
+    assert_eq!(one_month.partial_cmp(&days), None);
+    assert_eq!(one_month.partial_cmp(&one_month), Some(Ordering::Equal));
+
+    let almost_two_hours = Duration::new(0, 0, 0, 0, 1, 59, 59, 999, 999, 999).unwrap();
+    assert!(two_hours.eq_approx(&almost_two_hours, 1));
+    assert!(!two_hours.eq_approx(&almost_two_hours, 0));
+    assert!(!one_month.eq_approx(&days, i128::MAX));
+}
+
+#[test]
+fn normalized_time_duration_round_trip() {
+    let time = TimeDuration::new(1, 59, 59, 999, 999, 1_001).unwrap();
+    let norm = time.to_normalized();
+    let balanced = norm.to_time_duration().unwrap();
+    assert_eq!(balanced, TimeDuration::new(2, 0, 0, 0, 0, 1).unwrap());
+    assert_eq!(balanced.to_normalized(), norm);
+
+    let time = TimeDuration::new(0, -90, 0, 0, 0, -1).unwrap();
+    let balanced = time.to_normalized().to_time_duration().unwrap();
+    assert_eq!(balanced, TimeDuration::new(-1, -30, 0, 0, 0, -1).unwrap());
+}
+
+#[test]
+fn duration_parts_is_zero() {
+    assert!(TimeDuration::default().is_zero());
+    assert!(TimeDuration::default().to_normalized().is_zero());
+    assert!(DateDuration::default().is_zero());
+
+    let time = TimeDuration::new(0, 0, 0, 0, 0, 1).unwrap();
+    assert!(!time.is_zero());
+    assert!(!time.to_normalized().is_zero());
+    let time = TimeDuration::new(0, 0, 0, 0, -1, 0).unwrap();
+    assert!(!time.is_zero());
+    assert!(!DateDuration::new(0, 0, 1, 0).unwrap().is_zero());
+    assert!(!DateDuration::new(-1, 0, 0, 0).unwrap().is_zero());
+}
+
+#[test]
+fn duration_split_and_from_parts() {
+    let duration = Duration::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10).unwrap();
+    let (date, time) = duration.split();
+    assert_eq!(date, DateDuration::new(1, 2, 3, 4).unwrap());
+    assert_eq!(time, TimeDuration::new(5, 6, 7, 8, 9, 10).unwrap());
+    let result = Duration::from_parts(date, time).unwrap();
+    assert_eq!(result.fields_signum(), duration.fields_signum());
+    assert_eq!(result.split(), duration.split());
+
+    // Mixed signs are rejected.
+    let err = Duration::from_parts(date, time.negated());
+    assert!(err.is_err());
+
+    assert!(duration.has_date_components());
+    assert!(duration.has_time_components());
+
+    let days = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap();
+    assert!(days.has_date_components());
+    assert!(!days.has_time_components());
+
+    let nanos = Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 0, -1).unwrap();
+    assert!(!nanos.has_date_components());
+    assert!(nanos.has_time_components());
+
+    assert!(!Duration::default().has_date_components());
+    assert!(!Duration::default().has_time_components());
+}
+
+#[test]
+fn duration_from_iso_duration_string() {
+    let duration = Duration::from_iso_duration_string("P1Y2M3W4DT5H6M7.89S").unwrap();
+    assert_eq!(
+        duration,
+        Duration::new(1, 2, 3, 4, 5, 6, 7, 890, 0, 0).unwrap()
+    );
+
+    let duration = Duration::from_iso_duration_string("-P1Y").unwrap();
+    assert_eq!(duration.years(), -1);
+    assert_eq!(duration.sign(), Sign::Negative);
+
+    let duration = Duration::from_iso_duration_string("P0D").unwrap();
+    assert!(duration.is_zero());
+
+    let duration = Duration::from_iso_duration_string("+P3W").unwrap();
+    assert_eq!(duration.weeks(), 3);
+
+    let duration = Duration::from_iso_duration_string("PT1.5H").unwrap();
+    assert_eq!(duration.hours(), 1);
+    assert_eq!(duration.minutes(), 30);
+
+    // Temporal/Duration/from/argument-string.js and
+    // Temporal/Duration/from/argument-string-fractional-units-rounding-mode.js
+    let valid = [
+        ("P1D", Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0)),
+        (
+            "p1y1m1dt1h1m1s",
+            Duration::new(1, 1, 0, 1, 1, 1, 1, 0, 0, 0),
+        ),
+        (
+            "P1Y1M1W1DT1H1M1.123456789S",
+            Duration::new(1, 1, 1, 1, 1, 1, 1, 123, 456, 789),
+        ),
+        (
+            "-P1Y1M1W1DT1H1M1,123456789S",
+            Duration::new(-1, -1, -1, -1, -1, -1, -1, -123, -456, -789),
+        ),
+        ("PT36H", Duration::new(0, 0, 0, 0, 36, 0, 0, 0, 0, 0)),
+        ("P1DT1.5H", Duration::new(0, 0, 0, 1, 1, 30, 0, 0, 0, 0)),
+        ("PT1.03125H", Duration::new(0, 0, 0, 0, 1, 1, 52, 500, 0, 0)),
+        ("PT0.5M", Duration::new(0, 0, 0, 0, 0, 0, 30, 0, 0, 0)),
+        ("PT1H0.5M", Duration::new(0, 0, 0, 0, 1, 0, 30, 0, 0, 0)),
+        (
+            "PT0.999999999H",
+            Duration::new(0, 0, 0, 0, 0, 59, 59, 999, 996, 400),
+        ),
+        (
+            "PT0.000000001M",
+            Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 0, 60),
+        ),
+    ];
+    for (s, expected) in valid {
+        assert_eq!(Duration::from_iso_duration_string(s), expected, "{s}");
+    }
+
+    // Temporal/Duration/from/argument-string-invalid.js
+    let invalid = [
+        "",
+        "P",
+        "PT",
+        "-P",
+        "-PT",
+        "+P",
+        "+PT",
+        "1Y",
+        "P1Y1Y",
+        "P1D2Y",
+        "PT1S2M",
+        "P1.5Y",
+        "P0.5Y",
+        "P1Y0,5M",
+        "P1Y1M0.5W",
+        "P1Y1M1W0,5D",
+        "PT1.5H2M",
+        "P1Y1M1W1DT0.5H5S",
+        "P1Y1M1W1DT1.5H0,5M",
+        "P1Y1M1W1DT1H0.5M0.5S",
+        "P1Y1M1W1DT1H1M1.123456789123S",
+        "P1Y1M1W1DT1H1M1.01Sjunk",
+        "P-1Y1M",
+        "P1Y-1M",
+        "P1YT",
+        "P1Y1M1W1DT",
+        "PT1.S",
+        "PT1H ",
+        "P1H",
+        "PT1D",
+        "P9007199254740992D",
+    ];
+    for s in invalid {
+        assert!(Duration::from_iso_duration_string(s).is_err(), "{s}");
+    }
+}
+
+#[test]
+fn duration_debug_output() {
+    let duration = Duration::from_str("P1Y2M3DT4H5M6.789S").unwrap();
+    assert_eq!(
+        alloc::format!("{duration:?}"),
+        "Duration(P1Y2M3DT4H5M6.789S)"
+    );
+    assert_eq!(
+        alloc::format!("{:?}", Duration::default()),
+        "Duration(PT0S)"
+    );
+}
+
+#[test]
+fn duration_constants() {
+    assert!(Duration::ZERO.is_zero());
+    assert_eq!(Duration::ZERO, Duration::default());
+
+    let max = Duration::MAX_SAFE;
+    assert_eq!(
+        max,
+        Duration::new(0, 0, 0, 0, 0, 0, 9_007_199_254_740_991, 999, 999, 999).unwrap()
+    );
+    assert!(Duration::new(0, 0, 0, 0, 0, 0, 9_007_199_254_740_991, 999, 999, 1_000).is_err());
+}
+
+#[test]
+fn duration_scaling() {
+    let year = Duration::from_str("P1Y").unwrap();
+    assert_eq!(
+        year.scale_by(1.5).unwrap(),
+        Duration::from_str("P1Y6M").unwrap()
+    );
+    assert_eq!(
+        Duration::from_str("P1W").unwrap().half().unwrap(),
+        Duration::from_str("P3DT12H").unwrap()
+    );
+    assert_eq!(
+        Duration::from_str("PT1H30M0.5S").unwrap().double().unwrap(),
+        Duration::new(0, 0, 0, 0, 2, 60, 0, 1000, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Duration::from_str("P2M3D").unwrap().scale_by(-2.0).unwrap(),
+        Duration::from_str("-P4M6D").unwrap()
+    );
+    assert!(Duration::from_str("P1M").unwrap().half().is_err());
+
+    assert!(year.scale_by(f64::NAN).is_err());
+    assert!(year.scale_by(f64::INFINITY).is_err());
+    assert!(year.scale_by(1e20).is_err());
+
+    let base = Duration::from_str("PT10M").unwrap();
+    let step = Duration::from_str("PT1S").unwrap();
+    assert_eq!(
+        base.add_scaled(&step, 90.5).unwrap(),
+        Duration::from_str("PT10M90.5S").unwrap()
+    );
+    assert!(base.add_scaled(&step, -1000.0).is_err());
+
+    // Subsecond fields past the maximum safe integer are scaled exactly.
+    let micros = Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 9_007_199_254_740_993, 1).unwrap();
+    assert_eq!(
+        micros.double().unwrap(),
+        Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 18_014_398_509_481_986, 2).unwrap()
+    );
+    assert_eq!(
+        Duration::from_str("PT0.000000003S")
+            .unwrap()
+            .scale_by(-1.5)
+            .unwrap(),
+        Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 0, -4).unwrap()
+    );
+}
+
+#[test]
+fn interpolate() {
+    let zero_days = Duration::from_str("P0D").unwrap();
+    let ten_days = Duration::from_str("P10D").unwrap();
+    let result = Duration::interpolate(&zero_days, &ten_days, 0.5).unwrap();
+    assert_eq!(result.days(), 5);
+    assert_eq!(result, Duration::from_str("P5D").unwrap());
+    assert_eq!(
+        Duration::interpolate(&zero_days, &ten_days, 0.0).unwrap(),
+        zero_days
+    );
+    assert_eq!(
+        Duration::interpolate(&zero_days, &ten_days, 1.0).unwrap(),
+        ten_days
+    );
+    // `t` is clamped to 0.0..=1.0.
+    assert_eq!(
+        Duration::interpolate(&zero_days, &ten_days, 2.0).unwrap(),
+        ten_days
+    );
+    assert_eq!(
+        Duration::interpolate(&zero_days, &ten_days, -1.0).unwrap(),
+        zero_days
+    );
+
+    let start = Duration::from_str("PT0S").unwrap();
+    let end = Duration::from_str("PT3600S").unwrap();
+    let result = Duration::interpolate(&start, &end, 1.0 / 3.0).unwrap();
+    let error = result
+        .subtract(&Duration::from_str("PT1200S").unwrap())
+        .unwrap()
+        .abs();
+    assert!(
+        error < Duration::from_str("PT0.000001S").unwrap(),
+        "{result}"
+    );
+    assert_eq!(result.hours(), 0);
+
+    let start = Duration::from_str("PT2H30M").unwrap();
+    let end = Duration::from_str("PT3H").unwrap();
+    let result = Duration::interpolate(&start, &end, 0.6).unwrap();
+    assert_eq!((result.hours(), result.minutes()), (2, 48));
+
+    let start = Duration::from_str("P1Y").unwrap();
+    let end = Duration::from_str("P2Y6M").unwrap();
+    let result = Duration::interpolate(&start, &end, 0.5).unwrap();
+    assert_eq!((result.years(), result.months()), (1, 3));
+
+    assert!(Duration::interpolate(&start, &end, f64::NAN).is_err());
+    assert!(Duration::interpolate(&start, &end, f64::INFINITY).is_err());
+}
@@ -13,7 +13,7 @@ use num_traits::Euclid;
 /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-time-duration-records
 /// [field spec]: https://tc39.es/proposal-temporal/#sec-properties-of-temporal-duration-instances
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 pub struct TimeDuration {
     /// `TimeDuration`'s internal hour value.
     pub hours: i64,
@@ -217,7 +217,7 @@ impl TimeDuration {
 
     /// Returns this `TimeDuration` as a `NormalizedTimeDuration`.
     #[inline]
-    pub(crate) fn to_normalized(self) -> NormalizedTimeDuration {
+    pub fn to_normalized(self) -> NormalizedTimeDuration {
         NormalizedTimeDuration::from_time_duration(&self)
     }
 
@@ -319,4 +319,11 @@ impl TimeDuration {
     pub fn sign(&self) -> Sign {
         duration_sign(&self.fields())
     }
+
+    /// Returns whether all fields of this `TimeDuration` are zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.sign() == Sign::Zero
+    }
 }
@@ -1,7 +1,7 @@
 //! An implementation of the Temporal Instant.
 
 use alloc::string::String;
-use core::{num::NonZeroU128, str::FromStr};
+use core::{cmp::Ordering, num::NonZeroU128, str::FromStr};
 
 use crate::{
     builtins::core::{
@@ -149,6 +149,9 @@ impl Instant {
 // ==== Public API ====
 
 impl Instant {
+    /// The Unix epoch, `1970-01-01T00:00:00Z`.
+    pub const UNIX_EPOCH: Self = Self(EpochNanoseconds(0));
+
     /// Create a new validated `Instant`.
     #[inline]
     pub fn try_new(nanoseconds: i128) -> TemporalResult<Self> {
@@ -200,7 +203,7 @@ impl Instant {
             ixdtf_record.time.second.clamp(0, 59).into(),
             millisecond.into(),
             microsecond.into(),
-            i128::from(nanosecond) - i128::from(ns_offset),
+            i64::from(nanosecond) - ns_offset,
         );
 
         let nanoseconds = balanced.as_nanoseconds()?;
@@ -274,6 +277,13 @@ impl Instant {
         &self.0
     }
 
+    /// Compares two `Instant`s, equivalent to `Temporal.Instant.compare`.
+    #[inline]
+    #[must_use]
+    pub fn compare(one: &Self, two: &Self) -> Ordering {
+        one.cmp(two)
+    }
+
     // TODO: May end up needing a provider API during impl
     pub fn to_zoned_date_time_iso(&self, time_zone: TimeZone) -> ZonedDateTime {
         ZonedDateTime::new_unchecked(*self, Calendar::default(), time_zone)
@@ -330,15 +340,33 @@ impl FromStr for Instant {
 #[cfg(test)]
 mod tests {
 
-    use core::str::FromStr;
+    use core::{cmp::Ordering, str::FromStr};
 
     use crate::{
         builtins::core::{duration::TimeDuration, Instant},
-        options::{DifferenceSettings, RoundingMode, Unit},
+        options::{DifferenceSettings, RoundingMode, RoundingOptions, Unit},
         unix_time::EpochNanoseconds,
         NS_MAX_INSTANT, NS_MIN_INSTANT,
     };
 
+    #[test]
+    fn unix_epoch_constant() {
+        assert_eq!(Instant::UNIX_EPOCH.as_i128(), 0);
+        assert_eq!(Instant::UNIX_EPOCH, Instant::try_new(0).unwrap());
+    }
+
+    #[test]
+    fn compare() {
+        let before = Instant::try_new(-1).unwrap();
+        let max = Instant::try_new(NS_MAX_INSTANT).unwrap();
+        assert_eq!(
+            Instant::compare(&before, &Instant::UNIX_EPOCH),
+            Ordering::Less
+        );
+        assert_eq!(Instant::compare(&max, &before), Ordering::Greater);
+        assert_eq!(Instant::compare(&max, &max), Ordering::Equal);
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn max_and_minimum_instant_bounds() {
@@ -574,6 +602,31 @@ mod tests {
         assert_time_duration(negative_result.time(), (-376435, -23, -8, -148, -529, -500));
     }
 
+    #[test]
+    fn instant_round_convenience_options() {
+        let instant = Instant::try_new(1_500_678_901_234).unwrap();
+
+        let default_mode = RoundingOptions {
+            smallest_unit: Some(Unit::Second),
+            ..Default::default()
+        };
+        let nearest = instant
+            .round(RoundingOptions::to_nearest(Unit::Second))
+            .unwrap();
+        assert_eq!(nearest, instant.round(default_mode).unwrap());
+        assert_eq!(nearest.as_i128(), 1_501_000_000_000);
+
+        let truncated = instant
+            .round(RoundingOptions::truncate(Unit::Second))
+            .unwrap();
+        assert_eq!(truncated.as_i128(), 1_500_000_000_000);
+
+        let nearest = instant
+            .round(RoundingOptions::to_nearest(Unit::Millisecond))
+            .unwrap();
+        assert_eq!(nearest.as_i128(), 1_500_679_000_000);
+    }
+
     // /test/built-ins/Temporal/Instant/prototype/add/cross-epoch.js
     #[cfg(feature = "tzdb")]
     #[test]
@@ -28,7 +28,7 @@ pub use date::{PartialDate, PlainDate};
 #[doc(inline)]
 pub use datetime::{PartialDateTime, PlainDateTime};
 #[doc(inline)]
-pub use duration::{DateDuration, Duration, PartialDuration, TimeDuration};
+pub use duration::{DateDuration, Duration, NormalizedTimeDuration, PartialDuration, TimeDuration};
 #[doc(inline)]
 pub use instant::Instant;
 #[doc(inline)]
@@ -43,8 +43,7 @@ impl PlainMonthDay {
         overflow: ArithmeticOverflow,
         ref_year: Option<i32>,
     ) -> TemporalResult<Self> {
-        let ry = ref_year.unwrap_or(1972);
-        // 1972 is the first leap year in the Unix epoch (needed to cover all dates)
+        let ry = ref_year.unwrap_or(IsoDate::MONTH_DAY_REFERENCE_YEAR);
         let iso = IsoDate::new_with_overflow(ry, month, day, overflow)?;
         Ok(Self::new_unchecked(iso, calendar))
     }
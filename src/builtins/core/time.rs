@@ -8,7 +8,7 @@ use crate::{
         RoundingIncrement, RoundingMode, ToStringRoundingOptions, Unit, UnitGroup,
     },
     parsers::{parse_time, IxdtfStringBuilder},
-    TemporalError, TemporalResult,
+    TemporalError, TemporalResult, NS_PER_DAY,
 };
 use alloc::string::String;
 use core::str::FromStr;
@@ -128,13 +128,17 @@ impl PlainTime {
     ///
     /// Spec Equivalent: `AddDurationToOrSubtractDurationFromPlainTime`.
     pub(crate) fn add_to_time(&self, duration: &TimeDuration) -> TemporalResult<Self> {
+        // NOTE: The day overflow is discarded, so the subsecond units can be
+        // reduced modulo a day before being narrowed for balancing.
+        let microseconds = duration.microseconds.rem_euclid(NS_PER_DAY as i128 / 1_000) as i64;
+        let nanoseconds = duration.nanoseconds.rem_euclid(NS_PER_DAY as i128) as i64;
         let (_, result) = IsoTime::balance(
             i64::from(self.hour()).saturating_add(duration.hours),
             i64::from(self.minute()).saturating_add(duration.minutes),
             i64::from(self.second()).saturating_add(duration.seconds),
             i64::from(self.millisecond()).saturating_add(duration.milliseconds),
-            i128::from(self.microsecond()).saturating_add(duration.microseconds),
-            i128::from(self.nanosecond()).saturating_add(duration.nanoseconds),
+            i64::from(self.microsecond()) + microseconds,
+            i64::from(self.nanosecond()) + nanoseconds,
         );
 
         // NOTE (nekevss): IsoTime::balance should never return an invalid `IsoTime`
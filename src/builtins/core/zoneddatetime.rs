@@ -151,7 +151,7 @@ impl ZonedDateTime {
         provider: &impl TimeZoneProvider,
     ) -> TemporalResult<Instant> {
         // 1. If DateDurationSign(duration.[[Date]]) = 0, then
-        if duration.date().sign() == Sign::Zero {
+        if duration.date().is_zero() {
             // a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
             return self.instant.add_to_instant(duration.time());
         }
@@ -1291,7 +1291,7 @@ pub(crate) fn interpret_isodatetime_offset(
                 time.second.into(),
                 time.millisecond.into(),
                 time.microsecond.into(),
-                (i64::from(time.nanosecond) - offset).into(),
+                i64::from(time.nanosecond) - offset,
             );
 
             // b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
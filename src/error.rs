@@ -2,6 +2,7 @@
 
 use alloc::borrow::Cow;
 use alloc::format;
+use alloc::string::String;
 use core::fmt;
 
 use icu_calendar::DateError;
@@ -132,6 +133,53 @@ impl TemporalError {
     pub fn from_icu4x(error: DateError) -> Self {
         TemporalError::range().with_message(format!("{error}"))
     }
+
+    /// Creates an error of the same kind as `inner` with `operation` prepended
+    /// to its message.
+    #[must_use]
+    pub fn context_of(operation: &str, inner: TemporalError) -> Self {
+        let msg = inner.msg.trim();
+        let msg = if msg.is_empty() {
+            Cow::Owned(String::from(operation))
+        } else {
+            Cow::Owned(format!("{operation}: {msg}"))
+        };
+        Self {
+            kind: inner.kind,
+            msg,
+        }
+    }
+}
+
+/// Context helpers for a `TemporalResult`.
+pub trait TemporalResultExt<T> {
+    /// Prepends `msg` to the message of the error.
+    fn with_context<S: Into<String>>(self, msg: S) -> Result<T, TemporalError>;
+
+    /// Prepends the message returned from `f` to the message of the error.
+    ///
+    /// `f` is only called if the result is an error.
+    fn context<F: FnOnce() -> String>(self, f: F) -> Result<T, TemporalError>;
+
+    /// Replaces the kind of the error with `kind`.
+    fn map_err_kind(self, kind: ErrorKind) -> Result<T, TemporalError>;
+}
+
+impl<T> TemporalResultExt<T> for Result<T, TemporalError> {
+    #[inline]
+    fn with_context<S: Into<String>>(self, msg: S) -> Result<T, TemporalError> {
+        self.map_err(|e| TemporalError::context_of(&msg.into(), e))
+    }
+
+    #[inline]
+    fn context<F: FnOnce() -> String>(self, f: F) -> Result<T, TemporalError> {
+        self.map_err(|e| TemporalError::context_of(&f(), e))
+    }
+
+    #[inline]
+    fn map_err_kind(self, kind: ErrorKind) -> Result<T, TemporalError> {
+        self.map_err(|e| TemporalError { kind, ..e })
+    }
 }
 
 impl fmt::Display for TemporalError {
@@ -146,3 +194,41 @@ impl fmt::Display for TemporalError {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorKind, TemporalError, TemporalResultExt};
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn error_context() {
+        let result: Result<(), TemporalError> =
+            Err(TemporalError::range().with_message("not a valid ISO date."));
+
+        let err = result.clone().with_context("month field").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(err.message(), "month field: not a valid ISO date.");
+        assert_eq!(
+            err.to_string(),
+            "RangeError: month field: not a valid ISO date."
+        );
+
+        let err = result
+            .clone()
+            .context(|| String::from("day field"))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(err.message(), "day field: not a valid ISO date.");
+
+        let err = result.map_err_kind(ErrorKind::Type).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Type);
+        assert_eq!(err.message(), "not a valid ISO date.");
+
+        let ok: Result<u8, TemporalError> = Ok(1);
+        assert_eq!(ok.context(|| unreachable!("context must be lazy")), Ok(1));
+
+        let err = TemporalError::context_of("AddISODate", TemporalError::range());
+        assert_eq!(err.kind(), ErrorKind::Range);
+        assert_eq!(err.message(), "AddISODate");
+    }
+}
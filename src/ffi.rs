@@ -0,0 +1,340 @@
+//! A minimal C interface to the ISO date and time records.
+//!
+//! This module is enabled with the `ffi` feature and is intended for embedding
+//! `temporal_rs` in non-Rust projects that only need plain ISO dates and times.
+//! The full API, including calendars, time zones, and durations, is available
+//! over FFI from the `temporal_capi` crate.
+//!
+//! The types in this module are `#[repr(C)]`, and every function returns a status
+//! code: [`TEMPORAL_OK`] on success, or one of the negative `TEMPORAL_ERROR_*`
+//! codes on failure. Results are written to caller-provided out pointers, which
+//! are left untouched on failure.
+//!
+//! A C header for this module is generated with [cbindgen] using the
+//! `cbindgen.toml` configuration in the repository root, and is checked in as
+//! `include/temporal_ffi.h`.
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+use core::ffi::{c_char, CStr};
+
+use crate::{
+    error::ErrorKind,
+    iso::{IsoDate, IsoDateTime, IsoTime},
+    options::ArithmeticOverflow,
+    DateDuration, TemporalError, TemporalResult,
+};
+
+/// The operation succeeded.
+pub const TEMPORAL_OK: i32 = 0;
+/// A value was out of range, e.g. an invalid date or a date outside of the `Temporal` limits.
+pub const TEMPORAL_ERROR_RANGE: i32 = -1;
+/// A string could not be parsed.
+pub const TEMPORAL_ERROR_SYNTAX: i32 = -2;
+/// A value had an invalid type.
+pub const TEMPORAL_ERROR_TYPE: i32 = -3;
+/// Any other error.
+pub const TEMPORAL_ERROR_GENERIC: i32 = -4;
+/// A required pointer argument was null.
+pub const TEMPORAL_ERROR_NULL_POINTER: i32 = -5;
+/// The provided buffer is too small for the result.
+pub const TEMPORAL_ERROR_BUFFER_TOO_SMALL: i32 = -6;
+
+/// A C-compatible ISO calendar date.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemporalDateC {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// A C-compatible wall-clock time.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemporalTimeC {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub millisecond: u16,
+    pub microsecond: u16,
+    pub nanosecond: u16,
+}
+
+/// A C-compatible ISO date and time.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemporalDateTimeC {
+    pub date: TemporalDateC,
+    pub time: TemporalTimeC,
+}
+
+impl From<IsoDate> for TemporalDateC {
+    fn from(value: IsoDate) -> Self {
+        Self {
+            year: value.year,
+            month: value.month,
+            day: value.day,
+        }
+    }
+}
+
+impl From<IsoTime> for TemporalTimeC {
+    fn from(value: IsoTime) -> Self {
+        Self {
+            hour: value.hour,
+            minute: value.minute,
+            second: value.second,
+            millisecond: value.millisecond,
+            microsecond: value.microsecond,
+            nanosecond: value.nanosecond,
+        }
+    }
+}
+
+impl From<IsoDateTime> for TemporalDateTimeC {
+    fn from(value: IsoDateTime) -> Self {
+        Self {
+            date: value.date.into(),
+            time: value.time.into(),
+        }
+    }
+}
+
+impl TemporalDateC {
+    /// Validates this date and converts it into an `IsoDate`.
+    fn to_iso(self) -> TemporalResult<IsoDate> {
+        IsoDate::new_with_overflow(self.year, self.month, self.day, ArithmeticOverflow::Reject)
+    }
+}
+
+impl TemporalTimeC {
+    /// Validates this time and converts it into an `IsoTime`.
+    fn to_iso(self) -> TemporalResult<IsoTime> {
+        IsoTime::new(
+            self.hour,
+            self.minute,
+            self.second,
+            self.millisecond,
+            self.microsecond,
+            self.nanosecond,
+            ArithmeticOverflow::Reject,
+        )
+    }
+}
+
+impl TemporalDateTimeC {
+    /// Validates this date and time and converts it into an `IsoDateTime`.
+    fn to_iso(self) -> TemporalResult<IsoDateTime> {
+        IsoDateTime::new(self.date.to_iso()?, self.time.to_iso()?)
+    }
+}
+
+/// Maps a `TemporalError` to its status code.
+fn error_code(error: &TemporalError) -> i32 {
+    match error.kind() {
+        ErrorKind::Range => TEMPORAL_ERROR_RANGE,
+        ErrorKind::Syntax => TEMPORAL_ERROR_SYNTAX,
+        ErrorKind::Type => TEMPORAL_ERROR_TYPE,
+        ErrorKind::Generic | ErrorKind::Assert => TEMPORAL_ERROR_GENERIC,
+    }
+}
+
+/// Writes the value of `result` to `out` and returns the status code.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `U`.
+unsafe fn write_result<T, U: From<T>>(result: TemporalResult<T>, out: *mut U) -> i32 {
+    if out.is_null() {
+        return TEMPORAL_ERROR_NULL_POINTER;
+    }
+    match result {
+        Ok(value) => {
+            // SAFETY: `out` is non-null and valid for writes as upheld by the caller.
+            unsafe { out.write(value.into()) };
+            TEMPORAL_OK
+        }
+        Err(error) => error_code(&error),
+    }
+}
+
+/// Creates a validated date and writes it to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `TemporalDateC`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_date_new(
+    year: i32,
+    month: u8,
+    day: u8,
+    out: *mut TemporalDateC,
+) -> i32 {
+    let date = TemporalDateC { year, month, day };
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(date.to_iso(), out) }
+}
+
+/// Adds `days` to `date` and writes the resulting date to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `TemporalDateC`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_date_add_days(
+    date: TemporalDateC,
+    days: i64,
+    out: *mut TemporalDateC,
+) -> i32 {
+    let result = date.to_iso().and_then(|date| {
+        date.add(
+            &DateDuration::new(0, 0, 0, days)?,
+            ArithmeticOverflow::Reject,
+        )
+    });
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(result, out) }
+}
+
+/// Writes the number of days from `start` until `end` to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_date_days_between(
+    start: TemporalDateC,
+    end: TemporalDateC,
+    out: *mut i64,
+) -> i32 {
+    let result = start.to_iso().and_then(|start| {
+        let end = end.to_iso()?;
+        Ok(i64::from(end.to_epoch_days()) - i64::from(start.to_epoch_days()))
+    });
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(result, out) }
+}
+
+/// Writes `date` as a NUL-terminated ISO 8601 string, e.g. `2024-03-15`, to `buffer`.
+///
+/// The length of the string, excluding the NUL terminator, is written to `written`
+/// if it is not null, including when `capacity` is too small for the string.
+///
+/// # Safety
+///
+/// `buffer` must be null or valid for writes of `capacity` bytes, and `written` must
+/// be null or valid for writes of `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_date_format(
+    date: TemporalDateC,
+    buffer: *mut c_char,
+    capacity: usize,
+    written: *mut usize,
+) -> i32 {
+    if buffer.is_null() {
+        return TEMPORAL_ERROR_NULL_POINTER;
+    }
+    let string = match date.to_iso() {
+        Ok(date) => date.to_iso_string(),
+        Err(error) => return error_code(&error),
+    };
+    if !written.is_null() {
+        // SAFETY: `written` is non-null and valid for writes as upheld by the caller.
+        unsafe { written.write(string.len()) };
+    }
+    if string.len() >= capacity {
+        return TEMPORAL_ERROR_BUFFER_TOO_SMALL;
+    }
+    // SAFETY: `buffer` is valid for `capacity` bytes, which is greater than the length
+    // of the string, as upheld by the caller.
+    unsafe {
+        core::ptr::copy_nonoverlapping(string.as_ptr(), buffer.cast::<u8>(), string.len());
+        buffer.add(string.len()).write(0);
+    }
+    TEMPORAL_OK
+}
+
+/// Parses a NUL-terminated ISO 8601 date string, e.g. `2024-03-15`, and writes the
+/// date to `out`.
+///
+/// # Safety
+///
+/// `source` must be null or a valid NUL-terminated string, and `out` must be null or
+/// valid for writes of `TemporalDateC`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_date_parse(
+    source: *const c_char,
+    out: *mut TemporalDateC,
+) -> i32 {
+    if source.is_null() {
+        return TEMPORAL_ERROR_NULL_POINTER;
+    }
+    // SAFETY: `source` is a valid NUL-terminated string as upheld by the caller.
+    let source = unsafe { CStr::from_ptr(source) };
+    let result = source
+        .to_str()
+        .map_err(|_| TemporalError::syntax().with_message("Date string must be valid UTF-8."))
+        .and_then(str::parse::<IsoDate>);
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(result, out) }
+}
+
+/// Creates a validated time and writes it to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `TemporalTimeC`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_time_new(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+    microsecond: u16,
+    nanosecond: u16,
+    out: *mut TemporalTimeC,
+) -> i32 {
+    let time = TemporalTimeC {
+        hour,
+        minute,
+        second,
+        millisecond,
+        microsecond,
+        nanosecond,
+    };
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(time.to_iso(), out) }
+}
+
+/// Writes the UTC date and time of `epoch_millis` milliseconds since the Unix epoch
+/// to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `TemporalDateTimeC`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_datetime_from_epoch_millis(
+    epoch_millis: i64,
+    out: *mut TemporalDateTimeC,
+) -> i32 {
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(IsoDateTime::from_unix_timestamp_millis(epoch_millis), out) }
+}
+
+/// Writes the milliseconds since the Unix epoch of `date_time`, read as UTC, to `out`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_datetime_to_epoch_millis(
+    date_time: TemporalDateTimeC,
+    out: *mut i64,
+) -> i32 {
+    let result = date_time
+        .to_iso()
+        .map(|date_time| date_time.to_epoch_millis() as i64);
+    // SAFETY: Upheld by the caller.
+    unsafe { write_result(result, out) }
+}
@@ -22,8 +22,14 @@
 //!
 //! An `IsoDateTime` has the internal slots of both an `IsoDate` and `IsoTime`.
 
-use core::num::NonZeroU128;
-use ixdtf::parsers::records::TimeRecord;
+use core::{cmp::Ordering, fmt, num::NonZeroU128, str::FromStr};
+use ixdtf::{
+    parsers::{
+        records::{TimeRecord, UtcOffsetRecordOrZ},
+        IxdtfParser,
+    },
+    ParseError,
+};
 
 use crate::{
     builtins::core::{
@@ -32,21 +38,288 @@ use crate::{
             normalized::{NormalizedDurationRecord, NormalizedTimeDuration},
             DateDuration, TimeDuration,
         },
-        Duration, PartialTime, PlainDate,
+        Duration, PartialTime, PlainDate, PlainDateTime,
+    },
+    error::{TemporalError, TemporalResultExt},
+    options::{
+        ArithmeticOverflow, DifferenceOperation, DifferenceSettings, DisplayCalendar,
+        DisplayOffset, ResolvedRoundingOptions, RoundingIncrement, RoundingMode, Unit, UnitGroup,
+    },
+    parsers::{
+        format_dtg, format_http_date, parse_annotated_date_time, parse_date_time, parse_dtg,
+        parse_ecma_date_time, parse_extended_time, parse_http_date, parse_lenient_date,
+        parse_partial_date_time, parse_rfc3339_time, IxdtfStringBuilder, ParseHint, Precision,
     },
-    error::TemporalError,
-    options::{ArithmeticOverflow, ResolvedRoundingOptions, Unit},
-    rounding::{IncrementRounder, Round},
+    rounding::{apply_unsigned_rounding_mode, IncrementRounder, Round},
     temporal_assert,
     unix_time::EpochNanoseconds,
-    utils, TemporalResult, TemporalUnwrap, NS_PER_DAY,
+    utils, Sign, TemporalResult, TemporalUnwrap, TimeZone, UtcOffset, NS_PER_DAY,
 };
+use alloc::{format, string::String, vec::Vec};
 use icu_calendar::{Date as IcuDate, Iso};
 use num_traits::{cast::FromPrimitive, Euclid};
 
+#[cfg(feature = "serde")]
+mod serde;
+
+/// A day of the week, numbered according to ISO 8601 from Monday (1) to Sunday (7).
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DayOfWeek {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+}
+
+impl DayOfWeek {
+    /// Returns the `DayOfWeek` for an ISO 8601 day number, from Monday (1) to
+    /// Sunday (7), or `None` if `day` is out of range.
+    pub const fn from_iso_number(day: u8) -> Option<Self> {
+        Some(match day {
+            1 => Self::Monday,
+            2 => Self::Tuesday,
+            3 => Self::Wednesday,
+            4 => Self::Thursday,
+            5 => Self::Friday,
+            6 => Self::Saturday,
+            7 => Self::Sunday,
+            _ => return None,
+        })
+    }
+}
+
+/// The layout of a month grid from [`IsoDate::to_week_grid`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GridLocale {
+    /// Weeks start on Monday, as in ISO 8601.
+    #[default]
+    IsoMondayStart,
+    /// Weeks start on Sunday, as in the United States.
+    USSundayStart,
+}
+
+impl GridLocale {
+    /// Returns the day of the week in the first column of the grid.
+    #[inline]
+    pub const fn first_day_of_week(&self) -> DayOfWeek {
+        match self {
+            Self::IsoMondayStart => DayOfWeek::Monday,
+            Self::USSundayStart => DayOfWeek::Sunday,
+        }
+    }
+}
+
+/// The result of parsing an `IsoDateTime` string along with its offset and annotations.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDateTime {
+    /// The parsed `IsoDateTime`.
+    pub datetime: IsoDateTime,
+    /// The UTC offset in seconds, where `Z` is represented as `0`.
+    pub offset_seconds: Option<i32>,
+    /// The time zone annotation's identifier.
+    pub time_zone: Option<String>,
+    /// The calendar annotation's identifier.
+    pub calendar: Option<String>,
+}
+
+/// The fields present in an incomplete ISO 8601 date-time string, as parsed by
+/// [`IsoDateTime::parse_partial`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PartialIsoDateTime {
+    /// The ISO year.
+    pub year: Option<i32>,
+    /// The ISO month.
+    pub month: Option<u8>,
+    /// The ISO day.
+    pub day: Option<u8>,
+    /// The hour.
+    pub hour: Option<u8>,
+    /// The minute.
+    pub minute: Option<u8>,
+    /// The second.
+    pub second: Option<u8>,
+    /// The millisecond.
+    pub millisecond: Option<u16>,
+    /// The microsecond.
+    pub microsecond: Option<u16>,
+    /// The nanosecond.
+    pub nanosecond: Option<u16>,
+}
+
+impl PartialIsoDateTime {
+    /// Creates an `IsoDate` from the date fields, regulating them according to
+    /// `overflow`.
+    ///
+    /// The year, month, and day are all required.
+    pub fn to_iso_date(&self, overflow: ArithmeticOverflow) -> TemporalResult<IsoDate> {
+        let (Some(year), Some(month), Some(day)) = (self.year, self.month, self.day) else {
+            return Err(TemporalError::r#type()
+                .with_message("year, month, and day are required for a date."));
+        };
+        IsoDate::new_with_overflow(year, month, day, overflow)
+    }
+
+    /// Creates an `IsoTime` from the time fields, regulating them according to
+    /// `overflow`.
+    ///
+    /// The hour is required, and any other missing fields are zero.
+    pub fn to_iso_time(&self, overflow: ArithmeticOverflow) -> TemporalResult<IsoTime> {
+        let Some(hour) = self.hour else {
+            return Err(TemporalError::r#type().with_message("hour is required for a time."));
+        };
+        IsoTime::new(
+            hour,
+            self.minute.unwrap_or_default(),
+            self.second.unwrap_or_default(),
+            self.millisecond.unwrap_or_default(),
+            self.microsecond.unwrap_or_default(),
+            self.nanosecond.unwrap_or_default(),
+            overflow,
+        )
+    }
+
+    /// Creates an `IsoDateTime` from these fields, taking any missing fields from
+    /// `defaults`.
+    ///
+    /// The merged fields must form a valid `IsoDateTime`, e.g. merging `2024-02` with a
+    /// default day of 31 is a `RangeError`.
+    pub fn merge_with_defaults(&self, defaults: &IsoDateTime) -> TemporalResult<IsoDateTime> {
+        let defaults = defaults.to_components();
+        IsoDateTimeComponents {
+            year: self.year.unwrap_or(defaults.year),
+            month: self.month.unwrap_or(defaults.month),
+            day: self.day.unwrap_or(defaults.day),
+            hour: self.hour.unwrap_or(defaults.hour),
+            minute: self.minute.unwrap_or(defaults.minute),
+            second: self.second.unwrap_or(defaults.second),
+            millisecond: self.millisecond.unwrap_or(defaults.millisecond),
+            microsecond: self.microsecond.unwrap_or(defaults.microsecond),
+            nanosecond: self.nanosecond.unwrap_or(defaults.nanosecond),
+        }
+        .to_iso_date_time(ArithmeticOverflow::Reject)
+    }
+}
+
+/// The individual fields of an `IsoDateTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsoDateTimeComponents {
+    /// The ISO year.
+    pub year: i32,
+    /// The ISO month.
+    pub month: u8,
+    /// The ISO day.
+    pub day: u8,
+    /// The hour.
+    pub hour: u8,
+    /// The minute.
+    pub minute: u8,
+    /// The second.
+    pub second: u8,
+    /// The millisecond.
+    pub millisecond: u16,
+    /// The microsecond.
+    pub microsecond: u16,
+    /// The nanosecond.
+    pub nanosecond: u16,
+}
+
+impl IsoDateTimeComponents {
+    /// Creates an `IsoDateTime` from these components, regulating the date and time
+    /// fields according to `overflow`.
+    pub fn to_iso_date_time(self, overflow: ArithmeticOverflow) -> TemporalResult<IsoDateTime> {
+        let date = IsoDate::new_with_overflow(self.year, self.month, self.day, overflow)?;
+        let time = IsoTime::new(
+            self.hour,
+            self.minute,
+            self.second,
+            self.millisecond,
+            self.microsecond,
+            self.nanosecond,
+            overflow,
+        )?;
+        IsoDateTime::new(date, time)
+    }
+}
+
+impl Default for IsoDateTimeComponents {
+    fn default() -> Self {
+        IsoDateTime::new_unchecked(IsoDate::new_unchecked(0, 1, 1), IsoTime::MIDNIGHT)
+            .to_components()
+    }
+}
+
+impl From<IsoDateTime> for IsoDateTimeComponents {
+    fn from(value: IsoDateTime) -> Self {
+        value.to_components()
+    }
+}
+
+impl From<IsoDate> for IsoDateTimeComponents {
+    fn from(value: IsoDate) -> Self {
+        IsoDateTime::new_unchecked(value, IsoTime::MIDNIGHT).to_components()
+    }
+}
+
+impl From<IsoTime> for IsoDateTimeComponents {
+    fn from(value: IsoTime) -> Self {
+        IsoDateTime::new_unchecked(IsoDate::EPOCH, value).to_components()
+    }
+}
+
+/// A wrapper for chaining fallible operations on a value without binding each
+/// intermediate result, e.g. `date.builder().try_map(..).map(..).unwrap_result()`.
+///
+/// The first error encountered is kept and every later operation is skipped.
+#[derive(Debug, Clone, PartialEq)]
+#[must_use]
+pub struct TemporalBuilder<T>(TemporalResult<T>);
+
+impl<T> TemporalBuilder<T> {
+    /// Creates a new `TemporalBuilder` wrapping `value`.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(Ok(value))
+    }
+
+    /// Applies an infallible operation to the wrapped value.
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> TemporalBuilder<U> {
+        TemporalBuilder(self.0.map(f))
+    }
+
+    /// Applies a fallible operation to the wrapped value.
+    #[inline]
+    pub fn try_map<U>(self, f: impl FnOnce(T) -> TemporalResult<U>) -> TemporalBuilder<U> {
+        TemporalBuilder(self.0.and_then(f))
+    }
+
+    /// Applies a final fallible operation to the wrapped value and returns its result.
+    #[inline]
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> TemporalResult<U>) -> TemporalResult<U> {
+        self.0.and_then(f)
+    }
+
+    /// Returns the wrapped value, or the first error encountered.
+    #[inline]
+    pub fn unwrap_result(self) -> TemporalResult<T> {
+        self.0
+    }
+}
+
+impl<T> From<TemporalResult<T>> for TemporalBuilder<T> {
+    fn from(value: TemporalResult<T>) -> Self {
+        Self(value)
+    }
+}
+
 /// `IsoDateTime` is the record of the `IsoDate` and `IsoTime` internal slots.
 #[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsoDateTime {
     /// The `IsoDate` fields.
     pub date: IsoDate,
@@ -54,9 +327,104 @@ pub struct IsoDateTime {
     pub time: IsoTime,
 }
 
+impl fmt::Debug for IsoDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IsoDateTime({})",
+            self.to_iso_string_with_calendar("iso8601", None)
+        )
+    }
+}
+
+impl fmt::Display for IsoDateTime {
+    /// Writes this `IsoDateTime` as an ISO 8601 date-time string, e.g.
+    /// `2024-03-15T14:30:05.001002003`.
+    ///
+    /// The time is always written, even at midnight.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl FromStr for IsoDateTime {
+    type Err = TemporalError;
+
+    /// Parses an ISO 8601 extended date-time string, e.g.
+    /// `2024-03-15T14:30:05.001002003`.
+    ///
+    /// A trailing `Z` is accepted and ignored, while numeric offsets and annotations
+    /// are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, time) = s
+            .split_once(['T', 't'])
+            .ok_or_else(|| TemporalError::syntax().with_message("Date-time requires a time."))?;
+        let time = time.strip_suffix(['Z', 'z']).unwrap_or(time);
+        Self::new(date.parse()?, time.parse()?)
+    }
+}
+
 impl IsoDateTime {
+    /// The Unix epoch, `1970-01-01T00:00:00`.
+    pub const UNIX_EPOCH: Self = Self::new_unchecked(IsoDate::EPOCH, IsoTime::MIDNIGHT);
+
     /// Creates a new `IsoDateTime` without any validaiton.
-    pub(crate) fn new_unchecked(date: IsoDate, time: IsoTime) -> Self {
+    pub(crate) const fn new_unchecked(date: IsoDate, time: IsoTime) -> Self {
+        Self { date, time }
+    }
+
+    /// Creates a new `IsoDateTime` in a const context, panicking if it is not within
+    /// valid limits.
+    ///
+    /// When used to initialize a `const`, an out of range value is a compile error.
+    ///
+    /// ```rust
+    /// use temporal_rs::iso::{IsoDate, IsoDateTime, IsoTime};
+    ///
+    /// const LAUNCH: IsoDateTime = IsoDateTime::const_new(
+    ///     IsoDate::new_checked(1969, 7, 16),
+    ///     IsoTime::const_new(13, 32, 0, 0, 0, 0),
+    /// );
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use temporal_rs::iso::{IsoDate, IsoDateTime, IsoTime};
+    ///
+    /// const BEFORE_MIN: IsoDateTime = IsoDateTime::const_new(
+    ///     IsoDate::new_checked(-271_821, 4, 19),
+    ///     IsoTime::MIDNIGHT,
+    /// );
+    /// ```
+    pub const fn const_new(date: IsoDate, time: IsoTime) -> Self {
+        if !is_valid_date(date.year, date.month, date.day)
+            || !is_valid_time(
+                time.hour,
+                time.minute,
+                time.second,
+                time.millisecond,
+                time.microsecond,
+                time.nanosecond,
+            )
+        {
+            panic!("IsoDateTime fields are not valid.");
+        }
+        let after_min = date.year > -271_821
+            || (date.year == -271_821
+                && (date.month > 4
+                    || (date.month == 4
+                        && (date.day > 19
+                            || (date.day == 19
+                                && (time.hour > 0
+                                    || time.minute > 0
+                                    || time.second > 0
+                                    || time.millisecond > 0
+                                    || time.microsecond > 0
+                                    || time.nanosecond > 0))))));
+        let before_max = date.year < 275_760
+            || (date.year == 275_760 && (date.month < 9 || (date.month == 9 && date.day <= 13)));
+        if !after_min || !before_max {
+            panic!("IsoDateTime not within a valid range.");
+        }
         Self { date, time }
     }
 
@@ -115,8 +483,8 @@ impl IsoDateTime {
             minute,
             second,
             millis,
-            micros.into(),
-            (nanos + offset).into(),
+            micros,
+            nanos + offset,
         ))
     }
 
@@ -129,8 +497,8 @@ impl IsoDateTime {
         minute: i64,
         second: i64,
         millisecond: i64,
-        microsecond: i128,
-        nanosecond: i128,
+        microsecond: i64,
+        nanosecond: i64,
     ) -> Self {
         let (overflow_day, time) =
             IsoTime::balance(hour, minute, second, millisecond, microsecond, nanosecond);
@@ -139,6 +507,28 @@ impl IsoDateTime {
         Self::new_unchecked(date, time)
     }
 
+    /// Returns all of the fields of this `IsoDateTime`.
+    #[inline]
+    pub const fn to_components(&self) -> IsoDateTimeComponents {
+        IsoDateTimeComponents {
+            year: self.date.year,
+            month: self.date.month,
+            day: self.date.day,
+            hour: self.time.hour,
+            minute: self.time.minute,
+            second: self.time.second,
+            millisecond: self.time.millisecond,
+            microsecond: self.time.microsecond,
+            nanosecond: self.time.nanosecond,
+        }
+    }
+
+    /// Returns a `TemporalBuilder` for chaining operations on this `IsoDateTime`.
+    #[inline]
+    pub fn builder(&self) -> TemporalBuilder<Self> {
+        TemporalBuilder::new(*self)
+    }
+
     /// Returns whether the `IsoDateTime` is within valid limits.
     pub(crate) fn is_within_limits(&self) -> bool {
         iso_dt_within_valid_limits(self.date, &self.time)
@@ -149,6 +539,308 @@ impl IsoDateTime {
         utc_epoch_nanos(self.date, &self.time)
     }
 
+    /// Returns the epoch nanoseconds of this `IsoDateTime`, taken to be in UTC.
+    ///
+    /// This is an alias of [`IsoDateTime::as_nanoseconds`], and errors if the result
+    /// is outside of the range of an `Instant`.
+    #[inline]
+    pub fn to_epoch_nanos(&self) -> TemporalResult<EpochNanoseconds> {
+        self.as_nanoseconds()
+    }
+
+    /// Returns the epoch milliseconds of this `IsoDateTime`, taken to be in UTC, as
+    /// used by ECMAScript's `Date`.
+    ///
+    /// Any submillisecond value is dropped.
+    #[inline]
+    pub fn to_epoch_millis(&self) -> f64 {
+        utils::epoch_days_to_epoch_ms(
+            i64::from(self.date.to_epoch_days()),
+            self.time.to_epoch_ms(),
+        ) as f64
+    }
+
+    /// Creates an `IsoDateTime` from epoch milliseconds, as used by ECMAScript's `Date`.
+    ///
+    /// `millis` must be an integer, and the result must be within the range of an
+    /// `Instant`.
+    pub fn from_epoch_millis(millis: f64) -> TemporalResult<Self> {
+        if !millis.is_finite() || millis as i64 as f64 != millis {
+            return Err(
+                TemporalError::range().with_message("Epoch milliseconds must be an integer.")
+            );
+        }
+        Self::from_unix_timestamp_millis(millis as i64)
+    }
+
+    /// Returns the epoch seconds of this `IsoDateTime`, taken to be in UTC.
+    ///
+    /// The result has millisecond precision, as with [`IsoDateTime::to_epoch_millis`].
+    #[inline]
+    pub fn to_epoch_seconds(&self) -> f64 {
+        self.to_epoch_millis() / 1000.0
+    }
+
+    /// Creates an `IsoDateTime` from epoch seconds, discarding any subsecond value by
+    /// flooring `seconds`.
+    pub fn from_epoch_seconds(seconds: f64) -> TemporalResult<Self> {
+        if !seconds.is_finite() {
+            return Err(TemporalError::range().with_message("Epoch seconds must be finite."));
+        }
+        Self::from_unix_timestamp_secs(floor_to_i64(seconds))
+    }
+
+    /// Creates an `IsoDateTime` from Unix timestamp seconds.
+    pub fn from_unix_timestamp_secs(secs: i64) -> TemporalResult<Self> {
+        Self::from_unix_timestamp_nanos(i128::from(secs) * 1_000_000_000)
+    }
+
+    /// Creates an `IsoDateTime` from Unix timestamp milliseconds.
+    pub fn from_unix_timestamp_millis(ms: i64) -> TemporalResult<Self> {
+        Self::from_unix_timestamp_nanos(i128::from(ms) * 1_000_000)
+    }
+
+    /// Creates an `IsoDateTime` from Unix timestamp nanoseconds.
+    pub fn from_unix_timestamp_nanos(ns: i128) -> TemporalResult<Self> {
+        let epoch_nanoseconds = EpochNanoseconds::try_from(ns)?;
+        Self::from_epoch_nanos(&epoch_nanoseconds, 0)
+    }
+
+    /// Returns this `IsoDateTime` as Unix timestamp seconds, flooring any subsecond value.
+    pub fn to_unix_timestamp_secs(&self) -> TemporalResult<i64> {
+        // NOTE: The cast is safe as the nanoseconds are within a valid epoch range.
+        Ok(self.to_unix_timestamp_nanos()?.div_euclid(1_000_000_000) as i64)
+    }
+
+    /// Returns this `IsoDateTime` as Unix timestamp milliseconds, flooring any submillisecond value.
+    pub fn to_unix_timestamp_millis(&self) -> TemporalResult<i64> {
+        // NOTE: The cast is safe as the nanoseconds are within a valid epoch range.
+        Ok(self.to_unix_timestamp_nanos()?.div_euclid(1_000_000) as i64)
+    }
+
+    /// Returns this `IsoDateTime` as Unix timestamp nanoseconds.
+    pub fn to_unix_timestamp_nanos(&self) -> TemporalResult<i128> {
+        self.as_nanoseconds().map(|ns| ns.as_i128())
+    }
+
+    /// Parses an RFC 9557 date-time string, returning the `IsoDateTime` along with its
+    /// UTC offset, time zone annotation, and calendar annotation if present,
+    /// e.g. `2024-03-15T12:00:00+01:00[Europe/Paris][u-ca=persian]`.
+    ///
+    /// Any fractional seconds of the UTC offset are truncated.
+    pub fn parse_with_annotations(s: &str) -> TemporalResult<ParsedDateTime> {
+        let record = parse_annotated_date_time(s.as_bytes())?;
+        let date = record.date.temporal_unwrap()?;
+        let date = IsoDate::new_with_overflow(
+            date.year,
+            date.month,
+            date.day,
+            ArithmeticOverflow::Reject,
+        )?;
+        let time = record
+            .time
+            .map(IsoTime::from_time_record)
+            .transpose()?
+            .unwrap_or_default();
+        let offset_seconds = record.offset.map(|offset| match offset {
+            UtcOffsetRecordOrZ::Offset(offset) => {
+                let seconds = offset.hour() as i32 * 3600
+                    + i32::from(offset.minute()) * 60
+                    + i32::from(offset.second().unwrap_or(0));
+                seconds * offset.sign() as i32
+            }
+            UtcOffsetRecordOrZ::Z => 0,
+        });
+        let time_zone = record
+            .tz
+            .map(|annotation| TimeZone::from_time_zone_record(annotation.tz)?.identifier())
+            .transpose()?;
+        Ok(ParsedDateTime {
+            datetime: Self::new(date, time)?,
+            offset_seconds,
+            time_zone,
+            calendar: record.calendar.map(calendar_identifier).transpose()?,
+        })
+    }
+
+    /// Returns an ISO 8601 string for this `IsoDateTime`, with an optional UTC offset
+    /// and a calendar annotation unless `calendar_id` is `"iso8601"`,
+    /// e.g. `2024-03-15T12:30:00+01:00[u-ca=hebrew]`.
+    pub fn to_iso_string_with_calendar(
+        &self,
+        calendar_id: &str,
+        offset: Option<UtcOffset>,
+    ) -> String {
+        let mut builder = IxdtfStringBuilder::default()
+            .with_date(self.date)
+            .with_time(self.time, Precision::Auto);
+        if let Some(UtcOffset(minutes)) = offset {
+            let sign = if minutes < 0 {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            };
+            let (hour, minute) = (minutes.unsigned_abs() / 60, minutes.unsigned_abs() % 60);
+            builder =
+                builder.with_minute_offset(sign, hour as u8, minute as u8, DisplayOffset::Auto);
+        }
+        builder
+            .with_calendar(calendar_id, DisplayCalendar::Auto)
+            .build()
+    }
+
+    /// Parses an incomplete ISO 8601 date-time string into whichever fields are
+    /// present, e.g. `2024-03`, `2024-03-15T14`, or `T14:30`.
+    ///
+    /// The date may be `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`, and the time, which must be
+    /// prefixed by `T`, may be `HH`, `HH:MM`, or `HH:MM:SS` with an optional fraction.
+    /// The fields are not validated until they are converted, e.g. with
+    /// [`PartialIsoDateTime::to_iso_date`].
+    #[inline]
+    pub fn parse_partial(s: &str) -> TemporalResult<PartialIsoDateTime> {
+        parse_partial_date_time(s)
+    }
+
+    /// Returns this `IsoDateTime` as an ECMAScript date time string in UTC, i.e. the
+    /// format of `Date.prototype.toISOString()`, e.g. `2024-03-15T14:30:00.000Z`.
+    ///
+    /// Unlike the ISO 8601 strings, exactly three fractional second digits are always
+    /// written, and any submillisecond value is truncated.
+    pub fn to_ecma_date_time_string(&self) -> String {
+        IxdtfStringBuilder::default()
+            .with_date(self.date)
+            .with_time(self.time, Precision::Digit(3))
+            .with_z(DisplayOffset::Auto)
+            .build()
+    }
+
+    /// Parses an ECMAScript date time string, e.g. `2024-03-15T14:30:00.000Z`.
+    ///
+    /// Only the exact format of `Date.prototype.toISOString()` is accepted: all
+    /// fields, exactly three fractional second digits, and the `Z` designator must
+    /// be present.
+    pub fn from_ecma_date_time_string(s: &str) -> TemporalResult<Self> {
+        let record = parse_ecma_date_time(s)?;
+        let date = IsoDate::new_with_overflow(
+            record.year,
+            record.month,
+            record.day,
+            ArithmeticOverflow::Reject,
+        )?;
+        let time = IsoTime::new(
+            record.hour,
+            record.minute,
+            record.second,
+            record.millisecond,
+            0,
+            0,
+            ArithmeticOverflow::Reject,
+        )?;
+        Self::new(date, time)
+    }
+
+    /// Returns this `IsoDateTime`, which is taken to be in UTC, as an HTTP date in the
+    /// RFC 7231 IMF-fixdate format, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// Any fractional seconds are truncated. The format only allows four digit years,
+    /// so the result is not a valid HTTP date for years outside of 0 through 9999.
+    pub fn to_http_date(&self) -> String {
+        let epoch_days = i64::from(self.date.to_epoch_days());
+        format_http_date(
+            self.date.year,
+            self.date.month,
+            self.date.day,
+            iso_day_of_week(epoch_days),
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+        )
+    }
+
+    /// Parses an HTTP date as defined in RFC 7231 section 7.1.1.1 into a UTC
+    /// `IsoDateTime`.
+    ///
+    /// The IMF-fixdate, `Sun, 06 Nov 1994 08:49:37 GMT`, the obsolete RFC 850 format,
+    /// `Sunday, 06-Nov-94 08:49:37 GMT`, and the ANSI C `asctime()` format,
+    /// `Sun Nov  6 08:49:37 1994`, are accepted, and `UTC` may be used in place of
+    /// `GMT`. The two digit year of the RFC 850 format is resolved to 1970 through
+    /// 2069, a leap second is constrained to 59, and the day of the week must match
+    /// the date.
+    pub fn from_http_date(s: &str) -> TemporalResult<Self> {
+        let record = parse_http_date(s)?;
+        let date = IsoDate::new_with_overflow(
+            record.year,
+            record.month,
+            record.day,
+            ArithmeticOverflow::Reject,
+        )?;
+        if iso_day_of_week(i64::from(date.to_epoch_days())) != record.day_of_week {
+            return Err(TemporalError::range()
+                .with_message("HTTP date day of the week does not match the date."));
+        }
+        let time =
+            IsoTime::new_unchecked(record.hour, record.minute, record.second.min(59), 0, 0, 0);
+        Self::new(date, time)
+    }
+
+    /// Creates an `IsoDateTime` from a JavaScript timestamp, i.e. the value of
+    /// `Date.prototype.getTime()`.
+    ///
+    /// Any fractional milliseconds are truncated toward zero.
+    #[cfg(feature = "js")]
+    pub fn from_js_timestamp(ms: f64) -> TemporalResult<Self> {
+        if !ms.is_finite() {
+            return Err(TemporalError::range().with_message("JavaScript timestamp must be finite."));
+        }
+        let max = f64::from(crate::MS_PER_DAY) * 100_000_000.0;
+        if !(-max..=max).contains(&ms) {
+            return Err(TemporalError::range()
+                .with_message("JavaScript timestamp is outside the valid epoch range."));
+        }
+        // NOTE: The cast is lossless as the value is within the valid epoch range.
+        Self::from_unix_timestamp_millis(ms as i64)
+    }
+
+    /// Returns this `IsoDateTime` as a JavaScript timestamp.
+    ///
+    /// Every millisecond in the valid epoch range is below 2^53 and is exactly
+    /// representable as an `f64`, but any submillisecond value is floored.
+    #[cfg(feature = "js")]
+    pub fn to_js_timestamp(&self) -> TemporalResult<f64> {
+        Ok(self.to_unix_timestamp_millis()? as f64)
+    }
+
+    /// Adds a `Duration` to this `IsoDateTime` in the ISO calendar.
+    ///
+    /// Time units are always added exactly and carried into days, while `overflow`
+    /// only governs the regulation of the day after adding the years and months,
+    /// e.g. adding one month to January 31st.
+    pub fn add(&self, duration: &Duration, overflow: ArithmeticOverflow) -> TemporalResult<Self> {
+        let result = self.add_date_duration(
+            Calendar::ISO,
+            duration.date(),
+            NormalizedTimeDuration::from_time_duration(duration.time()),
+            Some(overflow),
+        )?;
+        if !result.is_within_limits() {
+            return Err(
+                TemporalError::range().with_message("IsoDateTime not within a valid range.")
+            );
+        }
+        Ok(result)
+    }
+
+    /// Subtracts a `Duration` from this `IsoDateTime` in the ISO calendar.
+    ///
+    /// See [`IsoDateTime::add`] for how `overflow` is applied.
+    pub fn subtract(
+        &self,
+        duration: &Duration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        self.add(&duration.negated(), overflow)
+    }
+
     /// Specification equivalent to 5.5.9 `AddDateTime`.
     pub(crate) fn add_date_duration(
         &self,
@@ -178,7 +870,9 @@ impl IsoDateTime {
         let duration = Duration::from(date_duration);
 
         // 6. Let addedDate be ? AddDate(calendarRec, datePart, dateDuration, options).
-        let added_date = date.add_date(&duration, overflow)?;
+        let added_date = date
+            .add_date(&duration, overflow)
+            .with_context("AddDateTime")?;
 
         // 7. Return ISO Date-Time Record { [[Year]]: addedDate.[[ISOYear]], [[Month]]: addedDate.[[ISOMonth]],
         // [[Day]]: addedDate.[[ISODay]], [[Hour]]: timeResult.[[Hour]], [[Minute]]: timeResult.[[Minute]],
@@ -193,56 +887,175 @@ impl IsoDateTime {
             self.date.year,
             self.date.month.into(),
             i64::from(self.date.day) + rounded_days,
-        )?;
+        )
+        .with_context("RoundISODateTime")?;
         Self::new(balance_result, rounded_time)
     }
 
-    // TODO: Determine whether to provide an options object...seems duplicative.
-    /// 5.5.11 DifferenceISODateTime ( y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1, y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2, calendarRec, largestUnit, options )
-    pub(crate) fn diff(
-        &self,
-        other: &Self,
-        calendar: &Calendar,
-        largest_unit: Unit,
-    ) -> TemporalResult<NormalizedDurationRecord> {
-        // 1. Assert: ISODateTimeWithinLimits(y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1) is true.
-        // 2. Assert: ISODateTimeWithinLimits(y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2) is true.
-        // 3. Assert: If y1 ≠ y2, and mon1 ≠ mon2, and d1 ≠ d2, and LargerOfTwoUnits(largestUnit, "day")
-        // is not "day", CalendarMethodsRecordHasLookedUp(calendarRec, date-until) is true.
+    /// Returns this `IsoDateTime` rounded down to the hour.
+    #[inline]
+    pub fn floor_to_hour(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(3_600_000_000_000, RoundingMode::Floor)
+    }
 
-        // 4. Let timeDuration be DifferenceTime(h1, min1, s1, ms1, mus1, ns1, h2, min2, s2, ms2, mus2, ns2).
-        let mut time_duration =
-            NormalizedTimeDuration::from_time_duration(&self.time.diff(&other.time));
+    /// Returns this `IsoDateTime` rounded down to the minute.
+    #[inline]
+    pub fn floor_to_minute(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(60_000_000_000, RoundingMode::Floor)
+    }
 
-        // 5. Let timeSign be NormalizedTimeDurationSign(timeDuration).
-        let time_sign = time_duration.sign() as i8;
+    /// Returns this `IsoDateTime` rounded down to the second.
+    #[inline]
+    pub fn floor_to_second(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(1_000_000_000, RoundingMode::Floor)
+    }
 
-        // 6. Let dateSign be CompareISODate(y2, mon2, d2, y1, mon1, d1).
-        let date_sign = other.date.cmp(&self.date) as i32;
-        // 7. Let adjustedDate be CreateISODateRecord(y2, mon2, d2).
-        let mut adjusted_date = other.date;
+    /// Returns this `IsoDateTime` rounded down to the millisecond.
+    #[inline]
+    pub fn floor_to_millisecond(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(1_000_000, RoundingMode::Floor)
+    }
 
-        // 8. If timeSign = -dateSign, then
-        if i32::from(time_sign) == -date_sign {
-            // a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]], adjustedDate.[[Month]], adjustedDate.[[Day]] + timeSign).
-            adjusted_date = IsoDate::balance(
-                adjusted_date.year,
-                i32::from(adjusted_date.month),
-                i32::from(adjusted_date.day) + i32::from(time_sign),
-            );
-            // b. Set timeDuration to ? Add24HourDaysToNormalizedTimeDuration(timeDuration, -timeSign).
-            time_duration = time_duration.add_days(-i64::from(time_sign))?;
-        }
+    /// Returns this `IsoDateTime` rounded down to the microsecond.
+    #[inline]
+    pub fn floor_to_microsecond(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(1_000, RoundingMode::Floor)
+    }
 
-        // 9. Let date1 be ! CreateTemporalDate(y1, mon1, d1, calendarRec.[[Receiver]]).
-        let date_one = PlainDate::new_unchecked(self.date, calendar.clone());
-        // 10. Let date2 be ! CreateTemporalDate(adjustedDate.[[Year]], adjustedDate.[[Month]],
-        // adjustedDate.[[Day]], calendarRec.[[Receiver]]).
-        let date_two = PlainDate::try_new(
-            adjusted_date.year,
-            adjusted_date.month,
-            adjusted_date.day,
-            calendar.clone(),
+    /// Returns this `IsoDateTime` rounded to the nearest hour, with ties rounded up.
+    #[inline]
+    pub fn round_to_nearest_hour(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(3_600_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoDateTime` rounded to the nearest minute, with ties rounded up.
+    #[inline]
+    pub fn round_to_nearest_minute(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(60_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoDateTime` rounded to the nearest second, with ties rounded up.
+    #[inline]
+    pub fn round_to_nearest_second(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(1_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoDateTime` rounded up to the hour.
+    #[inline]
+    pub fn ceil_to_hour(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(3_600_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Returns this `IsoDateTime` rounded up to the minute.
+    #[inline]
+    pub fn ceil_to_minute(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(60_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Returns this `IsoDateTime` rounded up to the second.
+    #[inline]
+    pub fn ceil_to_second(&self) -> TemporalResult<Self> {
+        self.round_time_to_increment(1_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Rounds the time of this `IsoDateTime` to a multiple of `increment` nanoseconds,
+    /// carrying any overflow into the date.
+    ///
+    /// Even rounding down may fail, as the earliest valid `IsoDateTime` is a nanosecond
+    /// after midnight.
+    fn round_time_to_increment(&self, increment: i64, mode: RoundingMode) -> TemporalResult<Self> {
+        let (days, time) = self.time.round_to_increment(increment, mode);
+        let date = IsoDate::try_balance(
+            self.date.year,
+            self.date.month.into(),
+            i64::from(self.date.day) + days,
+        )?;
+        Self::new(date, time)
+    }
+
+    /// Returns the `Duration` from this `IsoDateTime` until `other` in the ISO calendar,
+    /// balanced up to `largest_unit`.
+    ///
+    /// `Unit::Auto` resolves to `Unit::Day`.
+    pub fn until(&self, other: &Self, largest_unit: Unit) -> TemporalResult<Duration> {
+        let largest_unit = match largest_unit {
+            Unit::Auto => Unit::Day,
+            unit => unit,
+        };
+        let record = self.diff(other, &Calendar::ISO, largest_unit)?;
+        Duration::from_normalized(record, largest_unit)
+    }
+
+    /// Returns the `Duration` from this `IsoDateTime` until `other` in the ISO calendar,
+    /// balanced and rounded according to the provided `DifferenceSettings`.
+    ///
+    /// This is equivalent to `PlainDateTime::until` with the ISO calendar.
+    pub fn diff_with_settings(
+        &self,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<Duration> {
+        PlainDateTime::new_unchecked(*self, Calendar::ISO).until(
+            &PlainDateTime::new_unchecked(*other, Calendar::ISO),
+            settings,
+        )
+    }
+
+    /// Returns the `Duration` from `other` until this `IsoDateTime` in the ISO calendar,
+    /// balanced up to `largest_unit`.
+    ///
+    /// This is equivalent to `other.until(self, largest_unit)`.
+    #[inline]
+    pub fn since(&self, other: &Self, largest_unit: Unit) -> TemporalResult<Duration> {
+        other.until(self, largest_unit)
+    }
+
+    // TODO: Determine whether to provide an options object...seems duplicative.
+    /// 5.5.11 DifferenceISODateTime ( y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1, y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2, calendarRec, largestUnit, options )
+    pub(crate) fn diff(
+        &self,
+        other: &Self,
+        calendar: &Calendar,
+        largest_unit: Unit,
+    ) -> TemporalResult<NormalizedDurationRecord> {
+        // 1. Assert: ISODateTimeWithinLimits(y1, mon1, d1, h1, min1, s1, ms1, mus1, ns1) is true.
+        // 2. Assert: ISODateTimeWithinLimits(y2, mon2, d2, h2, min2, s2, ms2, mus2, ns2) is true.
+        // 3. Assert: If y1 ≠ y2, and mon1 ≠ mon2, and d1 ≠ d2, and LargerOfTwoUnits(largestUnit, "day")
+        // is not "day", CalendarMethodsRecordHasLookedUp(calendarRec, date-until) is true.
+
+        // 4. Let timeDuration be DifferenceTime(h1, min1, s1, ms1, mus1, ns1, h2, min2, s2, ms2, mus2, ns2).
+        let mut time_duration =
+            NormalizedTimeDuration::from_time_duration(&self.time.diff(&other.time));
+
+        // 5. Let timeSign be NormalizedTimeDurationSign(timeDuration).
+        let time_sign = time_duration.sign() as i8;
+
+        // 6. Let dateSign be CompareISODate(y2, mon2, d2, y1, mon1, d1).
+        let date_sign = other.date.cmp(&self.date) as i32;
+        // 7. Let adjustedDate be CreateISODateRecord(y2, mon2, d2).
+        let mut adjusted_date = other.date;
+
+        // 8. If timeSign = -dateSign, then
+        if i32::from(time_sign) == -date_sign {
+            // a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]], adjustedDate.[[Month]], adjustedDate.[[Day]] + timeSign).
+            adjusted_date = IsoDate::balance(
+                adjusted_date.year,
+                i32::from(adjusted_date.month),
+                i32::from(adjusted_date.day) + i32::from(time_sign),
+            );
+            // b. Set timeDuration to ? Add24HourDaysToNormalizedTimeDuration(timeDuration, -timeSign).
+            time_duration = time_duration.add_days(-i64::from(time_sign))?;
+        }
+
+        // 9. Let date1 be ! CreateTemporalDate(y1, mon1, d1, calendarRec.[[Receiver]]).
+        let date_one = PlainDate::new_unchecked(self.date, calendar.clone());
+        // 10. Let date2 be ! CreateTemporalDate(adjustedDate.[[Year]], adjustedDate.[[Month]],
+        // adjustedDate.[[Day]], calendarRec.[[Receiver]]).
+        let date_two = PlainDate::try_new(
+            adjusted_date.year,
+            adjusted_date.month,
+            adjusted_date.day,
+            calendar.clone(),
         )?;
 
         // 11. Let dateLargestUnit be LargerOfTwoUnits("day", largestUnit).
@@ -285,7 +1098,7 @@ impl IsoDateTime {
 /// These fields are used for the `Temporal.PlainDate` object, the
 /// `Temporal.YearMonth` object, and the `Temporal.MonthDay` object.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsoDate {
     /// An ISO year within a range -271821..=275760
     pub year: i32,
@@ -295,12 +1108,94 @@ pub struct IsoDate {
     pub day: u8,
 }
 
+impl fmt::Debug for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IsoDate({})", self.to_iso_string())
+    }
+}
+
+impl fmt::Display for IsoDate {
+    /// Writes this `IsoDate` as an ISO 8601 date string, e.g. `2024-03-15`.
+    ///
+    /// Years outside of 0 through 9999 are written as expanded `±YYYYYY` years.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_iso_string())
+    }
+}
+
+impl FromStr for IsoDate {
+    type Err = TemporalError;
+
+    /// Parses an ISO 8601 extended calendar date string, e.g. `2024-03-15`,
+    /// `+010000-01-01`, or `-000500-06-30`.
+    ///
+    /// Unlike [`IsoDate::parse_with_calendar_annotation`], no time, offset, or
+    /// annotations are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let record = IxdtfParser::from_str(s).parse().map_err(|e| match e {
+            ParseError::InvalidMonthRange | ParseError::InvalidDayRange => {
+                TemporalError::range().with_message(format!("{e}"))
+            }
+            _ => TemporalError::syntax().with_message(format!("{e}")),
+        })?;
+        // NOTE: Annotations are the only part of the grammar using brackets, and unknown
+        // non-critical annotations are not kept in the record.
+        if record.time.is_some() || record.offset.is_some() || s.contains('[') {
+            return Err(TemporalError::syntax()
+                .with_message("Date string must not have a time, offset, or annotations."));
+        }
+        let date = record.date.temporal_unwrap()?;
+        Self::new_with_overflow(date.year, date.month, date.day, ArithmeticOverflow::Reject)
+    }
+}
+
 impl IsoDate {
+    /// The Unix epoch date, `1970-01-01`.
+    pub const EPOCH: Self = Self::new_unchecked(1970, 1, 1);
+
+    /// The earliest date representable by `Temporal`, `-271821-04-19`.
+    pub const TEMPORAL_MIN: Self = Self::new_unchecked(-271_821, 4, 19);
+
+    /// The latest date representable by `Temporal`, `+275760-09-13`.
+    pub const TEMPORAL_MAX: Self = Self::new_unchecked(275_760, 9, 13);
+
+    /// The reference day of an ISO `PlainYearMonth`.
+    pub const YEAR_MONTH_REFERENCE_DAY: u8 = 1;
+
+    /// The reference year of an ISO `PlainMonthDay`.
+    ///
+    /// 1972 is the first leap year after the Unix epoch, so every month and day,
+    /// including February 29th, is valid in it.
+    pub const MONTH_DAY_REFERENCE_YEAR: i32 = 1972;
+
     /// Creates a new `IsoDate` without determining the validity.
     pub(crate) const fn new_unchecked(year: i32, month: u8, day: u8) -> Self {
         Self { year, month, day }
     }
 
+    /// Creates a new `IsoDate` in a const context, panicking if the date is not valid.
+    ///
+    /// When used to initialize a `const`, an invalid date is a compile error. Note that
+    /// only the month and day are validated, not the `Temporal` year limits.
+    ///
+    /// ```rust
+    /// use temporal_rs::iso::IsoDate;
+    ///
+    /// const EPOCH: IsoDate = IsoDate::new_checked(1970, 1, 1);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use temporal_rs::iso::IsoDate;
+    ///
+    /// const INVALID: IsoDate = IsoDate::new_checked(2024, 2, 30);
+    /// ```
+    pub const fn new_checked(year: i32, month: u8, day: u8) -> Self {
+        if !is_valid_date(year, month, day) {
+            panic!("IsoDate fields are not valid.");
+        }
+        Self::new_unchecked(year, month, day)
+    }
+
     pub(crate) fn regulate(
         year: i32,
         month: u8,
@@ -331,7 +1226,83 @@ impl IsoDate {
         overflow: ArithmeticOverflow,
     ) -> TemporalResult<Self> {
         let date = Self::regulate(year, month, day, overflow)?;
-        if !iso_dt_within_valid_limits(date, &IsoTime::noon()) {
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
+
+    /// Creates a new `IsoDate` from a proleptic Gregorian year, month, and day,
+    /// regulating the values according to `overflow`.
+    pub fn from_gregorian(
+        year: i32,
+        month: i32,
+        day: i32,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let (month, day) = match overflow {
+            ArithmeticOverflow::Constrain => (month.clamp(1, 12) as u8, day.clamp(1, 31) as u8),
+            ArithmeticOverflow::Reject => (
+                u8::try_from(month)
+                    .map_err(|_| TemporalError::range().with_message("not a valid ISO date."))?,
+                u8::try_from(day)
+                    .map_err(|_| TemporalError::range().with_message("not a valid ISO date."))?,
+            ),
+        };
+        Self::new_with_overflow(year, month, day, overflow)
+    }
+
+    /// Creates a new `IsoDate` from a proleptic Gregorian year, month, and day,
+    /// rejecting any invalid values.
+    #[inline]
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> TemporalResult<Self> {
+        Self::new_with_overflow(year, month, day, ArithmeticOverflow::Reject)
+    }
+
+    /// Leniently parses a user-facing date string into an `IsoDate`.
+    ///
+    /// The following formats are accepted:
+    ///
+    /// - ISO 8601 extended and basic, e.g. `2024-03-15` and `20240315`
+    /// - Year-first with dashes, e.g. `2024-3-15`
+    /// - Slash-separated, e.g. `03/15/2024` or `15/03/2024` depending on `hint`
+    /// - Dot-separated, e.g. `15.03.2024`
+    /// - Long-form with full or abbreviated English month names, e.g.
+    ///   `March 15, 2024` and `15 Mar 2024`
+    ///
+    /// Unrecognized formats return a syntax error, while invalid dates or
+    /// dates outside the supported range return a range error.
+    pub fn parse_lenient(s: &str, hint: ParseHint) -> TemporalResult<Self> {
+        let (year, month, day) = parse_lenient_date(s, hint)?;
+        Self::from_ymd(year, month, day)
+    }
+
+    /// Creates an `IsoDate` from JavaScript `Date` components, where the month is
+    /// 0-indexed, i.e. `0` is January and `11` is December.
+    #[cfg(feature = "js")]
+    pub fn from_js_date(year: i32, month_0indexed: u8, day: u8) -> TemporalResult<Self> {
+        let month = month_0indexed
+            .checked_add(1)
+            .ok_or(TemporalError::range().with_message("not a valid ISO date."))?;
+        Self::from_ymd(year, month, day)
+    }
+
+    /// Creates an `IsoDate` from JavaScript-style epoch milliseconds.
+    ///
+    /// Fractional milliseconds are floored, so `-1.0` and `-0.5` are both `1969-12-31`.
+    pub fn from_epoch_ms(epoch_ms: f64) -> TemporalResult<Self> {
+        if !epoch_ms.is_finite() {
+            return Err(TemporalError::range().with_message("epoch milliseconds must be finite."));
+        }
+        let epoch_ms = floor_to_i64(epoch_ms);
+        if MAX_EPOCH_DAYS < epoch_ms.div_euclid(i64::from(crate::MS_PER_DAY)).abs() {
+            return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
+        }
+        let (year, month, day) = utils::ymd_from_epoch_milliseconds(epoch_ms);
+        let date = Self::new_unchecked(year, month, day);
+        if !iso_date_within_valid_limits(date) {
             return Err(
                 TemporalError::range().with_message("Date is not within ISO date time limits.")
             );
@@ -339,25 +1310,186 @@ impl IsoDate {
         Ok(date)
     }
 
+    /// Returns the seconds since the Unix epoch at the start of this `IsoDate` in UTC.
+    #[inline]
+    pub fn seconds_since_epoch(&self) -> i64 {
+        i64::from(self.to_epoch_days()) * 86_400
+    }
+
+    /// Creates an `IsoDate` from seconds since the Unix epoch, i.e. the UTC date
+    /// containing that second.
+    pub fn from_seconds_since_epoch(secs: i64) -> TemporalResult<Self> {
+        let epoch_days = secs.div_euclid(86_400);
+        if MAX_EPOCH_DAYS < epoch_days.abs() {
+            return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
+        }
+        Self::from_unix_date(epoch_days as i32)
+    }
+
+    /// Returns the days since the Unix epoch, 1970-01-01, of this `IsoDate`.
+    ///
+    /// This is always relative to the Unix epoch, not to other epochs such as J2000.0
+    /// or the start of the Julian Period.
+    #[inline]
+    pub fn to_unix_date(&self) -> i32 {
+        self.to_epoch_days()
+    }
+
+    /// Returns the days since the Unix epoch, 1970-01-01, of this `IsoDate`.
+    ///
+    /// This is an alias of [`IsoDate::to_unix_date`].
+    #[inline]
+    pub fn days_since_unix_epoch(&self) -> i32 {
+        self.to_unix_date()
+    }
+
+    /// Creates an `IsoDate` from days since the Unix epoch, 1970-01-01.
+    ///
+    /// This is an alias of [`IsoDate::from_epoch_days`].
+    #[inline]
+    pub fn from_unix_date(days: i32) -> TemporalResult<Self> {
+        Self::from_epoch_days(days)
+    }
+
+    /// Returns the Excel serial date number of this `IsoDate` in the 1900 date system,
+    /// where 1900-01-01 is serial 1.
+    ///
+    /// Excel treats 1900 as a leap year, so serial 60 is the nonexistent 1900-02-29 and
+    /// every date from 1900-03-01 onward is one more than its actual day count. Dates
+    /// outside of Excel's range, 1900-01-01 through 9999-12-31, are rejected.
+    pub fn to_excel_serial(&self) -> TemporalResult<u32> {
+        if self.year < 1900 || self.year > 9999 {
+            return Err(
+                TemporalError::range().with_message("Date is not within the Excel date range.")
+            );
+        }
+        let serial = self.to_epoch_days() - EXCEL_EPOCH_DAYS;
+        // Dates before the fictional 1900-02-29 are not offset by it.
+        if serial <= EXCEL_LEAP_DAY_SERIAL {
+            return Ok((serial - 1) as u32);
+        }
+        Ok(serial as u32)
+    }
+
+    /// Creates an `IsoDate` from an Excel serial date number in the 1900 date system,
+    /// where 1900-01-01 is serial 1.
+    ///
+    /// Serial 0, Excel's 1900-01-00, and serial 60, Excel's nonexistent 1900-02-29, are
+    /// rejected, as are serials after 9999-12-31.
+    pub fn from_excel_serial(serial: u32) -> TemporalResult<Self> {
+        if serial == 0 || serial == EXCEL_LEAP_DAY_SERIAL as u32 || serial > EXCEL_MAX_SERIAL {
+            return Err(TemporalError::range().with_message("Excel serial is not a valid date."));
+        }
+        let serial = serial as i32;
+        let epoch_days = if serial < EXCEL_LEAP_DAY_SERIAL {
+            serial + 1
+        } else {
+            serial
+        };
+        Ok(Self::from_epoch_days_unchecked(
+            epoch_days + EXCEL_EPOCH_DAYS,
+        ))
+    }
+
+    /// Parses an RFC 9557 date string, returning the `IsoDate` and the calendar
+    /// annotation's identifier if present, e.g. `2024-03-15[u-ca=persian]`.
+    pub fn parse_with_calendar_annotation(s: &str) -> TemporalResult<(Self, Option<String>)> {
+        let record = parse_date_time(s.as_bytes())?;
+        let date = record.date.temporal_unwrap()?;
+        let date =
+            Self::new_with_overflow(date.year, date.month, date.day, ArithmeticOverflow::Reject)?;
+        Ok((date, record.calendar.map(calendar_identifier).transpose()?))
+    }
+
+    /// Returns an ISO 8601 string for this `IsoDate`, e.g. `2024-03-15`.
+    #[inline]
+    pub fn to_iso_string(&self) -> String {
+        IxdtfStringBuilder::default().with_date(*self).build()
+    }
+
+    /// Returns the date portion of an ECMAScript date time string for this `IsoDate`,
+    /// e.g. `2024-03-15`.
+    ///
+    /// No calendar annotation is ever written. As with `Date.prototype.toISOString()`,
+    /// years outside of 0 through 9999 are written as expanded `±YYYYYY` years, so this
+    /// is the same string as [`IsoDate::to_iso_string`].
+    #[inline]
+    pub fn to_ecma_date_string(&self) -> String {
+        self.to_iso_string()
+    }
+
+    /// Returns an ISO 8601 string for this `IsoDate` with a calendar annotation,
+    /// e.g. `2024-03-15[u-ca=hebrew]`.
+    ///
+    /// The annotation is omitted when `calendar_id` is `"iso8601"`.
+    #[inline]
+    pub fn to_iso_string_with_calendar(&self, calendar_id: &str) -> String {
+        IxdtfStringBuilder::default()
+            .with_date(*self)
+            .with_calendar(calendar_id, DisplayCalendar::Auto)
+            .build()
+    }
+
+    /// Returns a `TemporalBuilder` for chaining operations on this `IsoDate`.
+    #[inline]
+    pub fn builder(&self) -> TemporalBuilder<Self> {
+        TemporalBuilder::new(*self)
+    }
+
+    /// Returns an English string for this `IsoDate`, e.g. `March 15, 2024`.
+    pub fn to_human_readable_string(&self) -> String {
+        let month = ENGLISH_MONTH_NAMES[usize::from(self.month - 1)];
+        format!("{month} {}, {}", self.day, self.year)
+    }
+
+    /// Returns the proleptic Gregorian year, month, and day of this `IsoDate`.
+    #[inline]
+    pub const fn to_gregorian_year_month_day(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
     /// Create a balance date while rejecting invalid intermediates
     pub(crate) fn try_balance(year: i32, month: i32, day: i64) -> TemporalResult<Self> {
-        let epoch_days = iso_date_to_epoch_days(year, month, 1) + day - 1;
+        let epoch_days = iso_date_epoch_days(year, month, 1) + day - 1;
         if (MAX_EPOCH_DAYS) < epoch_days.abs() {
             return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
         }
         // NOTE The cast is to i32 is safe due to MAX_EPOCH_DAYS check
-        let ms = utils::epoch_days_to_epoch_ms(epoch_days, 0);
-        let (year, month, day) = utils::ymd_from_epoch_milliseconds(ms);
-        Ok(Self::new_unchecked(year, month, day))
+        Ok(Self::from_epoch_days_unchecked(epoch_days as i32))
     }
 
     /// Create a balanced `IsoDate`
     ///
     /// Equivalent to `BalanceISODate`.
     pub(crate) fn balance(year: i32, month: i32, day: i32) -> Self {
-        let epoch_days = iso_date_to_epoch_days(year, month, day);
-        let ms = utils::epoch_days_to_epoch_ms(epoch_days, 0);
-        let (year, month, day) = utils::ymd_from_epoch_milliseconds(ms);
+        let epoch_days = iso_date_epoch_days(year, month, day);
+        Self::from_epoch_days_unchecked(epoch_days as i32)
+    }
+
+    /// Creates an `IsoDate` from days since the Unix epoch, 1970-01-01.
+    ///
+    /// This is the inverse of [`IsoDate::to_epoch_days`], and errors if the date is
+    /// not within the valid `Temporal` limits.
+    pub fn from_epoch_days(epoch_days: i32) -> TemporalResult<Self> {
+        if MAX_EPOCH_DAYS < i64::from(epoch_days).abs() {
+            return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
+        }
+        let date = Self::from_epoch_days_unchecked(epoch_days);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
+
+    /// Creates an `IsoDate` from the provided epoch days without checking the limits.
+    ///
+    /// The epoch days are converted directly with integer arithmetic, avoiding
+    /// the intermediate epoch milliseconds value.
+    #[inline]
+    pub(crate) fn from_epoch_days_unchecked(epoch_days: i32) -> Self {
+        let (year, month, day) = utils::ymd_from_epoch_days(epoch_days);
         Self::new_unchecked(year, month, day)
     }
 
@@ -378,7 +1510,7 @@ impl IsoDate {
     ///
     /// Equivalent to `IsoDateToEpochDays`
     #[inline]
-    pub(crate) fn to_epoch_days(self) -> i32 {
+    pub fn to_epoch_days(self) -> i32 {
         // NOTE: cast to i32 is safe as IsoDate is in a valid range.
         utils::epoch_days_from_gregorian_date(self.year, self.month, self.day) as i32
     }
@@ -388,446 +1520,1994 @@ impl IsoDate {
         is_valid_date(self.year, self.month, self.day)
     }
 
-    /// Returns the resulting `IsoDate` from adding a provided `Duration` to this `IsoDate`
-    pub(crate) fn add_date_duration(
-        self,
-        duration: &DateDuration,
-        overflow: ArithmeticOverflow,
-    ) -> TemporalResult<Self> {
-        // 1. Assert: year, month, day, years, months, weeks, and days are integers.
-        // 2. Assert: overflow is either "constrain" or "reject".
-        // 3. Let intermediate be ! BalanceISOYearMonth(year + years, month + months).
-        let intermediate = balance_iso_year_month_with_clamp(
-            i64::from(self.year) + duration.years,
-            i64::from(self.month) + duration.months,
-        );
+    /// Returns the number of days in this `IsoDate`'s month.
+    #[inline]
+    pub fn days_in_month(&self) -> u8 {
+        utils::iso_days_in_month(self.year, self.month)
+    }
 
-        // 4. Let intermediate be ? RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], day, overflow).
-        let intermediate =
-            Self::new_with_overflow(intermediate.0, intermediate.1, self.day, overflow)?;
+    /// Returns whether this `IsoDate`'s year is a leap year in the proleptic Gregorian
+    /// calendar.
+    #[inline]
+    #[must_use]
+    pub const fn is_leap_year(&self) -> bool {
+        utils::is_leap_year(self.year)
+    }
 
-        // 5. Set days to days + 7 × weeks.
-        let additional_days = duration.days + (7 * duration.weeks); // Verify
-                                                                    // 6. Let d be intermediate.[[Day]] + days.
-        let intermediate_days = i64::from(intermediate.day) + additional_days;
+    /// Returns the ISO 8601 day of the week of this `IsoDate`, from Monday (1) to
+    /// Sunday (7).
+    #[inline]
+    #[must_use]
+    pub const fn day_of_week(&self) -> u8 {
+        iso_day_of_week(utils::epoch_days_from_gregorian_date(
+            self.year, self.month, self.day,
+        ))
+    }
 
-        // 7. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], d).
-        Self::try_balance(
-            intermediate.year,
-            intermediate.month.into(),
-            intermediate_days,
-        )
+    /// Returns the day of the week of this `IsoDate`.
+    #[inline]
+    #[must_use]
+    pub const fn weekday(&self) -> DayOfWeek {
+        match DayOfWeek::from_iso_number(self.day_of_week()) {
+            Some(weekday) => weekday,
+            None => unreachable!(),
+        }
     }
 
-    pub(crate) fn diff_iso_date(
-        &self,
-        other: &Self,
-        largest_unit: Unit,
-    ) -> TemporalResult<DateDuration> {
-        // 1. Assert: IsValidISODate(y1, m1, d1) is true.
-        // 2. Assert: IsValidISODate(y2, m2, d2) is true.
-        // 3. Let sign be -CompareISODate(y1, m1, d1, y2, m2, d2).
-        let sign = -(self.cmp(other) as i8);
-        // 4. If sign = 0, return ! CreateDateDurationRecord(0, 0, 0, 0).
-        if sign == 0 {
-            return Ok(DateDuration::default());
-        };
-
-        // 5. Let years be 0.
-        let mut years = 0;
-        let mut months = 0;
-        // 6. If largestUnit is "year", then
-        if largest_unit == Unit::Year || largest_unit == Unit::Month {
-            // others.year - self.year is adopted from temporal-proposal/polyfill as it saves iterations.
-            // a. Let candidateYears be sign.
-            let mut candidate_years: i32 = other.year - self.year;
-            if candidate_years != 0 {
-                candidate_years -= i32::from(sign);
-            }
-            // b. Repeat, while ISODateSurpasses(sign, y1 + candidateYears, m1, d1, y2, m2, d2) is false,
-            while !iso_date_surpasses(
-                &IsoDate::new_unchecked(self.year + candidate_years, self.month, self.day),
-                other,
-                sign,
-            ) {
-                // i. Set years to candidateYears.
-                years = candidate_years;
-                // ii. Set candidateYears to candidateYears + sign.
-                candidate_years += i32::from(sign);
-            }
-
-            // 7. Let months be 0.
-            // 8. If largestUnit is "year" or largestUnit is "month", then
-            // a. Let candidateMonths be sign.
-            let mut candidate_months: i32 = sign.into();
-            // b. Let intermediate be BalanceISOYearMonth(y1 + years, m1 + candidateMonths).
-            let mut intermediate =
-                balance_iso_year_month(self.year + years, i32::from(self.month) + candidate_months);
-            // c. Repeat, while ISODateSurpasses(sign, intermediate.[[Year]], intermediate.[[Month]], d1, y2, m2, d2) is false,
-            // Safety: balance_iso_year_month should always return a month value from 1..=12
-            while !iso_date_surpasses(
-                &IsoDate::new_unchecked(intermediate.0, intermediate.1 as u8, self.day),
-                other,
-                sign,
-            ) {
-                // i. Set months to candidateMonths.
-                months = candidate_months;
-                // ii. Set candidateMonths to candidateMonths + sign.
-                candidate_months += i32::from(sign);
-                // iii. Set intermediate to BalanceISOYearMonth(intermediate.[[Year]], intermediate.[[Month]] + sign).
-                intermediate = balance_iso_year_month(
-                    intermediate.0,
-                    i32::from(intermediate.1) + i32::from(sign),
-                );
-            }
+    /// Returns the day of the year of this `IsoDate`, from 1 through 366.
+    #[inline]
+    pub fn day_of_year(&self) -> u16 {
+        (i64::from(self.to_epoch_days()) - iso_date_epoch_days(self.year, 1, 1) + 1) as u16
+    }
 
-            if largest_unit == Unit::Month {
-                months += years * 12;
-                years = 0;
-            }
+    /// Creates an `IsoDate` from a year and a day of the year, e.g. 2024 and 60 is
+    /// 2024-02-29.
+    ///
+    /// `ordinal` must be from 1 through the number of days in `year`.
+    pub fn from_ordinal(year: i32, ordinal: u16) -> TemporalResult<Self> {
+        let days_in_year = 365 + u16::from(utils::is_leap_year(year));
+        if !(1..=days_in_year).contains(&ordinal) {
+            return Err(TemporalError::range().with_message("ordinal is not valid for the year."));
         }
-
-        // 9. Set intermediate to BalanceISOYearMonth(y1 + years, m1 + months).
-        let intermediate =
-            balance_iso_year_month(self.year + years, i32::from(self.month) + months);
-        // 10. Let constrained be ! RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], d1, "constrain").
-        let constrained = Self::new_with_overflow(
-            intermediate.0,
-            intermediate.1,
-            self.day,
-            ArithmeticOverflow::Constrain,
-        )?;
-
-        // NOTE: Below is adapted from the polyfill. Preferring this as it avoids looping.
-        // 11. Let weeks be 0.
-        let days = utils::epoch_days_from_gregorian_date(other.year, other.month, other.day)
-            - utils::epoch_days_from_gregorian_date(
-                constrained.year,
-                constrained.month,
-                constrained.day,
+        let epoch_days = iso_date_epoch_days(year, 1, 1) + i64::from(ordinal - 1);
+        if MAX_EPOCH_DAYS < epoch_days.abs() {
+            return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
+        }
+        let date = Self::from_epoch_days_unchecked(epoch_days as i32);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
             );
+        }
+        Ok(date)
+    }
 
-        let (weeks, days) = if largest_unit == Unit::Week {
-            (days / 7, days % 7)
-        } else {
-            (0, days)
-        };
+    /// Returns this `IsoDate` and `time` as a military Date-Time Group, e.g. `152000Z MAR 24`.
+    ///
+    /// The seconds and subseconds of `time` are omitted, as is all but the last two
+    /// digits of the year. `zone` is the military time zone letter, e.g. `Z` for UTC.
+    pub fn to_dtg(&self, time: &IsoTime, zone: char) -> String {
+        format_dtg(
+            self.year,
+            self.month,
+            self.day,
+            time.hour,
+            time.minute,
+            zone,
+        )
+    }
 
-        // 17. Return ! CreateDateDurationRecord(years, months, weeks, days).
-        DateDuration::new(years as i64, months as i64, weeks, days)
+    /// Parses a military Date-Time Group, e.g. `152000Z MAR 24`, returning the date,
+    /// time, and time zone letter.
+    ///
+    /// The two digit year is resolved per the common Y2K convention: `70` through `99`
+    /// are 1970 through 1999, and `00` through `69` are 2000 through 2069.
+    pub fn from_dtg(s: &str) -> TemporalResult<(Self, IsoTime, char)> {
+        let record = parse_dtg(s)?;
+        let date = Self::new_with_overflow(
+            record.year,
+            record.month,
+            record.day,
+            ArithmeticOverflow::Reject,
+        )?;
+        let time = IsoTime::new_unchecked(record.hour, record.minute, 0, 0, 0, 0);
+        Ok((date, time, record.zone))
     }
-}
 
-impl IsoDate {
-    /// Creates `[[ISOYear]]`, `[[isoMonth]]`, `[[isoDay]]` fields from `ICU4X`'s `Date<Iso>` struct.
-    pub(crate) fn to_icu4x(self) -> IcuDate<Iso> {
-        IcuDate::try_new_iso(self.year, self.month, self.day).expect("must not fail.")
+    /// Returns the first day of this `IsoDate`'s quarter.
+    #[inline]
+    pub fn start_of_quarter(&self) -> IsoDate {
+        let month = (self.month - 1) / 3 * 3 + 1;
+        Self::new_unchecked(self.year, month, 1)
     }
-}
 
-// ==== `IsoTime` section ====
+    /// Returns the last day of this `IsoDate`'s quarter.
+    #[inline]
+    pub fn end_of_quarter(&self) -> IsoDate {
+        let month = (self.month - 1) / 3 * 3 + 3;
+        Self::new_unchecked(self.year, month, utils::iso_days_in_month(self.year, month))
+    }
 
-/// An `IsoTime` record that contains `Temporal`'s
-/// time slots.
-#[non_exhaustive]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct IsoTime {
-    /// A valid hour value between 0..=23
-    pub hour: u8, // 0..=23
-    /// A valid minute value between 0..=59
-    pub minute: u8, // 0..=59
-    /// A valid second value between 0..=59
-    pub second: u8, // 0..=59
-    /// A valid millisecond value between 0..=999
-    pub millisecond: u16, // 0..=999
-    /// A valid microsecond value between 0..=999
-    pub microsecond: u16, // 0..=999
-    /// A valid nanosecond value between 0..=999
-    pub nanosecond: u16, // 0..=999
-}
+    /// Returns the first day of the provided quarter, 1 through 4, of `year`.
+    pub fn quarter_start(year: i32, quarter: u8) -> TemporalResult<IsoDate> {
+        validate_quarter(quarter)?;
+        let date = Self::new_unchecked(year, quarter * 3 - 2, 1);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
 
-impl IsoTime {
-    /// Creates a new `IsoTime` without any validation.
-    pub(crate) fn new_unchecked(
-        hour: u8,
-        minute: u8,
-        second: u8,
-        millisecond: u16,
-        microsecond: u16,
-        nanosecond: u16,
-    ) -> Self {
-        Self {
-            hour,
-            minute,
-            second,
-            millisecond,
-            microsecond,
-            nanosecond,
+    /// Returns the last day of the provided quarter, 1 through 4, of `year`.
+    pub fn quarter_end(year: i32, quarter: u8) -> TemporalResult<IsoDate> {
+        validate_quarter(quarter)?;
+        let month = quarter * 3;
+        let date = Self::new_unchecked(year, month, utils::iso_days_in_month(year, month));
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
         }
+        Ok(date)
     }
 
-    /// Creates a new regulated `IsoTime`.
-    pub fn new(
-        hour: u8,
-        minute: u8,
-        second: u8,
-        millisecond: u16,
-        microsecond: u16,
-        nanosecond: u16,
+    /// Returns an iterator over every quarter from `start_year` through `end_year`,
+    /// yielding the quarter number along with its first and last days.
+    ///
+    /// The dates are not validated against the `Temporal` limits.
+    pub fn quarters_in_range(
+        start_year: i32,
+        end_year: i32,
+    ) -> impl Iterator<Item = (u8, IsoDate, IsoDate)> {
+        (start_year..=end_year).flat_map(|year| {
+            (1..=4).map(move |quarter| {
+                let start = Self::new_unchecked(year, quarter * 3 - 2, 1);
+                (quarter, start, start.end_of_quarter())
+            })
+        })
+    }
+
+    /// Creates the `IsoDate` for the first day of a year and month, regulating the month
+    /// according to `overflow`.
+    ///
+    /// This is the reference date used for year-month values, so only the year and
+    /// month are validated against the `Temporal` year-month limits.
+    pub fn from_year_month(
+        year: i32,
+        month: i32,
         overflow: ArithmeticOverflow,
-    ) -> TemporalResult<IsoTime> {
-        match overflow {
-            ArithmeticOverflow::Constrain => {
-                let h = hour.clamp(0, 23);
-                let min = minute.clamp(0, 59);
-                let sec = second.clamp(0, 59);
-                let milli = millisecond.clamp(0, 999);
-                let micro = microsecond.clamp(0, 999);
-                let nano = nanosecond.clamp(0, 999);
-                Ok(Self::new_unchecked(h, min, sec, milli, micro, nano))
-            }
+    ) -> TemporalResult<Self> {
+        let month = match overflow {
+            ArithmeticOverflow::Constrain => month.clamp(1, 12) as u8,
+            ArithmeticOverflow::Reject if (1..=12).contains(&month) => month as u8,
             ArithmeticOverflow::Reject => {
-                if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
-                    return Err(TemporalError::range().with_message("IsoTime is not valid"));
-                };
-                Ok(Self::new_unchecked(
-                    hour,
-                    minute,
-                    second,
-                    millisecond,
-                    microsecond,
-                    nanosecond,
-                ))
+                return Err(
+                    TemporalError::range().with_message("month is not within a valid range.")
+                )
             }
+        };
+        if !year_month_within_limits(year, month) {
+            return Err(
+                TemporalError::range().with_message("Year-month is not within a valid range.")
+            );
         }
+        Ok(Self::new_unchecked(
+            year,
+            month,
+            Self::YEAR_MONTH_REFERENCE_DAY,
+        ))
     }
 
-    /// Creates a new `Time` with the fields provided from a `PartialTime`.
+    /// Returns whether this `IsoDate` is a valid reference date for an ISO `PlainYearMonth`.
     #[inline]
-    pub(crate) fn with(
-        &self,
-        partial: PartialTime,
-        overflow: ArithmeticOverflow,
-    ) -> TemporalResult<Self> {
-        let hour = partial.hour.unwrap_or(self.hour);
-        let minute = partial.minute.unwrap_or(self.minute);
-        let second = partial.second.unwrap_or(self.second);
-        let millisecond = partial.millisecond.unwrap_or(self.millisecond);
-        let microsecond = partial.microsecond.unwrap_or(self.microsecond);
-        let nanosecond = partial.nanosecond.unwrap_or(self.nanosecond);
-        Self::new(
-            hour,
-            minute,
-            second,
-            millisecond,
-            microsecond,
-            nanosecond,
-            overflow,
-        )
+    pub fn is_valid_reference_for_year_month(&self) -> bool {
+        self.day == Self::YEAR_MONTH_REFERENCE_DAY
     }
 
-    /// Returns an `IsoTime` set to 12:00:00
-    pub(crate) const fn noon() -> Self {
-        Self {
-            hour: 12,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-            microsecond: 0,
-            nanosecond: 0,
-        }
+    /// Returns whether this `IsoDate` is a valid reference date for an ISO `PlainMonthDay`.
+    #[inline]
+    pub fn is_valid_reference_for_month_day(&self) -> bool {
+        self.year == Self::MONTH_DAY_REFERENCE_YEAR
     }
 
-    /// Returns an `IsoTime` based off parse components.
-    pub(crate) fn from_time_record(time_record: TimeRecord) -> TemporalResult<Self> {
-        let second = time_record.second.clamp(0, 59);
-        let fractional_seconds = time_record
-            .fraction
-            .map(|x| {
-                x.to_nanoseconds().ok_or(
-                    TemporalError::range().with_message("fractional seconds exceeds nine digits."),
-                )
-            })
-            .transpose()?
-            .unwrap_or(0);
+    /// Returns the last day of the provided year and month.
+    pub fn last_day_of(year: i32, month: i32) -> TemporalResult<Self> {
+        let first = Self::from_year_month(year, month, ArithmeticOverflow::Reject)?;
+        Ok(Self::new_unchecked(
+            year,
+            first.month,
+            first.days_in_month(),
+        ))
+    }
 
-        let (millisecond, rem) = fractional_seconds.div_rem_euclid(&1_000_000);
-        let (micros, nanos) = rem.div_rem_euclid(&1_000);
+    /// Returns whether this `IsoDate` is in the same year and month as `other`.
+    #[inline]
+    pub fn is_same_year_month_as(&self, other: &IsoDate) -> bool {
+        self.year == other.year && self.month == other.month
+    }
 
-        Self::new(
-            time_record.hour,
-            time_record.minute,
-            second,
-            millisecond as u16,
-            micros as u16,
-            nanos as u16,
-            ArithmeticOverflow::Reject,
-        )
+    /// Returns the last day of the month before this `IsoDate`'s month.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    #[inline]
+    pub fn previous_month_end(&self) -> TemporalResult<IsoDate> {
+        let (year, month) = balance_iso_year_month(self.year, i32::from(self.month) - 1);
+        Self::new_within_limits(year, month, utils::iso_days_in_month(year, month))
     }
 
-    /// Balances and creates a new `IsoTime` with `day` overflow from the provided values.
-    pub(crate) fn balance(
-        hour: i64,
-        minute: i64,
-        second: i64,
-        millisecond: i64,
-        microsecond: i128,
-        nanosecond: i128,
-    ) -> (i64, Self) {
-        // 1. Set microsecond to microsecond + floor(nanosecond / 1000).
-        // 2. Set nanosecond to nanosecond modulo 1000.
-        let (quotient, nanosecond) = (nanosecond.div_euclid(1000), nanosecond.rem_euclid(1000));
-        let microsecond = microsecond + quotient;
+    /// Returns the first day of the month after this `IsoDate`'s month.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    #[inline]
+    pub fn next_month_start(&self) -> TemporalResult<IsoDate> {
+        self.months_ahead(1)
+    }
 
-        // 3. Set millisecond to millisecond + floor(microsecond / 1000).
-        // 4. Set microsecond to microsecond modulo 1000.
-        let (quotient, microsecond) = (microsecond.div_euclid(1000), microsecond.rem_euclid(1000));
-        let millisecond = millisecond + quotient as i64;
+    /// Returns the first day of the month `n` months after this `IsoDate`'s month,
+    /// or before it when `n` is negative.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    #[inline]
+    pub fn months_ahead(&self, n: i32) -> TemporalResult<IsoDate> {
+        let (year, month) = balance_iso_year_month_with_clamp(
+            i64::from(self.year),
+            i64::from(self.month) + i64::from(n),
+        );
+        Self::new_within_limits(year, month, 1)
+    }
 
-        // 5. Set second to second + floor(millisecond / 1000).
-        // 6. Set millisecond to millisecond modulo 1000.
-        let (quotient, millisecond) = div_mod(millisecond, 1000);
-        let second = second + quotient;
+    /// Creates an `IsoDate` from valid fields, checking that it is within the valid
+    /// `Temporal` limits.
+    fn new_within_limits(year: i32, month: u8, day: u8) -> TemporalResult<IsoDate> {
+        let date = Self::new_unchecked(year, month, day);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
 
-        // 7. Set minute to minute + floor(second / 60).
-        // 8. Set second to second modulo 60.
-        let (quotient, second) = div_mod(second, 60);
-        let minute = minute + quotient;
+    /// Returns the number of days in this `IsoDate`'s quarter.
+    #[inline]
+    pub fn days_in_quarter(&self) -> u16 {
+        let first_month = (self.month - 1) / 3 * 3 + 1;
+        (first_month..first_month + 3)
+            .map(|month| u16::from(utils::iso_days_in_month(self.year, month)))
+            .sum()
+    }
 
-        // 9. Set hour to hour + floor(minute / 60).
-        // 10. Set minute to minute modulo 60.
-        let (quotient, minute) = div_mod(minute, 60);
-        let hour = hour + quotient;
+    /// Returns the 1-based day of this `IsoDate` within its quarter.
+    #[inline]
+    pub fn day_of_quarter(&self) -> u16 {
+        let first_month = (self.month - 1) / 3 * 3 + 1;
+        (first_month..self.month)
+            .map(|month| u16::from(utils::iso_days_in_month(self.year, month)))
+            .sum::<u16>()
+            + u16::from(self.day)
+    }
 
-        // 11. Let days be floor(hour / 24).
-        // 12. Set hour to hour modulo 24.
-        let (days, hour) = div_mod(hour, 24);
+    /// Returns the fiscal year of this `IsoDate` for a fiscal year starting on the
+    /// first day of `fiscal_year_start_month`.
+    ///
+    /// A fiscal year is numbered by the calendar year it ends in, e.g. April 2023
+    /// through March 2024 is fiscal year 2024 for an April start.
+    pub fn fiscal_year(&self, fiscal_year_start_month: u8) -> TemporalResult<i32> {
+        validate_fiscal_year_start_month(fiscal_year_start_month)?;
+        if fiscal_year_start_month != 1 && self.month >= fiscal_year_start_month {
+            return Ok(self.year + 1);
+        }
+        Ok(self.year)
+    }
 
-        let time = Self::new_unchecked(
-            hour as u8,
-            minute as u8,
-            second as u8,
-            millisecond as u16,
-            microsecond as u16,
-            nanosecond as u16,
+    /// Returns the quarter, 1 through 4, of this `IsoDate` within its fiscal year for a
+    /// fiscal year starting on the first day of `fiscal_year_start_month`.
+    pub fn fiscal_quarter(&self, fiscal_year_start_month: u8) -> TemporalResult<u8> {
+        validate_fiscal_year_start_month(fiscal_year_start_month)?;
+        let months_into_year =
+            (i32::from(self.month) - i32::from(fiscal_year_start_month)).rem_euclid(12) as u8;
+        Ok(months_into_year / 3 + 1)
+    }
+
+    /// Returns the first day of the fiscal year containing this `IsoDate` for a fiscal
+    /// year starting on the first day of `fiscal_year_start_month`.
+    pub fn fiscal_year_start(&self, fiscal_year_start_month: u8) -> TemporalResult<IsoDate> {
+        validate_fiscal_year_start_month(fiscal_year_start_month)?;
+        let year = if self.month >= fiscal_year_start_month {
+            self.year
+        } else {
+            self.year - 1
+        };
+        let start = Self::new_unchecked(year, fiscal_year_start_month, 1);
+        if !iso_date_within_valid_limits(start) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(start)
+    }
+
+    /// Returns whether this `IsoDate` is the first day of the month.
+    #[inline]
+    pub fn is_first_day_of_month(&self) -> bool {
+        self.day == 1
+    }
+
+    /// Returns whether this `IsoDate` is the last day of the month.
+    #[inline]
+    pub fn is_last_day_of_month(&self) -> bool {
+        self.day == self.days_in_month()
+    }
+
+    /// Returns whether this `IsoDate` is the first day of the year.
+    #[inline]
+    pub fn is_first_day_of_year(&self) -> bool {
+        self.month == 1 && self.day == 1
+    }
+
+    /// Returns whether this `IsoDate` is the last day of the year.
+    #[inline]
+    pub fn is_last_day_of_year(&self) -> bool {
+        self.month == 12 && self.day == 31
+    }
+
+    /// Returns whether this `IsoDate` is the first day of the quarter.
+    #[inline]
+    pub fn is_first_day_of_quarter(&self) -> bool {
+        *self == self.start_of_quarter()
+    }
+
+    /// Returns whether this `IsoDate` is the last day of the quarter.
+    #[inline]
+    pub fn is_last_day_of_quarter(&self) -> bool {
+        *self == self.end_of_quarter()
+    }
+
+    /// Returns whether this `IsoDate` is a leap day, i.e. February 29th.
+    #[inline]
+    pub fn is_leap_day(&self) -> bool {
+        self.month == 2 && self.day == 29
+    }
+
+    /// Calls `f` with each month of `year` and the first day of that month.
+    pub fn for_each_month_in_year(year: i32, mut f: impl FnMut(u8, IsoDate)) {
+        for month in 1..=12 {
+            f(month, Self::new_unchecked(year, month, 1));
+        }
+    }
+
+    /// Returns an iterator over the first day of each month from the month
+    /// containing `start` through the month containing `end`.
+    ///
+    /// The iterator is empty if `end` is in a month before `start`.
+    pub fn months_in_range(start: &IsoDate, end: &IsoDate) -> impl Iterator<Item = IsoDate> {
+        let start = i64::from(start.year) * 12 + i64::from(start.month) - 1;
+        let end = i64::from(end.year) * 12 + i64::from(end.month) - 1;
+        (start..=end).map(|months| {
+            let (year, month) = div_mod(months, 12);
+            Self::new_unchecked(year as i32, month as u8 + 1, 1)
+        })
+    }
+
+    /// Returns an iterator over each year from `start`'s year through `end`'s year.
+    ///
+    /// The iterator is empty if `end` is in a year before `start`.
+    pub fn years_in_range(start: &IsoDate, end: &IsoDate) -> impl Iterator<Item = i32> {
+        start.year..=end.year
+    }
+
+    /// Returns the date in `candidates` that is the fewest days from this `IsoDate`,
+    /// or `None` if `candidates` is empty.
+    ///
+    /// Ties between equidistant dates are resolved to the earlier date.
+    pub fn nearest(&self, candidates: &[IsoDate]) -> Option<IsoDate> {
+        let epoch_days = self.to_epoch_days();
+        candidates
+            .iter()
+            .min_by_key(|date| ((date.to_epoch_days() - epoch_days).unsigned_abs(), **date))
+            .copied()
+    }
+
+    /// Returns the latest date in `candidates` that is on or before this `IsoDate`.
+    pub fn nearest_before(&self, candidates: &[IsoDate]) -> Option<IsoDate> {
+        candidates
+            .iter()
+            .filter(|date| *date <= self)
+            .max()
+            .copied()
+    }
+
+    /// Returns the earliest date in `candidates` that is on or after this `IsoDate`.
+    pub fn nearest_after(&self, candidates: &[IsoDate]) -> Option<IsoDate> {
+        candidates
+            .iter()
+            .filter(|date| *date >= self)
+            .min()
+            .copied()
+    }
+
+    /// Returns the number of ISO weeks, 52 or 53, in the provided ISO week year.
+    #[inline]
+    pub fn weeks_in_year(year: i32) -> u8 {
+        // A year has 53 weeks if January 1st is a Thursday, or if it is a leap
+        // year and January 1st is a Wednesday.
+        let jan_one = iso_day_of_week(iso_date_epoch_days(year, 1, 1));
+        if jan_one == 4 || (jan_one == 3 && utils::is_leap_year(year)) {
+            return 53;
+        }
+        52
+    }
+
+    /// Returns whether the provided ISO week year is a long year, i.e. has 53 weeks.
+    #[inline]
+    pub fn is_long_year(year: i32) -> bool {
+        Self::weeks_in_year(year) == 53
+    }
+
+    /// Creates an `IsoDate` from an ISO week year, week, and day of the week.
+    ///
+    /// Note that the resulting date may fall in a different calendar year than
+    /// `iso_week_year`, i.e. 2015-W01-1 is 2014-12-29.
+    pub fn from_ywd(iso_week_year: i32, week: u8, day: DayOfWeek) -> TemporalResult<Self> {
+        if week == 0 || week > Self::weeks_in_year(iso_week_year) {
+            return Err(
+                TemporalError::range().with_message("week is not valid for the ISO week year.")
+            );
+        }
+        let epoch_days = i64::from(Self::iso_week_year_start(iso_week_year).to_epoch_days())
+            + i64::from(week - 1) * 7
+            + i64::from(day as u8 - 1);
+        if MAX_EPOCH_DAYS < epoch_days.abs() {
+            return Err(TemporalError::range().with_message("epoch days exceed maximum range."));
+        }
+        let date = Self::from_epoch_days_unchecked(epoch_days as i32);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
+
+    /// Creates an `IsoDate` from an ISO week year, week, and ISO 8601 day number, from
+    /// Monday (1) to Sunday (7).
+    ///
+    /// This is equivalent to [`IsoDate::from_ywd`] with a raw day number.
+    pub fn from_iso_week_date(year: i32, week: u8, day: u8) -> TemporalResult<Self> {
+        let day = DayOfWeek::from_iso_number(day)
+            .ok_or_else(|| TemporalError::range().with_message("day of week is not valid."))?;
+        Self::from_ywd(year, week, day)
+    }
+
+    /// Returns the ISO week number of this `IsoDate`, from 1 through 53.
+    ///
+    /// The week belongs to the ISO week year given by [`IsoDate::year_of_week`].
+    #[inline]
+    pub fn week_of_year(&self) -> u8 {
+        self.iso_week_date().1
+    }
+
+    /// Returns the ISO week year of this `IsoDate`, which may differ from its calendar
+    /// year for dates in the first or last days of the year, e.g. 2019-12-30 is in
+    /// week 1 of 2020.
+    #[inline]
+    pub fn year_of_week(&self) -> i32 {
+        self.iso_week_date().0
+    }
+
+    /// Returns the ISO week year and week number of this `IsoDate`.
+    fn iso_week_date(&self) -> (i32, u8) {
+        // A week belongs to the ISO week year that contains its Thursday.
+        let epoch_days = i64::from(self.to_epoch_days());
+        let thursday = Self::from_epoch_days_unchecked(
+            (epoch_days + 4 - i64::from(iso_day_of_week(epoch_days))) as i32,
         );
+        (thursday.year, ((thursday.day_of_year() - 1) / 7 + 1) as u8)
+    }
 
-        (days, time)
+    /// Returns the Monday of week 1 for the provided ISO week year.
+    #[inline]
+    pub fn iso_week_year_start(year: i32) -> IsoDate {
+        // January 4th is always in week 1.
+        let jan_four = iso_date_epoch_days(year, 1, 4);
+        let monday = jan_four - i64::from(iso_day_of_week(jan_four)) + 1;
+        Self::from_epoch_days_unchecked(monday as i32)
     }
 
-    /// Difference this `IsoTime` against another and returning a `TimeDuration`.
-    pub(crate) fn diff(&self, other: &Self) -> TimeDuration {
-        let h = i64::from(other.hour) - i64::from(self.hour);
-        let m = i64::from(other.minute) - i64::from(self.minute);
-        let s = i64::from(other.second) - i64::from(self.second);
-        let ms = i64::from(other.millisecond) - i64::from(self.millisecond);
-        let mis = i128::from(other.microsecond) - i128::from(self.microsecond);
-        let ns = i128::from(other.nanosecond) - i128::from(self.nanosecond);
+    /// Returns an iterator over each ISO week of the provided ISO week year, yielding
+    /// the week number and its dates from Monday through Sunday.
+    ///
+    /// The iterator yields 52 or 53 weeks, and the first and last weeks may include
+    /// dates from the adjacent calendar years.
+    pub fn week_dates_in_year(week_year: i32) -> impl Iterator<Item = (u8, [IsoDate; 7])> {
+        let start = i64::from(Self::iso_week_year_start(week_year).to_epoch_days());
+        (0..Self::weeks_in_year(week_year)).map(move |week| {
+            let monday = start + i64::from(week) * 7;
+            (week + 1, Self::week_from_monday(monday))
+        })
+    }
 
-        TimeDuration::new_unchecked(h, m, s, ms, mis, ns)
+    /// Returns an iterator over each ISO week that contains a date of the provided
+    /// year and month, yielding the ISO week number and its dates from Monday through
+    /// Sunday.
+    ///
+    /// The first and last weeks may include dates from the adjacent months.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not within 1..=12.
+    pub fn week_dates_in_month(year: i32, month: u8) -> impl Iterator<Item = (u8, [IsoDate; 7])> {
+        let first = iso_date_epoch_days(year, month.into(), 1);
+        let last = first + i64::from(utils::iso_days_in_month(year, month)) - 1;
+        let start = first - i64::from(iso_day_of_week(first)) + 1;
+        let end = last - i64::from(iso_day_of_week(last)) + 1;
+        (start..=end).step_by(7).map(|monday| {
+            // A week belongs to the ISO week year of its Thursday.
+            let week_year = Self::from_epoch_days_unchecked((monday + 3) as i32).year;
+            let week_year_start = i64::from(Self::iso_week_year_start(week_year).to_epoch_days());
+            let week = ((monday - week_year_start) / 7 + 1) as u8;
+            (week, Self::week_from_monday(monday))
+        })
     }
 
-    // NOTE (nekevss): Specification seemed to be off / not entirely working, so the below was adapted from the
-    // temporal-polyfill
-    // TODO: DayLengthNS can probably be a u64, but keep as is for now and optimize.
-    /// Rounds the current `IsoTime` according to the provided settings.
-    pub(crate) fn round(
-        &self,
-        resolved_options: ResolvedRoundingOptions,
-    ) -> TemporalResult<(i64, Self)> {
-        // 1. If unit is "day" or "hour", then
-        let quantity = match resolved_options.smallest_unit {
-            Unit::Day | Unit::Hour => {
-                // a. Let quantity be ((((hour × 60 + minute) × 60 + second) × 1000 + millisecond)
-                // × 1000 + microsecond) × 1000 + nanosecond.
-                let minutes = i128::from(self.hour) * 60 + i128::from(self.minute);
-                let seconds = minutes * 60 + i128::from(self.second);
-                let millis = seconds * 1000 + i128::from(self.millisecond);
-                let micros = millis * 1000 + i128::from(self.microsecond);
-                micros * 1000 + i128::from(self.nanosecond)
-            }
-            // 2. Else if unit is "minute", then
-            Unit::Minute => {
-                // a. Let quantity be (((minute × 60 + second) × 1000 + millisecond) × 1000 + microsecond) × 1000 + nanosecond.
-                let seconds = i128::from(self.minute) * 60 + i128::from(self.second);
-                let millis = seconds * 1000 + i128::from(self.millisecond);
-                let micros = millis * 1000 + i128::from(self.microsecond);
-                micros * 1000 + i128::from(self.nanosecond)
-            }
-            // 3. Else if unit is "second", then
-            Unit::Second => {
-                // a. Let quantity be ((second × 1000 + millisecond) × 1000 + microsecond) × 1000 + nanosecond.
-                let millis = i128::from(self.second) * 1000 + i128::from(self.millisecond);
-                let micros = millis * 1000 + i128::from(self.microsecond);
-                micros * 1000 + i128::from(self.nanosecond)
-            }
-            // 4. Else if unit is "millisecond", then
-            Unit::Millisecond => {
-                // a. Let quantity be (millisecond × 1000 + microsecond) × 1000 + nanosecond.
-                let micros = i128::from(self.millisecond) * 1000 + i128::from(self.microsecond);
-                micros * 1000 + i128::from(self.nanosecond)
-            }
-            // 5. Else if unit is "microsecond", then
-            Unit::Microsecond => {
-                // a. Let quantity be microsecond × 1000 + nanosecond.
-                i128::from(self.microsecond) * 1000 + i128::from(self.nanosecond)
-            }
-            // 6. Else,
-            Unit::Nanosecond => {
-                // a. Assert: unit is "nanosecond".
-                // b. Let quantity be nanosecond.
-                i128::from(self.nanosecond)
+    fn week_from_monday(monday: i64) -> [IsoDate; 7] {
+        core::array::from_fn(|day| Self::from_epoch_days_unchecked((monday + day as i64) as i32))
+    }
+
+    /// Returns the first weekday, Monday through Friday, after this `IsoDate`.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    pub fn next_business_day(&self) -> TemporalResult<IsoDate> {
+        self.add_business_days(1)
+    }
+
+    /// Returns the last weekday, Monday through Friday, before this `IsoDate`.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    pub fn previous_business_day(&self) -> TemporalResult<IsoDate> {
+        self.add_business_days(-1)
+    }
+
+    /// Returns the `IsoDate` that is `days` business days after this `IsoDate`,
+    /// or before it if `days` is negative.
+    ///
+    /// Only weekdays are counted. If this `IsoDate` falls on a weekend, counting
+    /// starts from the adjacent weekend boundary, so one business day after a
+    /// Saturday or Sunday is the following Monday.
+    ///
+    /// Returns a `RangeError` if the result is not within the valid `Temporal` limits.
+    pub fn add_business_days(&self, days: i32) -> TemporalResult<IsoDate> {
+        let epoch_days = i64::from(self.to_epoch_days());
+        if days == 0 {
+            return Ok(*self);
+        }
+        let day_of_week = iso_day_of_week(epoch_days);
+        let (weeks, remainder) = (i64::from(days.unsigned_abs() / 5), days.unsigned_abs() % 5);
+        let mut offset = weeks * 7 + i64::from(remainder);
+        let result = if days > 0 {
+            // Treat a weekend as the preceding Friday.
+            let (start, weekday) = match day_of_week {
+                6 | 7 => (epoch_days - i64::from(day_of_week - 5), 5),
+                _ => (epoch_days, u32::from(day_of_week)),
+            };
+            if weekday + remainder > 5 {
+                offset += 2;
             }
-            _ => {
-                return Err(TemporalError::range()
-                    .with_message("Invalid smallestUnit value for time rounding."))
+            start + offset
+        } else {
+            // Treat a weekend as the following Monday.
+            let (start, weekday) = match day_of_week {
+                6 | 7 => (epoch_days + i64::from(8 - day_of_week), 1),
+                _ => (epoch_days, u32::from(day_of_week)),
+            };
+            if weekday <= remainder {
+                offset += 2;
             }
+            start - offset
         };
-        // 7. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 22 whose "Singular" column contains unit.
-        let length = NonZeroU128::new(
-            resolved_options
-                .smallest_unit
-                .as_nanoseconds()
-                .temporal_unwrap()?
-                .into(),
-        )
-        .temporal_unwrap()?;
+        let result = i32::try_from(result).map_err(|_| {
+            TemporalError::range().with_message("Date is not within ISO date time limits.")
+        })?;
+        Self::from_epoch_days(result)
+    }
 
-        let increment = resolved_options
-            .increment
-            .as_extended_increment()
-            .checked_mul(length)
-            .ok_or(TemporalError::range().with_message("increment exceeded valid range."))?;
+    /// Returns the number of business days from this `IsoDate` until `end`.
+    ///
+    /// The count includes `end` but not this `IsoDate`, so that for a weekday
+    /// `end`, `self.add_business_days(self.business_days_until(end)) == end`.
+    /// The result is negative if `end` is before this `IsoDate`.
+    pub fn business_days_until(&self, end: &IsoDate) -> i32 {
+        let start = i64::from(self.to_epoch_days());
+        let end = i64::from(end.to_epoch_days());
+        match start.cmp(&end) {
+            Ordering::Less => weekdays_in_range(start + 1, end + 1) as i32,
+            Ordering::Equal => 0,
+            Ordering::Greater => -(weekdays_in_range(end, start) as i32),
+        }
+    }
 
-        // 8. Let result be RoundNumberToIncrement(quantity, increment × unitLength, roundingMode) / unitLength.
-        let result = IncrementRounder::<i128>::from_signed_num(quantity, increment)?
-            .round(resolved_options.rounding_mode)
-            / length.get() as i128;
+    /// Returns the number of weekdays, Monday through Friday, in this `IsoDate`'s month.
+    pub fn business_days_in_month(&self) -> u8 {
+        let start = iso_date_epoch_days(self.year, self.month.into(), 1);
+        weekdays_in_range(start, start + i64::from(self.days_in_month())) as u8
+    }
 
-        let result_i64 = i64::from_i128(result)
-            .ok_or(TemporalError::range().with_message("round result valid range."))?;
+    /// Returns the number of weekdays, Monday through Friday, in this `IsoDate`'s year.
+    pub fn business_days_in_year(&self) -> u16 {
+        let start = iso_date_epoch_days(self.year, 1, 1);
+        let end = iso_date_epoch_days(self.year + 1, 1, 1);
+        weekdays_in_range(start, end) as u16
+    }
 
-        match resolved_options.smallest_unit {
-            // 9. If unit is "day", then
-            // a. Return Time Record { [[Days]]: result, [[Hour]]: 0, [[Minute]]: 0, [[Second]]: 0, [[Millisecond]]: 0, [[Microsecond]]: 0, [[Nanosecond]]: 0  }.
-            Unit::Day => Ok((result_i64, Self::default())),
-            // 10. If unit is "hour", then
-            // a. Return BalanceTime(result, 0, 0, 0, 0, 0).
-            Unit::Hour => Ok(Self::balance(result_i64, 0, 0, 0, 0, 0)),
-            // 11. If unit is "minute", then
-            // a. Return BalanceTime(hour, result, 0.0, 0.0, 0.0, 0).
-            Unit::Minute => Ok(Self::balance(self.hour.into(), result_i64, 0, 0, 0, 0)),
-            // 12. If unit is "second", then
-            // a. Return BalanceTime(hour, minute, result, 0.0, 0.0, 0).
-            Unit::Second => Ok(Self::balance(
-                self.hour.into(),
-                self.minute.into(),
-                result_i64,
-                0,
-                0,
-                0,
-            )),
-            // 13. If unit is "millisecond", then
+    /// Returns which business day of its month this `IsoDate` is, starting from 1, or
+    /// `None` if it falls on a weekend.
+    pub fn business_day_of_month(&self) -> Option<u8> {
+        let epoch_days = i64::from(self.to_epoch_days());
+        if iso_day_of_week(epoch_days) > 5 {
+            return None;
+        }
+        let start = iso_date_epoch_days(self.year, self.month.into(), 1);
+        Some(weekdays_in_range(start, epoch_days + 1) as u8)
+    }
+
+    /// Returns the number of times `weekday` occurs in this `IsoDate`'s month, 4 or 5.
+    pub fn count_weekday_in_month(&self, weekday: DayOfWeek) -> u8 {
+        let first = iso_date_epoch_days(self.year, self.month.into(), 1);
+        count_weekday_from(first, u16::from(self.days_in_month()), weekday) as u8
+    }
+
+    /// Returns the number of times `weekday` occurs in this `IsoDate`'s year, 52 or 53.
+    pub fn count_weekday_in_year(&self, weekday: DayOfWeek) -> u8 {
+        let first = iso_date_epoch_days(self.year, 1, 1);
+        let days = 365 + u16::from(self.is_leap_year());
+        count_weekday_from(first, days, weekday) as u8
+    }
+
+    /// Returns a grid of six weeks by seven days for the provided year and month, as
+    /// used by calendar widgets, where `grid[row][column]` is `None` for any cell
+    /// outside of the month.
+    ///
+    /// The first column is the first day of the week of `locale`, and the first row
+    /// contains the first day of the month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not within 1..=12.
+    pub fn to_week_grid(year: i32, month: u8, locale: GridLocale) -> [[Option<IsoDate>; 7]; 6] {
+        let first = iso_date_epoch_days(year, month.into(), 1);
+        let offset = (i64::from(iso_day_of_week(first))
+            - i64::from(locale.first_day_of_week() as u8))
+        .rem_euclid(7);
+        let mut grid = [[None; 7]; 6];
+        for day in 0..i64::from(utils::iso_days_in_month(year, month)) {
+            let cell = (offset + day) as usize;
+            grid[cell / 7][cell % 7] = Some(Self::from_epoch_days_unchecked((first + day) as i32));
+        }
+        grid
+    }
+
+    /// Returns an iterator over every date in this `IsoDate`'s month that falls on
+    /// `weekday`.
+    pub fn weekday_positions_in_month(&self, weekday: DayOfWeek) -> impl Iterator<Item = IsoDate> {
+        let first = iso_date_epoch_days(self.year, self.month.into(), 1);
+        let offset = i64::from(weekday as u8) - i64::from(iso_day_of_week(first));
+        let start = first + offset.rem_euclid(7);
+        (0..i64::from(self.count_weekday_in_month(weekday)))
+            .map(move |week| Self::from_epoch_days_unchecked((start + week * 7) as i32))
+    }
+
+    /// Returns the `business_day`-th weekday, Monday through Friday, of the provided
+    /// year and month, starting from 1.
+    ///
+    /// Holidays are not taken into account, so the first business day of January
+    /// 2024 is New Year's Day.
+    pub fn from_business_day_of_month(
+        year: i32,
+        month: u8,
+        business_day: u8,
+    ) -> TemporalResult<IsoDate> {
+        if !(1..=12).contains(&month) || !year_month_within_limits(year, month) {
+            return Err(
+                TemporalError::range().with_message("Year and month are not within ISO limits.")
+            );
+        }
+        let first = Self::new_unchecked(year, month, 1);
+        if business_day == 0 || business_day > first.business_days_in_month() {
+            return Err(
+                TemporalError::range().with_message("Business day is not within the month.")
+            );
+        }
+        // A weekend is treated as the preceding Friday, so the first weekday is one
+        // business day after it.
+        let offset = match first.business_day_of_month() {
+            Some(_) => business_day - 1,
+            None => business_day,
+        };
+        first.add_business_days(offset.into())
+    }
+
+    /// Sorts `dates` in ascending order.
+    #[inline]
+    pub fn sort_slice(dates: &mut [IsoDate]) {
+        dates.sort_unstable_by(Self::compare);
+    }
+
+    /// Returns the dates of `holiday_list` within `[start, end]`, sorted and without
+    /// duplicates.
+    pub fn holidays_in_range(
+        start: &IsoDate,
+        end: &IsoDate,
+        holiday_list: &[IsoDate],
+    ) -> Vec<IsoDate> {
+        let mut holidays = holiday_list
+            .iter()
+            .filter(Self::closed_range_filter(start, end))
+            .copied()
+            .collect::<Vec<_>>();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+
+    /// Returns the number of weekdays, Monday through Friday, within `[start, end]`
+    /// that are not in `holidays`.
+    ///
+    /// Holidays that fall on a weekend are ignored, and zero is returned if `end` is
+    /// before `start`.
+    pub fn business_days_between_excluding(
+        start: &IsoDate,
+        end: &IsoDate,
+        holidays: &[IsoDate],
+    ) -> u32 {
+        if end < start {
+            return 0;
+        }
+        let weekdays = weekdays_in_range(
+            i64::from(start.to_epoch_days()),
+            i64::from(end.to_epoch_days()) + 1,
+        );
+        let weekday_holidays = Self::holidays_in_range(start, end, holidays)
+            .iter()
+            .filter(|holiday| iso_day_of_week(i64::from(holiday.to_epoch_days())) < 6)
+            .count();
+        (weekdays - weekday_holidays as i64) as u32
+    }
+
+    /// Returns whether this `IsoDate` is the current date in the system time zone.
+    #[cfg(feature = "sys")]
+    pub fn is_today_with_provider(
+        &self,
+        provider: &impl crate::provider::TimeZoneProvider,
+    ) -> TemporalResult<bool> {
+        let today = crate::Temporal::try_now()?.plain_date_iso_with_provider(None, provider)?;
+        Ok(*self == today.iso)
+    }
+
+    /// Returns whether this `IsoDate` is the current date in the system time zone.
+    #[cfg(all(feature = "sys", feature = "compiled_data"))]
+    pub fn is_today(&self) -> TemporalResult<bool> {
+        let today = crate::Temporal::try_now()?.plain_date_iso(None)?;
+        Ok(*self == today.iso)
+    }
+
+    /// Returns the date of Easter Sunday in the provided year of the Gregorian calendar.
+    pub fn gregorian_easter(year: i32) -> TemporalResult<Self> {
+        let (month, day) = utils::algorithms::gregorian_easter(year);
+        let date = Self::new_unchecked(year, month, day);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
+
+    /// Returns whether this `IsoDate` is Easter Sunday in the Gregorian calendar.
+    #[inline]
+    pub fn is_gregorian_easter(&self) -> bool {
+        utils::algorithms::gregorian_easter(self.year) == (self.month, self.day)
+    }
+
+    /// Returns the number of completed years of age on `reference` for a `birth` date.
+    ///
+    /// A birthday on February 29th is considered to occur on February 28th in
+    /// common years.
+    pub fn age_in_years(birth: &IsoDate, reference: &IsoDate) -> TemporalResult<u32> {
+        if reference < birth {
+            return Err(
+                TemporalError::range().with_message("reference date must not precede birth date.")
+            );
+        }
+        let mut years = reference.year - birth.year;
+        if (reference.month, reference.day) < birth.anniversary_in(reference.year) {
+            years -= 1;
+        }
+        Ok(years as u32)
+    }
+
+    /// Returns the number of completed months of age on `reference` for a `birth` date.
+    ///
+    /// A monthly anniversary on a day beyond the end of a month is considered to occur on
+    /// the last day of that month.
+    pub fn age_in_months(birth: &IsoDate, reference: &IsoDate) -> TemporalResult<u32> {
+        if reference < birth {
+            return Err(
+                TemporalError::range().with_message("reference date must not precede birth date.")
+            );
+        }
+        let mut months = (reference.year - birth.year) * 12 + i32::from(reference.month)
+            - i32::from(birth.month);
+        if reference.day < constrain_iso_day(reference.year, reference.month, birth.day) {
+            months -= 1;
+        }
+        Ok(months as u32)
+    }
+
+    /// Returns the first birthday for a `birth` date that is strictly after `after`.
+    ///
+    /// If `after` precedes `birth`, `birth` itself is returned. A birthday on February
+    /// 29th is considered to occur on February 28th in common years.
+    pub fn next_birthday(birth: &IsoDate, after: &IsoDate) -> IsoDate {
+        if after < birth {
+            return *birth;
+        }
+        let (month, day) = birth.anniversary_in(after.year);
+        if (month, day) > (after.month, after.day) {
+            return Self::new_unchecked(after.year, month, day);
+        }
+        let (month, day) = birth.anniversary_in(after.year + 1);
+        Self::new_unchecked(after.year + 1, month, day)
+    }
+
+    /// Returns the month and day of this `IsoDate`'s anniversary in `year`.
+    #[inline]
+    fn anniversary_in(&self, year: i32) -> (u8, u8) {
+        (self.month, constrain_iso_day(year, self.month, self.day))
+    }
+
+    /// Returns whether this `IsoDate` is before the common era.
+    ///
+    /// `IsoDate` uses astronomical year numbering, so year 0 is 1 BCE
+    /// and year -1 is 2 BCE.
+    #[inline]
+    pub fn year_is_bce(&self) -> bool {
+        self.year <= 0
+    }
+
+    /// Returns the historical year and era designation for this `IsoDate`,
+    /// i.e. `(1, "BCE")` for year 0 or `(2025, "CE")` for year 2025.
+    #[inline]
+    pub fn to_historical_year(&self) -> (i32, &'static str) {
+        if self.year_is_bce() {
+            return (self.year.abs() + 1, "BCE");
+        }
+        (self.year, "CE")
+    }
+
+    /// Returns the year of era and era designation for this `IsoDate`, i.e.
+    /// `(1, "BCE")` for year 0 or `(2025, "CE")` for year 2025.
+    #[inline]
+    pub fn year_in_era(&self) -> (u32, &'static str) {
+        let (year, era) = self.to_historical_year();
+        (year.unsigned_abs(), era)
+    }
+
+    /// Returns the year of this `IsoDate` with its era designation, e.g. `44 BCE` or
+    /// `1066 CE`.
+    pub fn to_string_with_era(&self) -> String {
+        let (year, era) = self.year_in_era();
+        format!("{year} {era}")
+    }
+
+    /// Returns January 1st of the provided year of era.
+    ///
+    /// `era` is one of `CE`, `AD`, `BCE`, or `BC`, compared case-insensitively, and
+    /// `year` is counted from 1 in either era, so 1 BCE is year 0.
+    pub fn from_era_year(year: u32, era: &str) -> TemporalResult<IsoDate> {
+        let is_bce = match era {
+            _ if era.eq_ignore_ascii_case("CE") || era.eq_ignore_ascii_case("AD") => false,
+            _ if era.eq_ignore_ascii_case("BCE") || era.eq_ignore_ascii_case("BC") => true,
+            _ => return Err(TemporalError::range().with_message("Unrecognized era.")),
+        };
+        let year = i32::try_from(year)
+            .ok()
+            .filter(|year| *year != 0)
+            .ok_or_else(|| TemporalError::range().with_message("Year of era is not valid."))?;
+        let year = if is_bce { 1 - year } else { year };
+        let date = Self::new_unchecked(year, 1, 1);
+        if !iso_date_within_valid_limits(date) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(date)
+    }
+
+    /// Adds the date portion of a `Duration` to this `IsoDate` in the ISO calendar.
+    ///
+    /// Returns a `RangeError` if `duration` has any non-zero time units, as an
+    /// `IsoDate` has no time to add them to.
+    pub fn add_duration(
+        &self,
+        duration: &Duration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        if !duration.time().is_zero() {
+            return Err(TemporalError::range()
+                .with_message("Duration with time units cannot be added to an IsoDate."));
+        }
+        self.add(duration.date(), overflow)
+    }
+
+    /// Adds a `DateDuration` to this `IsoDate` in the ISO calendar.
+    ///
+    /// The years and months are added first, and `overflow` regulates the resulting
+    /// day before the weeks and days are added, e.g. adding one month to January 31st
+    /// is constrained to the end of February or rejected.
+    pub fn add(
+        &self,
+        duration: &DateDuration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let result = self.add_date_duration(duration, overflow)?;
+        if !iso_date_within_valid_limits(result) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(result)
+    }
+
+    /// Subtracts a `DateDuration` from this `IsoDate` in the ISO calendar.
+    ///
+    /// See [`IsoDate::add`] for how `overflow` is applied.
+    #[inline]
+    pub fn subtract(
+        &self,
+        duration: &DateDuration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        self.add(&duration.negated(), overflow)
+    }
+
+    /// Subtracts the date portion of a `Duration` from this `IsoDate` in the ISO calendar.
+    ///
+    /// See [`IsoDate::add_duration`] for the handling of time units.
+    pub fn subtract_duration(
+        &self,
+        duration: &Duration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        self.add_duration(&duration.negated(), overflow)
+    }
+
+    /// Adds `years` and `months` to this `IsoDate`, regulating the day with `overflow`.
+    pub fn add_years_months(
+        &self,
+        years: i64,
+        months: i64,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let (year, month) = balance_iso_year_month_with_clamp(
+            i64::from(self.year).saturating_add(years),
+            i64::from(self.month).saturating_add(months),
+        );
+        let result = Self::new_with_overflow(year, month, self.day, overflow)?;
+        if !iso_date_within_valid_limits(result) {
+            return Err(
+                TemporalError::range().with_message("Date is not within ISO date time limits.")
+            );
+        }
+        Ok(result)
+    }
+
+    /// Returns the resulting `IsoDate` from adding a provided `Duration` to this `IsoDate`
+    pub(crate) fn add_date_duration(
+        self,
+        duration: &DateDuration,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        // 1. Assert: year, month, day, years, months, weeks, and days are integers.
+        // 2. Assert: overflow is either "constrain" or "reject".
+        // 3. Let intermediate be ! BalanceISOYearMonth(year + years, month + months).
+        let intermediate = balance_iso_year_month_with_clamp(
+            i64::from(self.year) + duration.years,
+            i64::from(self.month) + duration.months,
+        );
+
+        // 4. Let intermediate be ? RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], day, overflow).
+        let intermediate =
+            Self::new_with_overflow(intermediate.0, intermediate.1, self.day, overflow)
+                .with_context("AddISODate")?;
+
+        // 5. Set days to days + 7 × weeks.
+        let additional_days = duration.days + (7 * duration.weeks); // Verify
+                                                                    // 6. Let d be intermediate.[[Day]] + days.
+        let intermediate_days = i64::from(intermediate.day) + additional_days;
+
+        // 7. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], d).
+        Self::try_balance(
+            intermediate.year,
+            intermediate.month.into(),
+            intermediate_days,
+        )
+    }
+
+    /// Returns the `DateDuration` from this `IsoDate` until `other`, balanced up to
+    /// `largest_unit`.
+    ///
+    /// `Unit::Auto` resolves to `Unit::Day`, and time units are rejected.
+    pub fn until(&self, other: &Self, largest_unit: Unit) -> TemporalResult<DateDuration> {
+        let largest_unit = match largest_unit {
+            Unit::Auto => Unit::Day,
+            unit => unit,
+        };
+        if !largest_unit.is_date_unit() {
+            return Err(TemporalError::range().with_message("largestUnit must be a date unit."));
+        }
+        self.diff_iso_date(other, largest_unit)
+    }
+
+    /// Returns the `DateDuration` from `other` until this `IsoDate`, balanced up to
+    /// `largest_unit`.
+    ///
+    /// This is equivalent to `other.until(self, largest_unit)`.
+    #[inline]
+    pub fn since(&self, other: &Self, largest_unit: Unit) -> TemporalResult<DateDuration> {
+        other.until(self, largest_unit)
+    }
+
+    pub(crate) fn diff_iso_date(
+        &self,
+        other: &Self,
+        largest_unit: Unit,
+    ) -> TemporalResult<DateDuration> {
+        // 1. Assert: IsValidISODate(y1, m1, d1) is true.
+        // 2. Assert: IsValidISODate(y2, m2, d2) is true.
+        // 3. Let sign be -CompareISODate(y1, m1, d1, y2, m2, d2).
+        let sign = -(self.cmp(other) as i8);
+        // 4. If sign = 0, return ! CreateDateDurationRecord(0, 0, 0, 0).
+        if sign == 0 {
+            return Ok(DateDuration::default());
+        };
+
+        // NOTE: Fast path for day and week units, which only depend on the
+        // difference in epoch days.
+        if largest_unit == Unit::Day || largest_unit == Unit::Week {
+            let days = i64::from(other.to_epoch_days()) - i64::from(self.to_epoch_days());
+            if largest_unit == Unit::Week {
+                return DateDuration::new(0, 0, days / 7, days % 7);
+            }
+            return DateDuration::new(0, 0, 0, days);
+        }
+
+        // 5. Let years be 0.
+        let mut years = 0;
+        let mut months = 0;
+        // 6. If largestUnit is "year", then
+        if largest_unit == Unit::Year || largest_unit == Unit::Month {
+            // others.year - self.year is adopted from temporal-proposal/polyfill as it saves iterations.
+            // a. Let candidateYears be sign.
+            let mut candidate_years: i32 = other.year - self.year;
+            if candidate_years != 0 {
+                candidate_years -= i32::from(sign);
+            }
+            // b. Repeat, while ISODateSurpasses(sign, y1 + candidateYears, m1, d1, y2, m2, d2) is false,
+            while !iso_date_surpasses(
+                &IsoDate::new_unchecked(self.year + candidate_years, self.month, self.day),
+                other,
+                sign,
+            ) {
+                // i. Set years to candidateYears.
+                years = candidate_years;
+                // ii. Set candidateYears to candidateYears + sign.
+                candidate_years += i32::from(sign);
+            }
+
+            // 7. Let months be 0.
+            // 8. If largestUnit is "year" or largestUnit is "month", then
+            // a. Let candidateMonths be sign.
+            let mut candidate_months: i32 = sign.into();
+            // b. Let intermediate be BalanceISOYearMonth(y1 + years, m1 + candidateMonths).
+            let mut intermediate =
+                balance_iso_year_month(self.year + years, i32::from(self.month) + candidate_months);
+            // c. Repeat, while ISODateSurpasses(sign, intermediate.[[Year]], intermediate.[[Month]], d1, y2, m2, d2) is false,
+            // Safety: balance_iso_year_month should always return a month value from 1..=12
+            while !iso_date_surpasses(
+                &IsoDate::new_unchecked(intermediate.0, intermediate.1 as u8, self.day),
+                other,
+                sign,
+            ) {
+                // i. Set months to candidateMonths.
+                months = candidate_months;
+                // ii. Set candidateMonths to candidateMonths + sign.
+                candidate_months += i32::from(sign);
+                // iii. Set intermediate to BalanceISOYearMonth(intermediate.[[Year]], intermediate.[[Month]] + sign).
+                intermediate = balance_iso_year_month(
+                    intermediate.0,
+                    i32::from(intermediate.1) + i32::from(sign),
+                );
+            }
+
+            if largest_unit == Unit::Month {
+                months += years * 12;
+                years = 0;
+            }
+        }
+
+        // 9. Set intermediate to BalanceISOYearMonth(y1 + years, m1 + months).
+        let intermediate =
+            balance_iso_year_month(self.year + years, i32::from(self.month) + months);
+        // 10. Let constrained be ! RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], d1, "constrain").
+        let constrained = Self::new_with_overflow(
+            intermediate.0,
+            intermediate.1,
+            self.day,
+            ArithmeticOverflow::Constrain,
+        )?;
+
+        // NOTE: Below is adapted from the polyfill. Preferring this as it avoids looping.
+        // 11. Let weeks be 0.
+        let days = utils::epoch_days_from_gregorian_date(other.year, other.month, other.day)
+            - utils::epoch_days_from_gregorian_date(
+                constrained.year,
+                constrained.month,
+                constrained.day,
+            );
+
+        // 17. Return ! CreateDateDurationRecord(years, months, weeks, days).
+        DateDuration::new(years as i64, months as i64, 0, days)
+    }
+}
+
+impl IsoDate {
+    /// Creates `[[ISOYear]]`, `[[isoMonth]]`, `[[isoDay]]` fields from `ICU4X`'s `Date<Iso>` struct.
+    pub(crate) fn to_icu4x(self) -> IcuDate<Iso> {
+        IcuDate::try_new_iso(self.year, self.month, self.day).expect("must not fail.")
+    }
+}
+
+// ==== `IsoTime` section ====
+
+/// An `IsoTime` record that contains `Temporal`'s
+/// time slots.
+#[non_exhaustive]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoTime {
+    /// A valid hour value between 0..=23
+    pub hour: u8, // 0..=23
+    /// A valid minute value between 0..=59
+    pub minute: u8, // 0..=59
+    /// A valid second value between 0..=59
+    pub second: u8, // 0..=59
+    /// A valid millisecond value between 0..=999
+    pub millisecond: u16, // 0..=999
+    /// A valid microsecond value between 0..=999
+    pub microsecond: u16, // 0..=999
+    /// A valid nanosecond value between 0..=999
+    pub nanosecond: u16, // 0..=999
+}
+
+impl fmt::Debug for IsoTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "IsoTime({})",
+            self.to_iso_string_with_precision(Precision::Auto)
+        )
+    }
+}
+
+impl fmt::Display for IsoTime {
+    /// Writes this `IsoTime` as an ISO 8601 time string, e.g. `14:30:05.001002003`.
+    ///
+    /// Trailing zeros of the fractional seconds are omitted, as is the fraction
+    /// entirely if it is zero.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_iso_string_with_precision(Precision::Auto))
+    }
+}
+
+impl FromStr for IsoTime {
+    type Err = TemporalError;
+
+    /// Parses an ISO 8601 extended time string, e.g. `14:30:05` or
+    /// `14:30:05.123456789`.
+    ///
+    /// The fields are not constrained, so `24:00:00` and leap seconds are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let record = parse_extended_time(s)?;
+        Self::new(
+            record.hour,
+            record.minute,
+            record.second,
+            (record.nanosecond / 1_000_000) as u16,
+            (record.nanosecond / 1_000 % 1_000) as u16,
+            (record.nanosecond % 1_000) as u16,
+            ArithmeticOverflow::Reject,
+        )
+    }
+}
+
+impl IsoTime {
+    /// An `IsoTime` set to 00:00:00
+    pub const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        millisecond: 0,
+        microsecond: 0,
+        nanosecond: 0,
+    };
+
+    /// An `IsoTime` set to 12:00:00
+    pub const NOON: Self = Self::noon();
+
+    /// Creates a new `IsoTime` without any validation.
+    pub(crate) const fn new_unchecked(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+    ) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        }
+    }
+
+    /// Creates a new `IsoTime` in a const context, panicking if any field is out of range.
+    ///
+    /// When used to initialize a `const`, an invalid time is a compile error.
+    ///
+    /// ```rust
+    /// use temporal_rs::iso::IsoTime;
+    ///
+    /// const OPENING: IsoTime = IsoTime::const_new(9, 30, 0, 0, 0, 0);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use temporal_rs::iso::IsoTime;
+    ///
+    /// const INVALID: IsoTime = IsoTime::const_new(24, 0, 0, 0, 0, 0);
+    /// ```
+    pub const fn const_new(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+    ) -> Self {
+        if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
+            panic!("IsoTime fields are not valid.");
+        }
+        Self::new_unchecked(hour, minute, second, millisecond, microsecond, nanosecond)
+    }
+
+    /// Creates an `IsoTime` for the time of day of JavaScript-style epoch milliseconds.
+    ///
+    /// Fractional milliseconds are floored, and values outside of the `i64` range saturate.
+    pub fn from_epoch_ms(epoch_ms: f64) -> Self {
+        let epoch_ms = floor_to_i64(epoch_ms);
+        Self::new_unchecked(
+            epoch_ms.div_euclid(3_600_000).rem_euclid(24) as u8,
+            epoch_ms.div_euclid(60_000).rem_euclid(60) as u8,
+            epoch_ms.div_euclid(1000).rem_euclid(60) as u8,
+            epoch_ms.rem_euclid(1000) as u16,
+            0,
+            0,
+        )
+    }
+
+    /// Returns the whole seconds since midnight of this `IsoTime`, discarding any
+    /// fractional second.
+    #[inline]
+    pub fn seconds_since_midnight(&self) -> u32 {
+        u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second)
+    }
+
+    /// Creates an `IsoTime` from seconds since midnight.
+    ///
+    /// Values of a full day, 86400, or more are rejected.
+    pub fn from_seconds_since_midnight(secs: u32) -> TemporalResult<Self> {
+        if secs >= 86_400 {
+            return Err(TemporalError::range().with_message("Seconds must be less than a day."));
+        }
+        Ok(Self::new_unchecked(
+            (secs / 3600) as u8,
+            (secs / 60 % 60) as u8,
+            (secs % 60) as u8,
+            0,
+            0,
+            0,
+        ))
+    }
+
+    /// Creates a new regulated `IsoTime`.
+    pub fn new(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        millisecond: u16,
+        microsecond: u16,
+        nanosecond: u16,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<IsoTime> {
+        match overflow {
+            ArithmeticOverflow::Constrain => {
+                let h = hour.clamp(0, 23);
+                let min = minute.clamp(0, 59);
+                let sec = second.clamp(0, 59);
+                let milli = millisecond.clamp(0, 999);
+                let micro = microsecond.clamp(0, 999);
+                let nano = nanosecond.clamp(0, 999);
+                Ok(Self::new_unchecked(h, min, sec, milli, micro, nano))
+            }
+            ArithmeticOverflow::Reject => {
+                if !is_valid_time(hour, minute, second, millisecond, microsecond, nanosecond) {
+                    return Err(TemporalError::range().with_message("IsoTime is not valid"));
+                };
+                Ok(Self::new_unchecked(
+                    hour,
+                    minute,
+                    second,
+                    millisecond,
+                    microsecond,
+                    nanosecond,
+                ))
+            }
+        }
+    }
+
+    /// Creates a new `IsoTime` from `f64` time fields.
+    ///
+    /// With `ArithmeticOverflow::Reject`, every field must be an integer within its
+    /// valid range. With `ArithmeticOverflow::Constrain`, the fractional part of each
+    /// field is carried into the next smaller unit, with any fractional nanoseconds
+    /// truncated, and the fields are then balanced, wrapping around midnight and
+    /// discarding the carried days.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_components_f64(
+        hour: f64,
+        minute: f64,
+        second: f64,
+        millisecond: f64,
+        microsecond: f64,
+        nanosecond: f64,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let fields = [hour, minute, second, millisecond, microsecond, nanosecond];
+        if fields.iter().any(|field| !field.is_finite()) {
+            return Err(TemporalError::range().with_message("IsoTime fields must be finite."));
+        }
+        match overflow {
+            ArithmeticOverflow::Reject => {
+                const MAXIMUMS: [f64; 6] = [23.0, 59.0, 59.0, 999.0, 999.0, 999.0];
+                let is_valid = fields.iter().zip(MAXIMUMS).all(|(field, max)| {
+                    (0.0..=max).contains(field) && f64::from(*field as u16) == *field
+                });
+                if !is_valid {
+                    return Err(TemporalError::range().with_message("IsoTime is not valid"));
+                }
+                Ok(Self::new_unchecked(
+                    hour as u8,
+                    minute as u8,
+                    second as u8,
+                    millisecond as u16,
+                    microsecond as u16,
+                    nanosecond as u16,
+                ))
+            }
+            ArithmeticOverflow::Constrain => {
+                const FACTORS: [f64; 5] = [60.0, 60.0, 1000.0, 1000.0, 1000.0];
+                let mut integral = [0i64; 6];
+                let mut carry = 0.0;
+                for (i, field) in fields.iter().enumerate() {
+                    let value = field + carry;
+                    // NOTE: Values beyond the `i64` range saturate.
+                    integral[i] = value as i64;
+                    carry = FACTORS
+                        .get(i)
+                        .map_or(0.0, |factor| (value - integral[i] as f64) * factor);
+                }
+                let [hour, minute, second, millisecond, microsecond, nanosecond] = integral;
+                let (_, time) =
+                    Self::balance(hour, minute, second, millisecond, microsecond, nanosecond);
+                Ok(time)
+            }
+        }
+    }
+
+    /// Creates a new `Time` with the fields provided from a `PartialTime`.
+    #[inline]
+    pub(crate) fn with(
+        &self,
+        partial: PartialTime,
+        overflow: ArithmeticOverflow,
+    ) -> TemporalResult<Self> {
+        let hour = partial.hour.unwrap_or(self.hour);
+        let minute = partial.minute.unwrap_or(self.minute);
+        let second = partial.second.unwrap_or(self.second);
+        let millisecond = partial.millisecond.unwrap_or(self.millisecond);
+        let microsecond = partial.microsecond.unwrap_or(self.microsecond);
+        let nanosecond = partial.nanosecond.unwrap_or(self.nanosecond);
+        Self::new(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            overflow,
+        )
+    }
+
+    /// Returns an `IsoTime` set to 12:00:00
+    pub(crate) const fn noon() -> Self {
+        Self {
+            hour: 12,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            microsecond: 0,
+            nanosecond: 0,
+        }
+    }
+
+    /// Returns an `IsoTime` based off parse components.
+    pub(crate) fn from_time_record(time_record: TimeRecord) -> TemporalResult<Self> {
+        let second = time_record.second.clamp(0, 59);
+        let fractional_seconds = time_record
+            .fraction
+            .map(|x| {
+                x.to_nanoseconds().ok_or(
+                    TemporalError::range().with_message("fractional seconds exceeds nine digits."),
+                )
+            })
+            .transpose()?
+            .unwrap_or(0);
+
+        let (millisecond, rem) = fractional_seconds.div_rem_euclid(&1_000_000);
+        let (micros, nanos) = rem.div_rem_euclid(&1_000);
+
+        Self::new(
+            time_record.hour,
+            time_record.minute,
+            second,
+            millisecond as u16,
+            micros as u16,
+            nanos as u16,
+            ArithmeticOverflow::Reject,
+        )
+    }
+
+    /// Balances and creates a new `IsoTime` with `day` overflow from the provided values.
+    ///
+    /// All carry propagation is done with integer arithmetic, and the fields are
+    /// only narrowed to their `IsoTime` representation once they are in range.
+    pub(crate) fn balance(
+        hour: i64,
+        minute: i64,
+        second: i64,
+        millisecond: i64,
+        microsecond: i64,
+        nanosecond: i64,
+    ) -> (i64, Self) {
+        // 1. Set microsecond to microsecond + floor(nanosecond / 1000).
+        // 2. Set nanosecond to nanosecond modulo 1000.
+        let (quotient, nanosecond) = div_mod(nanosecond, 1000);
+        let microsecond = microsecond + quotient;
+
+        // 3. Set millisecond to millisecond + floor(microsecond / 1000).
+        // 4. Set microsecond to microsecond modulo 1000.
+        let (quotient, microsecond) = div_mod(microsecond, 1000);
+        let millisecond = millisecond + quotient;
+
+        // 5. Set second to second + floor(millisecond / 1000).
+        // 6. Set millisecond to millisecond modulo 1000.
+        let (quotient, millisecond) = div_mod(millisecond, 1000);
+        let second = second + quotient;
+
+        // 7. Set minute to minute + floor(second / 60).
+        // 8. Set second to second modulo 60.
+        let (quotient, second) = div_mod(second, 60);
+        let minute = minute + quotient;
+
+        // 9. Set hour to hour + floor(minute / 60).
+        // 10. Set minute to minute modulo 60.
+        let (quotient, minute) = div_mod(minute, 60);
+        let hour = hour + quotient;
+
+        // 11. Let days be floor(hour / 24).
+        // 12. Set hour to hour modulo 24.
+        let (days, hour) = div_mod(hour, 24);
+
+        let time = Self::new_unchecked(
+            hour as u8,
+            minute as u8,
+            second as u8,
+            millisecond as u16,
+            microsecond as u16,
+            nanosecond as u16,
+        );
+
+        (days, time)
+    }
+
+    /// Returns the signed nanoseconds from this `IsoTime` to `other`.
+    #[inline]
+    pub fn diff_in_nanoseconds(&self, other: &IsoTime) -> i64 {
+        other.total_nanoseconds() - self.total_nanoseconds()
+    }
+
+    /// Returns the signed microseconds from this `IsoTime` to `other`, truncated toward zero.
+    #[inline]
+    pub fn diff_in_microseconds(&self, other: &IsoTime) -> i64 {
+        self.diff_in_nanoseconds(other) / 1_000
+    }
+
+    /// Returns the signed milliseconds from this `IsoTime` to `other`, truncated toward zero.
+    #[inline]
+    pub fn diff_in_milliseconds(&self, other: &IsoTime) -> i64 {
+        self.diff_in_nanoseconds(other) / 1_000_000
+    }
+
+    /// Returns the signed seconds from this `IsoTime` to `other`, truncated toward zero.
+    #[inline]
+    pub fn diff_in_seconds(&self, other: &IsoTime) -> i64 {
+        self.diff_in_nanoseconds(other) / 1_000_000_000
+    }
+
+    /// Returns the nanoseconds since midnight for this `IsoTime`.
+    #[inline]
+    fn total_nanoseconds(&self) -> i64 {
+        self.to_epoch_ms() * 1_000_000
+            + i64::from(self.microsecond) * 1_000
+            + i64::from(self.nanosecond)
+    }
+
+    /// Returns the nanoseconds since midnight for this `IsoTime`, which is always less
+    /// than `NS_PER_DAY`.
+    #[inline]
+    pub fn to_total_nanoseconds(&self) -> u64 {
+        // NOTE: The cast is safe as the total nanoseconds are never negative.
+        self.total_nanoseconds() as u64
+    }
+
+    /// Creates an `IsoTime` from nanoseconds since midnight.
+    ///
+    /// Values of a full day, `NS_PER_DAY`, or more are rejected.
+    pub fn from_total_nanoseconds(ns: u64) -> TemporalResult<Self> {
+        if ns >= NS_PER_DAY {
+            return Err(TemporalError::range().with_message("Nanoseconds must be less than a day."));
+        }
+        Ok(Self::balance(0, 0, 0, 0, 0, ns as i64).1)
+    }
+
+    /// Returns the total nanoseconds of this `IsoTime`'s fractional second.
+    #[inline]
+    pub fn total_sub_second_nanoseconds(&self) -> u32 {
+        u32::from(self.millisecond) * 1_000_000
+            + u32::from(self.microsecond) * 1_000
+            + u32::from(self.nanosecond)
+    }
+
+    /// Returns this `IsoTime` with its fractional second replaced by `ns` nanoseconds.
+    ///
+    /// Values of `ns` of one second or more are rejected.
+    pub fn with_sub_second_nanoseconds(&self, ns: u32) -> TemporalResult<Self> {
+        if ns >= 1_000_000_000 {
+            return Err(TemporalError::range()
+                .with_message("sub-second nanoseconds must be less than one second."));
+        }
+        Ok(Self {
+            millisecond: (ns / 1_000_000) as u16,
+            microsecond: (ns / 1_000 % 1_000) as u16,
+            nanosecond: (ns % 1_000) as u16,
+            ..*self
+        })
+    }
+
+    /// Returns a `TemporalBuilder` for chaining operations on this `IsoTime`.
+    #[inline]
+    pub fn builder(&self) -> TemporalBuilder<Self> {
+        TemporalBuilder::new(*self)
+    }
+
+    /// Returns this `IsoTime` with its fractional second set to zero.
+    #[inline]
+    pub fn truncate_sub_seconds(&self) -> Self {
+        Self {
+            millisecond: 0,
+            microsecond: 0,
+            nanosecond: 0,
+            ..*self
+        }
+    }
+
+    /// Returns this `IsoTime` rounded down to the hour.
+    #[inline]
+    pub fn floor_to_hour(&self) -> Self {
+        self.round_to_increment(3_600_000_000_000, RoundingMode::Floor)
+            .1
+    }
+
+    /// Returns this `IsoTime` rounded down to the minute.
+    #[inline]
+    pub fn floor_to_minute(&self) -> Self {
+        self.round_to_increment(60_000_000_000, RoundingMode::Floor)
+            .1
+    }
+
+    /// Returns this `IsoTime` rounded down to the second.
+    #[inline]
+    pub fn floor_to_second(&self) -> Self {
+        self.round_to_increment(1_000_000_000, RoundingMode::Floor)
+            .1
+    }
+
+    /// Returns this `IsoTime` rounded down to the millisecond.
+    #[inline]
+    pub fn floor_to_millisecond(&self) -> Self {
+        self.round_to_increment(1_000_000, RoundingMode::Floor).1
+    }
+
+    /// Returns this `IsoTime` rounded down to the microsecond.
+    #[inline]
+    pub fn floor_to_microsecond(&self) -> Self {
+        self.round_to_increment(1_000, RoundingMode::Floor).1
+    }
+
+    /// Returns this `IsoTime` rounded to the nearest hour, with ties rounded up,
+    /// along with the days carried over from rounding up past midnight.
+    #[inline]
+    pub fn round_to_nearest_hour(&self) -> (i64, Self) {
+        self.round_to_increment(3_600_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoTime` rounded to the nearest minute, with ties rounded up,
+    /// along with the days carried over from rounding up past midnight.
+    #[inline]
+    pub fn round_to_nearest_minute(&self) -> (i64, Self) {
+        self.round_to_increment(60_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoTime` rounded to the nearest second, with ties rounded up,
+    /// along with the days carried over from rounding up past midnight.
+    #[inline]
+    pub fn round_to_nearest_second(&self) -> (i64, Self) {
+        self.round_to_increment(1_000_000_000, RoundingMode::HalfExpand)
+    }
+
+    /// Returns this `IsoTime` rounded up to the hour, along with the days carried
+    /// over from rounding up past midnight.
+    #[inline]
+    pub fn ceil_to_hour(&self) -> (i64, Self) {
+        self.round_to_increment(3_600_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Returns this `IsoTime` rounded up to the minute, along with the days carried
+    /// over from rounding up past midnight.
+    #[inline]
+    pub fn ceil_to_minute(&self) -> (i64, Self) {
+        self.round_to_increment(60_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Returns this `IsoTime` rounded up to the second, along with the days carried
+    /// over from rounding up past midnight.
+    #[inline]
+    pub fn ceil_to_second(&self) -> (i64, Self) {
+        self.round_to_increment(1_000_000_000, RoundingMode::Ceil)
+    }
+
+    /// Rounds this `IsoTime` to a multiple of `increment` nanoseconds, returning the
+    /// days carried over along with the balanced time.
+    fn round_to_increment(&self, increment: i64, mode: RoundingMode) -> (i64, Self) {
+        let quotient = apply_unsigned_rounding_mode(
+            i128::from(self.total_nanoseconds()),
+            i128::from(increment),
+            mode.get_unsigned_round_mode(true),
+        );
+        Self::balance(0, 0, 0, 0, 0, quotient as i64 * increment)
+    }
+
+    /// Returns an ISO 8601 string for this `IsoTime` with the provided precision.
+    ///
+    /// `Precision::Auto` omits any trailing zeros of the fractional seconds, while
+    /// `Precision::Digit` always emits that many fractional digits, truncating any
+    /// remaining digits.
+    #[inline]
+    pub fn to_iso_string_with_precision(&self, precision: Precision) -> String {
+        IxdtfStringBuilder::default()
+            .with_time(*self, precision)
+            .build()
+    }
+
+    /// Returns a time string for this `IsoTime` with the provided precision.
+    ///
+    /// This is an alias of [`IsoTime::to_iso_string_with_precision`].
+    #[inline]
+    pub fn to_time_string_with_precision(&self, precision: Precision) -> String {
+        self.to_iso_string_with_precision(precision)
+    }
+
+    /// Returns a time string for this `IsoTime` with only as much precision as needed,
+    /// e.g. `12:00`, `12:00:30`, or `12:00:30.5`.
+    ///
+    /// The seconds are omitted if they and the fractional seconds are zero, and the
+    /// fractional seconds are omitted if they are zero.
+    pub fn to_human_readable_string(&self) -> String {
+        let precision = if self.second == 0 && self.total_sub_second_nanoseconds() == 0 {
+            Precision::Minute
+        } else {
+            Precision::Auto
+        };
+        self.to_iso_string_with_precision(precision)
+    }
+
+    /// Returns an RFC 3339 partial time string for this `IsoTime`, e.g. `14:30:00` or
+    /// `14:30:00.123`.
+    ///
+    /// `None` is equivalent to `Precision::Auto`. As RFC 3339 requires the seconds,
+    /// `Precision::Minute` is treated as `Precision::Digit(0)`.
+    pub fn to_rfc3339_time_string(&self, precision: Option<Precision>) -> String {
+        let precision = match precision.unwrap_or_default() {
+            Precision::Minute => Precision::Digit(0),
+            precision => precision,
+        };
+        self.to_iso_string_with_precision(precision)
+    }
+
+    /// Returns an RFC 3339 full time string for this `IsoTime` at the UTC offset of
+    /// `offset_seconds`, e.g. `14:30:00+05:30`.
+    ///
+    /// The offset must be a whole number of minutes less than 24 hours from UTC.
+    pub fn to_rfc3339_offset_time_string(&self, offset_seconds: i32) -> TemporalResult<String> {
+        if offset_seconds % 60 != 0 || offset_seconds.unsigned_abs() >= 86_400 {
+            return Err(TemporalError::range()
+                .with_message("RFC 3339 offset must be whole minutes within a day."));
+        }
+        let sign = if offset_seconds < 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let minutes = offset_seconds.unsigned_abs() / 60;
+        Ok(IxdtfStringBuilder::default()
+            .with_time(*self, Precision::Auto)
+            .with_minute_offset(
+                sign,
+                (minutes / 60) as u8,
+                (minutes % 60) as u8,
+                DisplayOffset::Auto,
+            )
+            .build())
+    }
+
+    /// Parses an RFC 3339 partial time string, e.g. `14:30:00`, `14:30:00.5`, or
+    /// `T14:30:00Z`.
+    ///
+    /// A leap second, i.e. a second of 60, is constrained to 59.
+    pub fn from_rfc3339_time_string(s: &str) -> TemporalResult<Self> {
+        let record = parse_rfc3339_time(s)?;
+        let second = if record.second == 60 {
+            59
+        } else {
+            record.second
+        };
+        Self::new(
+            record.hour,
+            record.minute,
+            second,
+            (record.nanosecond / 1_000_000) as u16,
+            (record.nanosecond / 1_000 % 1_000) as u16,
+            (record.nanosecond % 1_000) as u16,
+            ArithmeticOverflow::Reject,
+        )
+    }
+
+    /// Returns the `TimeDuration` from this `IsoTime` until `other`, balanced up to
+    /// `largest_unit`.
+    ///
+    /// `Unit::Auto` resolves to `Unit::Hour`, and date units are rejected.
+    pub fn until(&self, other: &Self, largest_unit: Unit) -> TemporalResult<TimeDuration> {
+        let largest_unit = match largest_unit {
+            Unit::Auto => Unit::Hour,
+            unit => unit,
+        };
+        if !largest_unit.is_time_unit() {
+            return Err(TemporalError::range().with_message("largestUnit must be a time unit."));
+        }
+        let norm = NormalizedTimeDuration::from_time_duration(&self.diff(other));
+        TimeDuration::from_normalized(norm, largest_unit).map(|(_, duration)| duration)
+    }
+
+    /// Returns the `TimeDuration` from `other` until this `IsoTime`, balanced up to
+    /// `largest_unit`.
+    ///
+    /// This is equivalent to `other.until(self, largest_unit)`.
+    #[inline]
+    pub fn since(&self, other: &Self, largest_unit: Unit) -> TemporalResult<TimeDuration> {
+        other.until(self, largest_unit)
+    }
+
+    /// Returns the `TimeDuration` from this `IsoTime` until `other`, rounded and balanced
+    /// according to the provided `DifferenceSettings`.
+    ///
+    /// The difference never wraps around midnight, so a later `other` on the next day,
+    /// e.g. `23:00` until `01:00`, yields a negative duration.
+    pub fn until_with_settings(
+        &self,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<TimeDuration> {
+        self.diff_with_settings(DifferenceOperation::Until, other, settings)
+    }
+
+    /// Returns the `TimeDuration` from `other` until this `IsoTime`, rounded and balanced
+    /// according to the provided `DifferenceSettings`.
+    ///
+    /// As with `PlainTime::since`, the rounding mode is negated before rounding.
+    pub fn since_with_settings(
+        &self,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<TimeDuration> {
+        self.diff_with_settings(DifferenceOperation::Since, other, settings)
+    }
+
+    /// Shared implementation of `until_with_settings` and `since_with_settings`, following
+    /// DifferenceTemporalPlainTime.
+    fn diff_with_settings(
+        &self,
+        op: DifferenceOperation,
+        other: &Self,
+        settings: DifferenceSettings,
+    ) -> TemporalResult<TimeDuration> {
+        let resolved = ResolvedRoundingOptions::from_diff_settings(
+            settings,
+            op,
+            UnitGroup::Time,
+            Unit::Hour,
+            Unit::Nanosecond,
+        )?;
+
+        let mut norm = self.diff(other).to_normalized();
+        if resolved.smallest_unit != Unit::Nanosecond
+            || resolved.increment != RoundingIncrement::ONE
+        {
+            norm = norm.round(resolved)?;
+        }
+
+        let result = TimeDuration::from_normalized(norm, resolved.largest_unit)?.1;
+        match op {
+            DifferenceOperation::Until => Ok(result),
+            DifferenceOperation::Since => Ok(result.negated()),
+        }
+    }
+
+    /// Difference this `IsoTime` against another and returning a `TimeDuration`.
+    pub(crate) fn diff(&self, other: &Self) -> TimeDuration {
+        let h = i64::from(other.hour) - i64::from(self.hour);
+        let m = i64::from(other.minute) - i64::from(self.minute);
+        let s = i64::from(other.second) - i64::from(self.second);
+        let ms = i64::from(other.millisecond) - i64::from(self.millisecond);
+        let mis = i128::from(other.microsecond) - i128::from(self.microsecond);
+        let ns = i128::from(other.nanosecond) - i128::from(self.nanosecond);
+
+        TimeDuration::new_unchecked(h, m, s, ms, mis, ns)
+    }
+
+    // NOTE (nekevss): Specification seemed to be off / not entirely working, so the below was adapted from the
+    // temporal-polyfill
+    // TODO: DayLengthNS can probably be a u64, but keep as is for now and optimize.
+    /// Rounds the current `IsoTime` according to the provided settings.
+    pub(crate) fn round(
+        &self,
+        resolved_options: ResolvedRoundingOptions,
+    ) -> TemporalResult<(i64, Self)> {
+        // 1. If unit is "day" or "hour", then
+        let quantity = match resolved_options.smallest_unit {
+            Unit::Day | Unit::Hour => {
+                // a. Let quantity be ((((hour × 60 + minute) × 60 + second) × 1000 + millisecond)
+                // × 1000 + microsecond) × 1000 + nanosecond.
+                let minutes = i128::from(self.hour) * 60 + i128::from(self.minute);
+                let seconds = minutes * 60 + i128::from(self.second);
+                let millis = seconds * 1000 + i128::from(self.millisecond);
+                let micros = millis * 1000 + i128::from(self.microsecond);
+                micros * 1000 + i128::from(self.nanosecond)
+            }
+            // 2. Else if unit is "minute", then
+            Unit::Minute => {
+                // a. Let quantity be (((minute × 60 + second) × 1000 + millisecond) × 1000 + microsecond) × 1000 + nanosecond.
+                let seconds = i128::from(self.minute) * 60 + i128::from(self.second);
+                let millis = seconds * 1000 + i128::from(self.millisecond);
+                let micros = millis * 1000 + i128::from(self.microsecond);
+                micros * 1000 + i128::from(self.nanosecond)
+            }
+            // 3. Else if unit is "second", then
+            Unit::Second => {
+                // a. Let quantity be ((second × 1000 + millisecond) × 1000 + microsecond) × 1000 + nanosecond.
+                let millis = i128::from(self.second) * 1000 + i128::from(self.millisecond);
+                let micros = millis * 1000 + i128::from(self.microsecond);
+                micros * 1000 + i128::from(self.nanosecond)
+            }
+            // 4. Else if unit is "millisecond", then
+            Unit::Millisecond => {
+                // a. Let quantity be (millisecond × 1000 + microsecond) × 1000 + nanosecond.
+                let micros = i128::from(self.millisecond) * 1000 + i128::from(self.microsecond);
+                micros * 1000 + i128::from(self.nanosecond)
+            }
+            // 5. Else if unit is "microsecond", then
+            Unit::Microsecond => {
+                // a. Let quantity be microsecond × 1000 + nanosecond.
+                i128::from(self.microsecond) * 1000 + i128::from(self.nanosecond)
+            }
+            // 6. Else,
+            Unit::Nanosecond => {
+                // a. Assert: unit is "nanosecond".
+                // b. Let quantity be nanosecond.
+                i128::from(self.nanosecond)
+            }
+            _ => {
+                return Err(TemporalError::range()
+                    .with_message("Invalid smallestUnit value for time rounding."))
+            }
+        };
+        // 7. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 22 whose "Singular" column contains unit.
+        let length = NonZeroU128::new(
+            resolved_options
+                .smallest_unit
+                .as_nanoseconds()
+                .temporal_unwrap()?
+                .into(),
+        )
+        .temporal_unwrap()?;
+
+        let increment = resolved_options
+            .increment
+            .as_extended_increment()
+            .checked_mul(length)
+            .ok_or(TemporalError::range().with_message("increment exceeded valid range."))?;
+
+        // 8. Let result be RoundNumberToIncrement(quantity, increment × unitLength, roundingMode) / unitLength.
+        let result = IncrementRounder::<i128>::from_signed_num(quantity, increment)?
+            .round(resolved_options.rounding_mode)
+            / length.get() as i128;
+
+        let result_i64 = i64::from_i128(result)
+            .ok_or(TemporalError::range().with_message("round result valid range."))?;
+
+        match resolved_options.smallest_unit {
+            // 9. If unit is "day", then
+            // a. Return Time Record { [[Days]]: result, [[Hour]]: 0, [[Minute]]: 0, [[Second]]: 0, [[Millisecond]]: 0, [[Microsecond]]: 0, [[Nanosecond]]: 0  }.
+            Unit::Day => Ok((result_i64, Self::default())),
+            // 10. If unit is "hour", then
+            // a. Return BalanceTime(result, 0, 0, 0, 0, 0).
+            Unit::Hour => Ok(Self::balance(result_i64, 0, 0, 0, 0, 0)),
+            // 11. If unit is "minute", then
+            // a. Return BalanceTime(hour, result, 0.0, 0.0, 0.0, 0).
+            Unit::Minute => Ok(Self::balance(self.hour.into(), result_i64, 0, 0, 0, 0)),
+            // 12. If unit is "second", then
+            // a. Return BalanceTime(hour, minute, result, 0.0, 0.0, 0).
+            Unit::Second => Ok(Self::balance(
+                self.hour.into(),
+                self.minute.into(),
+                result_i64,
+                0,
+                0,
+                0,
+            )),
+            // 13. If unit is "millisecond", then
             // a. Return BalanceTime(hour, minute, second, result, 0.0, 0).
             Unit::Millisecond => Ok(Self::balance(
                 self.hour.into(),
@@ -844,7 +3524,7 @@ impl IsoTime {
                 self.minute.into(),
                 self.second.into(),
                 self.millisecond.into(),
-                result_i64.into(),
+                result_i64,
                 0,
             )),
             // 15. Assert: unit is "nanosecond".
@@ -855,245 +3535,3700 @@ impl IsoTime {
                 self.second.into(),
                 self.millisecond.into(),
                 self.microsecond.into(),
-                result_i64.into(),
+                result_i64,
             )),
             _ => Err(TemporalError::assert()),
         }
     }
 
-    /// Checks if the time is a valid `IsoTime`
-    pub(crate) fn is_valid(&self) -> bool {
-        if !(0..=23).contains(&self.hour) {
-            return false;
+    /// Checks if the time is a valid `IsoTime`
+    pub(crate) fn is_valid(&self) -> bool {
+        if !(0..=23).contains(&self.hour) {
+            return false;
+        }
+
+        let min_sec = 0..=59;
+        if !min_sec.contains(&self.minute) || !min_sec.contains(&self.second) {
+            return false;
+        }
+
+        let sub_second = 0..=999;
+        sub_second.contains(&self.millisecond)
+            && sub_second.contains(&self.microsecond)
+            && sub_second.contains(&self.nanosecond)
+    }
+
+    /// Adds a `NormalizedTimeDuration` to this `IsoTime`, returning the overflow days and
+    /// the balanced time.
+    pub fn add(&self, norm: NormalizedTimeDuration) -> (i64, Self) {
+        // 1. Set second to second + NormalizedTimeDurationSeconds(norm).
+        let seconds = i64::from(self.second) + norm.seconds();
+        // 2. Set nanosecond to nanosecond + NormalizedTimeDurationSubseconds(norm).
+        let nanos = i32::from(self.nanosecond) + norm.subseconds();
+        // 3. Return BalanceTime(hour, minute, second, millisecond, microsecond, nanosecond).
+        Self::balance(
+            self.hour.into(),
+            self.minute.into(),
+            seconds,
+            self.millisecond.into(),
+            self.microsecond.into(),
+            nanos.into(),
+        )
+    }
+
+    /// Subtracts a `NormalizedTimeDuration` from this `IsoTime`, returning the overflow
+    /// days and the balanced time.
+    pub fn sub(&self, norm: NormalizedTimeDuration) -> (i64, Self) {
+        self.add(NormalizedTimeDuration(-norm.0))
+    }
+
+    /// Adds `seconds` to this `IsoTime`, returning the overflow days and the balanced time.
+    pub fn add_seconds(&self, seconds: i64) -> (i64, Self) {
+        let (days, seconds) = div_mod(seconds, 86_400);
+        let (overflow_days, time) = Self::balance(
+            self.hour.into(),
+            self.minute.into(),
+            i64::from(self.second) + seconds,
+            self.millisecond.into(),
+            self.microsecond.into(),
+            self.nanosecond.into(),
+        );
+        (days + overflow_days, time)
+    }
+
+    /// Subtracts `seconds` from this `IsoTime`, returning the overflow days and the
+    /// balanced time.
+    pub fn sub_seconds(&self, seconds: i64) -> (i64, Self) {
+        let (days, time) = self.add_nanoseconds_i128(-i128::from(seconds) * 1_000_000_000);
+        // NOTE: The overflow days for an `i64` of seconds always fit in an `i64`.
+        (days as i64, time)
+    }
+
+    /// Adds `nanoseconds` to this `IsoTime`, returning the overflow days and the
+    /// balanced time.
+    ///
+    /// Unlike [`IsoTime::balance`], the overflow days are an `i128`, as the days in an
+    /// `i128` of nanoseconds, up to about 1.97 × 10²⁴, do not fit in an `i64`.
+    pub fn add_nanoseconds_i128(&self, nanoseconds: i128) -> (i128, Self) {
+        let days = nanoseconds.div_euclid(i128::from(NS_PER_DAY));
+        let nanoseconds = nanoseconds.rem_euclid(i128::from(NS_PER_DAY));
+        let (overflow_days, time) = Self::balance(
+            self.hour.into(),
+            self.minute.into(),
+            self.second.into(),
+            self.millisecond.into(),
+            self.microsecond.into(),
+            i64::from(self.nanosecond) + nanoseconds as i64,
+        );
+        (days + i128::from(overflow_days), time)
+    }
+
+    /// `IsoTimeToEpochMs`
+    ///
+    /// Note: This method is library specific and not in spec
+    ///
+    /// Functionally the same as Date's `MakeTime`
+    pub(crate) fn to_epoch_ms(self) -> i64 {
+        ((i64::from(self.hour) * utils::MS_PER_HOUR
+            + i64::from(self.minute) * utils::MS_PER_MINUTE)
+            + i64::from(self.second) * 1000i64)
+            + i64::from(self.millisecond)
+    }
+}
+
+// ==== `IsoDateTime` specific utility functions ====
+
+const MAX_EPOCH_DAYS: i64 = 10i64.pow(8) + 1;
+
+/// Returns whether the date is within the valid `Temporal` limits.
+///
+/// This is equivalent to `iso_dt_within_valid_limits(date, &IsoTime::noon())`, but as
+/// the limits are a whole number of days, the check is done directly on the epoch days
+/// without converting to epoch nanoseconds.
+#[inline]
+pub(crate) fn iso_date_within_valid_limits(date: IsoDate) -> bool {
+    let epoch_days = utils::epoch_days_from_gregorian_date(date.year, date.month, date.day);
+    (-MAX_EPOCH_DAYS..MAX_EPOCH_DAYS).contains(&epoch_days)
+}
+
+#[inline]
+/// Utility function to determine if a `DateTime`'s components create a `DateTime` within valid limits
+fn iso_dt_within_valid_limits(date: IsoDate, time: &IsoTime) -> bool {
+    if utils::epoch_days_from_gregorian_date(date.year, date.month, date.day).abs() > MAX_EPOCH_DAYS
+    {
+        return false;
+    }
+
+    let ns = to_unchecked_epoch_nanoseconds(date, time);
+    let max = crate::NS_MAX_INSTANT + i128::from(NS_PER_DAY);
+    let min = crate::NS_MIN_INSTANT - i128::from(NS_PER_DAY);
+
+    min < ns && max > ns
+}
+
+#[inline]
+/// Utility function to convert a `IsoDate` and `IsoTime` values into epoch nanoseconds
+fn utc_epoch_nanos(date: IsoDate, time: &IsoTime) -> TemporalResult<EpochNanoseconds> {
+    let epoch_nanos = to_unchecked_epoch_nanoseconds(date, time);
+    EpochNanoseconds::try_from(epoch_nanos)
+}
+
+#[inline]
+fn to_unchecked_epoch_nanoseconds(date: IsoDate, time: &IsoTime) -> i128 {
+    let ms = time.to_epoch_ms();
+    let epoch_ms = utils::epoch_days_to_epoch_ms(date.to_epoch_days() as i64, ms);
+    epoch_ms as i128 * 1_000_000 + time.microsecond as i128 * 1_000 + time.nanosecond as i128
+}
+
+// ==== `IsoDate` specific utiltiy functions ====
+
+/// Returns the Epoch days based off the given year, month, and day.
+/// Note: Month should be 1 indexed
+#[inline]
+pub fn iso_date_epoch_days(year: i32, month: i32, day: i32) -> i64 {
+    // 1. Let resolvedYear be year + floor(month / 12).
+    let resolved_year = year + month.div_euclid(12);
+    // 2. Let resolvedMonth be month modulo 12.
+    let resolved_month = month.rem_euclid(12) as u8;
+    // 3. Find a time t such that EpochTimeToEpochYear(t) is resolvedYear,
+    // EpochTimeToMonthInYear(t) is resolvedMonth, and EpochTimeToDate(t) is 1.
+    let epoch_days = utils::epoch_days_from_gregorian_date(resolved_year, resolved_month, 1);
+
+    // 4. Return EpochTimeToDayNumber(t) + date - 1.
+    epoch_days + day as i64 - 1
+}
+
+/// Floors an `f64` to an `i64`, saturating at the bounds of `i64`.
+#[inline]
+fn floor_to_i64(value: f64) -> i64 {
+    let truncated = value as i64;
+    if (truncated as f64) > value {
+        return truncated.saturating_sub(1);
+    }
+    truncated
+}
+
+/// Converts a parsed calendar annotation value into an owned identifier.
+#[inline]
+fn calendar_identifier(value: &[u8]) -> TemporalResult<String> {
+    core::str::from_utf8(value).map(String::from).map_err(|_| {
+        TemporalError::range().with_message("calendar annotation must be valid UTF-8.")
+    })
+}
+
+const ENGLISH_MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The epoch days of 1899-12-30, the day that Excel serial dates from 1900-03-01 onward count from.
+const EXCEL_EPOCH_DAYS: i32 = -25_569;
+/// The Excel serial of the nonexistent 1900-02-29.
+const EXCEL_LEAP_DAY_SERIAL: i32 = 60;
+/// The Excel serial of 9999-12-31.
+const EXCEL_MAX_SERIAL: u32 = 2_958_465;
+
+/// Returns the ISO day of the week, 1 (Monday) through 7 (Sunday), for the provided epoch days.
+#[inline]
+const fn iso_day_of_week(epoch_days: i64) -> u8 {
+    // NOTE: 1970-01-01 is a Thursday.
+    ((epoch_days + 3).rem_euclid(7) + 1) as u8
+}
+
+fn validate_quarter(quarter: u8) -> TemporalResult<()> {
+    if !(1..=4).contains(&quarter) {
+        return Err(TemporalError::range().with_message("Quarter must be from 1 to 4."));
+    }
+    Ok(())
+}
+
+fn validate_fiscal_year_start_month(month: u8) -> TemporalResult<()> {
+    if !(1..=12).contains(&month) {
+        return Err(
+            TemporalError::range().with_message("Fiscal year start month must be from 1 to 12.")
+        );
+    }
+    Ok(())
+}
+
+/// Returns the number of times `weekday` occurs in the `days` days starting from the
+/// epoch days `first`.
+fn count_weekday_from(first: i64, days: u16, weekday: DayOfWeek) -> u16 {
+    debug_assert!(days >= 7);
+    let offset = (i64::from(weekday as u8) - i64::from(iso_day_of_week(first))).rem_euclid(7);
+    (days - 1 - offset as u16) / 7 + 1
+}
+
+/// Returns the number of weekdays in the half-open range of epoch days `[start, end)`.
+fn weekdays_in_range(start: i64, end: i64) -> i64 {
+    debug_assert!(start <= end);
+    let full_weeks = (end - start) / 7;
+    let remaining = (start + full_weeks * 7..end)
+        .filter(|day| iso_day_of_week(*day) < 6)
+        .count() as i64;
+    full_weeks * 5 + remaining
+}
+
+#[inline]
+// Determines if the month and day are valid for the given year.
+const fn is_valid_date(year: i32, month: u8, day: u8) -> bool {
+    if month == 0 || month > 12 {
+        return false;
+    }
+    is_valid_iso_day(year, month, day)
+}
+
+#[inline]
+/// Returns with the `this` surpasses `other`.
+fn iso_date_surpasses(this: &IsoDate, other: &IsoDate, sign: i8) -> bool {
+    this.cmp(other) as i8 * sign == 1
+}
+
+#[inline]
+pub(crate) fn year_month_within_limits(year: i32, month: u8) -> bool {
+    // 1. If isoDate.[[Year]] < -271821 or isoDate.[[Year]] > 275760, then
+    if !(-271821..=275760).contains(&year) {
+        // a. Return false.
+        return false;
+    // 2. If isoDate.[[Year]] = -271821 and isoDate.[[Month]] < 4, then
+    } else if year == -271821 && month < 4 {
+        // a. Return false.
+        return false;
+    // 3. If isoDate.[[Year]] = 275760 and isoDate.[[Month]] > 9, then
+    } else if year == 275760 && month > 9 {
+        // a. Return false.
+        return false;
+    }
+    // 4. Return true.
+    true
+}
+
+fn balance_iso_year_month_with_clamp(year: i64, month: i64) -> (i32, u8) {
+    // 1. Assert: year and month are integers.
+    // 2. Set year to year + floor((month - 1) / 12).
+    let y = year + (month - 1).div_euclid(12);
+    // 3. Set month to ((month - 1) modulo 12) + 1.
+    let m = (month - 1).rem_euclid(12) + 1;
+    // 4. Return the Record { [[Year]]: year, [[Month]]: month  }.
+    (y.clamp(i32::MIN as i64, i32::MAX as i64) as i32, m as u8)
+}
+
+/// Balances a year and an unbounded 1-based month into a year and a month within `1..=12`.
+///
+/// Months above 12 carry into the following years and months below 1 borrow from
+/// the preceding years, e.g. month 13 of 2024 is month 1 of 2025 and month 0 of
+/// 2024 is month 12 of 2023.
+///
+/// Equivalent: `BalanceISOYearMonth`
+#[inline]
+pub fn balance_iso_year_month(year: i32, month: i32) -> (i32, u8) {
+    // 1. Assert: year and month are integers.
+    // 2. Set year to year + floor((month - 1) / 12).
+    let y = year + (month - 1).div_euclid(12);
+    // 3. Set month to ((month - 1) modulo 12) + 1.
+    let m = (month - 1).rem_euclid(12) + 1;
+    // 4. Return the Record { [[Year]]: year, [[Month]]: month  }.
+    (y, m as u8)
+}
+
+/// Note: month is 1 based.
+#[inline]
+pub(crate) fn constrain_iso_day(year: i32, month: u8, day: u8) -> u8 {
+    let days_in_month = utils::iso_days_in_month(year, month);
+    day.clamp(1, days_in_month)
+}
+
+#[inline]
+pub(crate) const fn is_valid_iso_day(year: i32, month: u8, day: u8) -> bool {
+    let days_in_month = utils::iso_days_in_month(year, month);
+    day != 0 && day <= days_in_month
+}
+
+// ==== `IsoTime` specific utilities ====
+
+#[inline]
+const fn is_valid_time(hour: u8, minute: u8, second: u8, ms: u16, mis: u16, ns: u16) -> bool {
+    if hour > 23 {
+        return false;
+    }
+
+    if minute > 59 || second > 59 {
+        return false;
+    }
+
+    ms <= 999 && mis <= 999 && ns <= 999
+}
+
+/// Balances unbounded time fields into an `IsoTime`, returning the days carried
+/// out of the time along with the balanced `IsoTime`.
+///
+/// Equivalent: `BalanceTime`
+#[inline]
+pub fn balance_iso_time(
+    hour: i64,
+    minute: i64,
+    second: i64,
+    millisecond: i64,
+    microsecond: i64,
+    nanosecond: i64,
+) -> (i64, IsoTime) {
+    IsoTime::balance(hour, minute, second, millisecond, microsecond, nanosecond)
+}
+
+#[inline]
+fn div_mod(dividend: i64, divisor: i64) -> (i64, i64) {
+    (dividend.div_euclid(divisor), dividend.rem_euclid(divisor))
+}
+
+// ==== Range checks ====
+
+/// Whether a bound of a range includes its endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBound {
+    /// The range excludes the endpoint.
+    Open,
+    /// The range includes the endpoint.
+    Closed,
+}
+
+fn is_in_range<T: Ord>(
+    value: &T,
+    start: &T,
+    start_bound: RangeBound,
+    end: &T,
+    end_bound: RangeBound,
+) -> bool {
+    debug_assert!(start <= end, "range start must not be after the range end");
+    if start > end {
+        return false;
+    }
+    let after_start = match start_bound {
+        RangeBound::Open => value > start,
+        RangeBound::Closed => value >= start,
+    };
+    let before_end = match end_bound {
+        RangeBound::Open => value < end,
+        RangeBound::Closed => value <= end,
+    };
+    after_start && before_end
+}
+
+macro_rules! impl_range_methods {
+    ($ty:ident) => {
+        impl $ty {
+            #[doc = concat!("Compares two `", stringify!($ty), "`s, equivalent to the `compare` method of the")]
+            /// corresponding `Temporal` type.
+            #[inline]
+            #[must_use]
+            pub fn compare(one: &$ty, two: &$ty) -> Ordering {
+                one.cmp(two)
+            }
+
+            #[doc = concat!("Returns whether this `", stringify!($ty), "` is within the range from `start` to `end`.")]
+            ///
+            /// # Panics
+            ///
+            /// Panics in debug builds if `start` is after `end`. In release
+            /// builds, `false` is returned instead.
+            pub fn in_range(
+                &self,
+                start: &$ty,
+                start_bound: RangeBound,
+                end: &$ty,
+                end_bound: RangeBound,
+            ) -> bool {
+                is_in_range(self, start, start_bound, end, end_bound)
+            }
+
+            #[doc = concat!("Returns whether this `", stringify!($ty), "` is within `[start, end]`.")]
+            #[inline]
+            pub fn in_closed_range(&self, start: &$ty, end: &$ty) -> bool {
+                self.in_range(start, RangeBound::Closed, end, RangeBound::Closed)
+            }
+
+            #[doc = concat!("Returns a predicate for whether a `", stringify!($ty), "` is within `[start, end]`,")]
+            /// for use with [`Iterator::filter`], e.g.
+            /// `values.iter().filter(Self::closed_range_filter(&start, &end))`.
+            #[inline]
+            pub fn closed_range_filter<'a>(
+                start: &'a $ty,
+                end: &'a $ty,
+            ) -> impl Fn(&&$ty) -> bool + 'a {
+                move |value| value.in_closed_range(start, end)
+            }
+
+            #[doc = concat!("Returns whether this `", stringify!($ty), "` is within `(start, end)`.")]
+            #[inline]
+            pub fn in_open_range(&self, start: &$ty, end: &$ty) -> bool {
+                self.in_range(start, RangeBound::Open, end, RangeBound::Open)
+            }
+
+            #[doc = concat!("Returns whether this `", stringify!($ty), "` is within `[start, end)`.")]
+            #[inline]
+            pub fn in_half_open_range(&self, start: &$ty, end: &$ty) -> bool {
+                self.in_range(start, RangeBound::Closed, end, RangeBound::Open)
+            }
+        }
+    };
+}
+
+impl_range_methods!(IsoDate);
+impl_range_methods!(IsoTime);
+impl_range_methods!(IsoDateTime);
+
+#[cfg(test)]
+mod tests {
+    use super::{iso_date_epoch_days, iso_day_of_week, IsoDate, IsoDateTime, IsoTime};
+    use crate::utils;
+    use crate::{
+        builtins::core::duration::{DateDuration, TimeDuration},
+        options::{ArithmeticOverflow, Unit},
+    };
+
+    const MAX_DAYS_BASE: i64 = 100_000_000;
+
+    #[test]
+    fn icu4x_max_conversion_test() {
+        // Test that the max ISO date does not panic on conversion
+        let _ = IsoDate::new_unchecked(275_760, 9, 13).to_icu4x();
+        // Test that the min ISO date does not panic on conversion
+        let _ = IsoDate::new_unchecked(-271_821, 4, 20).to_icu4x();
+    }
+
+    #[test]
+    fn iso_date_epoch_days_limits() {
+        // Succeeds
+        assert_eq!(iso_date_epoch_days(-271_821, 4, 20).abs(), MAX_DAYS_BASE);
+        // Succeeds
+        assert_eq!(
+            iso_date_epoch_days(-271_821, 4, 19).abs(),
+            MAX_DAYS_BASE + 1
+        );
+        // Fails
+        assert_eq!(
+            iso_date_epoch_days(-271_821, 4, 18).abs(),
+            MAX_DAYS_BASE + 2
+        );
+        // Succeeds
+        assert_eq!(iso_date_epoch_days(275_760, 9, 13).abs(), MAX_DAYS_BASE);
+        // Succeeds
+        assert_eq!(iso_date_epoch_days(275_760, 9, 14).abs(), MAX_DAYS_BASE + 1);
+        // Fails
+        assert_eq!(iso_date_epoch_days(275_760, 9, 15).abs(), MAX_DAYS_BASE + 2);
+    }
+
+    #[test]
+    fn epoch_days_round_trip_limits() {
+        for epoch_days in [
+            -100_000_001,
+            -100_000_000,
+            -99_999_999,
+            0,
+            99_999_999,
+            100_000_000,
+            100_000_001,
+        ] {
+            let date = IsoDate::from_epoch_days_unchecked(epoch_days);
+            assert_eq!(date.to_epoch_days(), epoch_days);
+        }
+
+        assert_eq!(
+            IsoDate::from_epoch_days_unchecked(100_000_000),
+            IsoDate::new_unchecked(275_760, 9, 13)
+        );
+        assert_eq!(
+            IsoDate::from_epoch_days_unchecked(-100_000_000),
+            IsoDate::new_unchecked(-271_821, 4, 20)
+        );
+    }
+
+    #[test]
+    fn epoch_days_for_year_past_i32_seconds() {
+        // The epoch seconds for these years exceed `i32::MAX`.
+        assert_eq!(utils::epoch_days_for_year(2040) * 86_400, 2_208_988_800);
+        assert_eq!(
+            utils::epoch_days_for_year(275_760),
+            iso_date_epoch_days(275_760, 1, 1)
+        );
+        assert_eq!(
+            utils::epoch_days_for_year(-271_821),
+            iso_date_epoch_days(-271_821, 1, 1)
+        );
+    }
+
+    #[test]
+    fn balance_time_large_nanoseconds() {
+        // One nanosecond past midnight of the following day.
+        let result = IsoTime::balance(0, 0, 0, 0, 0, 86_400_000_000_001);
+        assert_eq!(result, (1, IsoTime::new_unchecked(0, 0, 0, 0, 0, 1)));
+
+        let result = IsoTime::balance(0, 0, 0, 0, 0, -1);
+        assert_eq!(
+            result,
+            (-1, IsoTime::new_unchecked(23, 59, 59, 999, 999, 999))
+        );
+
+        // 1e18 nanoseconds is 11_574 days, 1 hour, 46 minutes, and 40 seconds.
+        let result = IsoTime::balance(0, 0, 0, 0, 0, 1_000_000_000_000_000_000);
+        assert_eq!(result, (11_574, IsoTime::new_unchecked(1, 46, 40, 0, 0, 0)));
+    }
+
+    #[test]
+    fn diff_iso_date_days_and_weeks() {
+        let one = IsoDate::new_unchecked(2015, 3, 14);
+        let two = IsoDate::new_unchecked(2025, 3, 14);
+
+        let result = one.diff_iso_date(&two, Unit::Day).unwrap();
+        assert_eq!(result, DateDuration::new(0, 0, 0, 3653).unwrap());
+        let result = two.diff_iso_date(&one, Unit::Day).unwrap();
+        assert_eq!(result, DateDuration::new(0, 0, 0, -3653).unwrap());
+
+        let result = one.diff_iso_date(&two, Unit::Week).unwrap();
+        assert_eq!(result, DateDuration::new(0, 0, 521, 6).unwrap());
+        let result = two.diff_iso_date(&one, Unit::Week).unwrap();
+        assert_eq!(result, DateDuration::new(0, 0, -521, -6).unwrap());
+
+        let result = one.diff_iso_date(&one, Unit::Day).unwrap();
+        assert_eq!(result, DateDuration::default());
+    }
+
+    #[test]
+    fn year_zero() {
+        // Year 0 is a leap year, as it is divisible by 400.
+        assert!(IsoDate::new_unchecked(0, 2, 29).is_valid());
+        assert!(!IsoDate::new_unchecked(0, 2, 30).is_valid());
+        assert!(!IsoDate::new_unchecked(-1, 2, 29).is_valid());
+        assert_eq!(utils::iso_days_in_month(0, 2), 29);
+
+        let date = IsoDate::new_unchecked(0, 1, 1);
+        assert!(date.year_is_bce());
+        assert_eq!(date.to_historical_year(), (1, "BCE"));
+        let date = IsoDate::new_unchecked(-1, 1, 1);
+        assert!(date.year_is_bce());
+        assert_eq!(date.to_historical_year(), (2, "BCE"));
+        let date = IsoDate::new_unchecked(1, 1, 1);
+        assert!(!date.year_is_bce());
+        assert_eq!(date.to_historical_year(), (1, "CE"));
+
+        assert_eq!(
+            IsoDate::new_unchecked(0, 1, 1).to_string_with_era(),
+            "1 BCE"
+        );
+        assert_eq!(IsoDate::new_unchecked(1, 1, 1).to_string_with_era(), "1 CE");
+        assert_eq!(
+            IsoDate::new_unchecked(-43, 3, 15).to_string_with_era(),
+            "44 BCE"
+        );
+        assert_eq!(
+            IsoDate::new_unchecked(1066, 10, 14).year_in_era(),
+            (1066, "CE")
+        );
+        assert_eq!(
+            IsoDate::from_era_year(1, "BCE"),
+            Ok(IsoDate::new_unchecked(0, 1, 1))
+        );
+        assert_eq!(
+            IsoDate::from_era_year(2, "bc"),
+            Ok(IsoDate::new_unchecked(-1, 1, 1))
+        );
+        assert_eq!(
+            IsoDate::from_era_year(1066, "AD"),
+            Ok(IsoDate::new_unchecked(1066, 1, 1))
+        );
+        assert!(IsoDate::from_era_year(0, "CE").is_err());
+        assert!(IsoDate::from_era_year(1, "BCEE").is_err());
+        assert!(IsoDate::from_era_year(300_000, "CE").is_err());
+        assert!(IsoDate::from_era_year(u32::MAX, "BCE").is_err());
+
+        // 0000-12-31 is the day before 0001-01-01
+        assert_eq!(
+            iso_date_epoch_days(1, 1, 1) - iso_date_epoch_days(0, 12, 31),
+            1
+        );
+        assert_eq!(
+            iso_date_epoch_days(1, 1, 1) - iso_date_epoch_days(0, 1, 1),
+            366
+        );
+    }
+
+    #[test]
+    fn day_predicates() {
+        let date = IsoDate::new_unchecked(2025, 1, 1);
+        assert!(date.is_first_day_of_month());
+        assert!(date.is_first_day_of_year());
+        assert!(date.is_first_day_of_quarter());
+        assert!(!date.is_last_day_of_month());
+        assert!(!date.is_last_day_of_year());
+        assert!(!date.is_last_day_of_quarter());
+        assert!(!date.is_leap_day());
+
+        let date = IsoDate::new_unchecked(2025, 1, 2);
+        assert!(!date.is_first_day_of_month());
+        assert!(!date.is_first_day_of_year());
+        assert!(!date.is_first_day_of_quarter());
+
+        let date = IsoDate::new_unchecked(2025, 12, 31);
+        assert!(date.is_last_day_of_month());
+        assert!(date.is_last_day_of_year());
+        assert!(date.is_last_day_of_quarter());
+        assert!(!date.is_first_day_of_year());
+
+        let date = IsoDate::new_unchecked(2025, 12, 30);
+        assert!(!date.is_last_day_of_month());
+        assert!(!date.is_last_day_of_year());
+        assert!(!date.is_last_day_of_quarter());
+
+        // Feb 28 in a non-leap year
+        let date = IsoDate::new_unchecked(2025, 2, 28);
+        assert!(date.is_last_day_of_month());
+        assert!(!date.is_leap_day());
+        assert!(!date.is_last_day_of_quarter());
+
+        // Feb 28 in a leap year
+        let date = IsoDate::new_unchecked(2024, 2, 28);
+        assert!(!date.is_last_day_of_month());
+        assert!(!date.is_leap_day());
+
+        let date = IsoDate::new_unchecked(2024, 2, 29);
+        assert!(date.is_last_day_of_month());
+        assert!(date.is_leap_day());
+
+        // Quarter boundaries
+        let date = IsoDate::new_unchecked(2025, 4, 1);
+        assert!(date.is_first_day_of_quarter());
+        assert!(!date.is_first_day_of_year());
+        assert_eq!(date.end_of_quarter(), IsoDate::new_unchecked(2025, 6, 30));
+        let date = IsoDate::new_unchecked(2025, 9, 30);
+        assert!(date.is_last_day_of_quarter());
+        assert_eq!(date.start_of_quarter(), IsoDate::new_unchecked(2025, 7, 1));
+        let date = IsoDate::new_unchecked(2025, 5, 1);
+        assert!(date.is_first_day_of_month());
+        assert!(!date.is_first_day_of_quarter());
+    }
+
+    #[test]
+    fn unix_timestamps() {
+        let epoch = IsoDateTime::from_unix_timestamp_secs(0).unwrap();
+        assert_eq!(epoch.date, IsoDate::new_unchecked(1970, 1, 1));
+        assert_eq!(epoch.time, IsoTime::default());
+        assert_eq!(epoch.to_unix_timestamp_secs().unwrap(), 0);
+        assert_eq!(epoch.to_unix_timestamp_millis().unwrap(), 0);
+        assert_eq!(epoch.to_unix_timestamp_nanos().unwrap(), 0);
+
+        // 9999-12-31T23:59:59Z
+        let dt = IsoDateTime::from_unix_timestamp_secs(253_402_300_799).unwrap();
+        assert_eq!(dt.date, IsoDate::new_unchecked(9999, 12, 31));
+        assert_eq!(dt.time, IsoTime::new_unchecked(23, 59, 59, 0, 0, 0));
+        assert_eq!(dt.to_unix_timestamp_secs().unwrap(), 253_402_300_799);
+
+        // 1965-01-01T00:00:00.5Z
+        let dt = IsoDateTime::from_unix_timestamp_millis(-157_766_399_500).unwrap();
+        assert_eq!(dt.date, IsoDate::new_unchecked(1965, 1, 1));
+        assert_eq!(dt.time, IsoTime::new_unchecked(0, 0, 0, 500, 0, 0));
+        assert_eq!(dt.to_unix_timestamp_secs().unwrap(), -157_766_400);
+        assert_eq!(dt.to_unix_timestamp_millis().unwrap(), -157_766_399_500);
+
+        let dt = IsoDateTime::from_unix_timestamp_nanos(-1).unwrap();
+        assert_eq!(dt.date, IsoDate::new_unchecked(1969, 12, 31));
+        assert_eq!(dt.time, IsoTime::new_unchecked(23, 59, 59, 999, 999, 999));
+        assert_eq!(dt.to_unix_timestamp_secs().unwrap(), -1);
+        assert_eq!(dt.to_unix_timestamp_nanos().unwrap(), -1);
+
+        // The limits of the valid epoch range
+        let max_millis = 8_640_000_000_000_000;
+        assert!(IsoDateTime::from_unix_timestamp_millis(max_millis).is_ok());
+        assert!(IsoDateTime::from_unix_timestamp_millis(max_millis + 1).is_err());
+        assert!(IsoDateTime::from_unix_timestamp_millis(-max_millis).is_ok());
+        assert!(IsoDateTime::from_unix_timestamp_millis(-max_millis - 1).is_err());
+        assert!(IsoDateTime::from_unix_timestamp_millis(i64::MAX).is_err());
+        assert!(IsoDateTime::from_unix_timestamp_millis(i64::MIN).is_err());
+        assert!(IsoDateTime::from_unix_timestamp_secs(i64::MAX).is_err());
+    }
+
+    #[cfg(feature = "js")]
+    #[test]
+    fn js_timestamps() {
+        let epoch = IsoDateTime::from_js_timestamp(0.0).unwrap();
+        assert_eq!(epoch.date, IsoDate::new_unchecked(1970, 1, 1));
+        assert_eq!(epoch.time, IsoTime::default());
+        assert_eq!(epoch.to_js_timestamp(), Ok(0.0));
+
+        let dt = IsoDateTime::from_js_timestamp(-157_766_399_500.0).unwrap();
+        assert_eq!(dt.date, IsoDate::new_unchecked(1965, 1, 1));
+        assert_eq!(dt.time, IsoTime::new_unchecked(0, 0, 0, 500, 0, 0));
+        assert_eq!(dt.to_js_timestamp(), Ok(-157_766_399_500.0));
+
+        let dt = IsoDateTime::from_js_timestamp(1.9).unwrap();
+        assert_eq!(dt.time, IsoTime::new_unchecked(0, 0, 0, 1, 0, 0));
+
+        assert!(IsoDateTime::from_js_timestamp(8.64e15).is_ok());
+        assert!(IsoDateTime::from_js_timestamp(-8.64e15).is_ok());
+        assert!(IsoDateTime::from_js_timestamp(8.64e15 + 1.0).is_err());
+        assert!(IsoDateTime::from_js_timestamp(f64::NAN).is_err());
+        assert!(IsoDateTime::from_js_timestamp(f64::INFINITY).is_err());
+        assert!(IsoDateTime::from_js_timestamp(f64::NEG_INFINITY).is_err());
+
+        for month in 0..12 {
+            let date = IsoDate::from_js_date(2024, month, 1).unwrap();
+            assert_eq!(date, IsoDate::new_unchecked(2024, month + 1, 1));
+        }
+        assert!(IsoDate::from_js_date(2024, 12, 1).is_err());
+        assert!(IsoDate::from_js_date(2024, u8::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn time_diff_in_units() {
+        assert_eq!(
+            IsoTime::MIDNIGHT.diff_in_nanoseconds(&IsoTime::NOON),
+            43_200_000_000_000i64
+        );
+        assert_eq!(
+            IsoTime::NOON.diff_in_nanoseconds(&IsoTime::MIDNIGHT),
+            -43_200_000_000_000i64
+        );
+
+        let one = IsoTime::new_unchecked(10, 0, 0, 0, 0, 0);
+        let two = IsoTime::new_unchecked(10, 0, 1, 999, 999, 999);
+        assert_eq!(one.diff_in_nanoseconds(&two), 1_999_999_999);
+        assert_eq!(one.diff_in_microseconds(&two), 1_999_999);
+        assert_eq!(one.diff_in_milliseconds(&two), 1_999);
+        assert_eq!(one.diff_in_seconds(&two), 1);
+        // Truncation is toward zero for negative differences.
+        assert_eq!(two.diff_in_seconds(&one), -1);
+        assert_eq!(two.diff_in_milliseconds(&one), -1_999);
+
+        let end_of_day = IsoTime::new_unchecked(23, 59, 59, 999, 999, 999);
+        assert_eq!(
+            IsoTime::MIDNIGHT.diff_in_nanoseconds(&end_of_day),
+            86_399_999_999_999
+        );
+    }
+
+    #[test]
+    fn iso_weeks_in_year() {
+        const LONG_YEARS: [i32; 5] = [2004, 2009, 2015, 2020, 2026];
+        for year in 2000..=2030 {
+            let expected = LONG_YEARS.contains(&year);
+            assert_eq!(IsoDate::is_long_year(year), expected, "year: {year}");
+            let weeks = if expected { 53 } else { 52 };
+            assert_eq!(IsoDate::weeks_in_year(year), weeks, "year: {year}");
+        }
+
+        assert_eq!(
+            IsoDate::iso_week_year_start(2020),
+            IsoDate::new_unchecked(2019, 12, 30)
+        );
+        assert_eq!(
+            IsoDate::iso_week_year_start(2021),
+            IsoDate::new_unchecked(2021, 1, 4)
+        );
+        assert_eq!(
+            IsoDate::iso_week_year_start(2026),
+            IsoDate::new_unchecked(2025, 12, 29)
+        );
+        assert_eq!(
+            IsoDate::iso_week_year_start(2027),
+            IsoDate::new_unchecked(2027, 1, 4)
+        );
+    }
+
+    #[test]
+    fn gregorian_constructors() {
+        assert!(IsoDate::from_ymd(2024, 2, 30).is_err());
+        let date = IsoDate::from_ymd(2024, 2, 29).unwrap();
+        assert_eq!(date, IsoDate::new_unchecked(2024, 2, 29));
+        assert_eq!(date.to_gregorian_year_month_day(), (2024, 2, 29));
+        assert_eq!(
+            date.to_gregorian_year_month_day(),
+            (date.year, date.month, date.day)
+        );
+
+        let date = IsoDate::from_gregorian(2023, 2, 30, ArithmeticOverflow::Constrain).unwrap();
+        assert_eq!(date.to_gregorian_year_month_day(), (2023, 2, 28));
+        let date = IsoDate::from_gregorian(2023, 13, 300, ArithmeticOverflow::Constrain).unwrap();
+        assert_eq!(date.to_gregorian_year_month_day(), (2023, 12, 31));
+        assert!(IsoDate::from_gregorian(2023, 2, 30, ArithmeticOverflow::Reject).is_err());
+        assert!(IsoDate::from_gregorian(2023, 257, 1, ArithmeticOverflow::Reject).is_err());
+        assert!(IsoDate::from_gregorian(2023, 1, -1, ArithmeticOverflow::Reject).is_err());
+    }
+
+    #[test]
+    fn business_days() {
+        // 2024-03-11 is a Monday and 2024-03-17 is a Sunday.
+        let date = |day| IsoDate::new_unchecked(2024, 3, day);
+        let next = [
+            (11, 12),
+            (12, 13),
+            (13, 14),
+            (14, 15),
+            (15, 18),
+            (16, 18),
+            (17, 18),
+        ];
+        for (day, expected) in next {
+            assert_eq!(date(day).next_business_day(), Ok(date(expected)));
+        }
+        let previous = [(11, 8), (12, 11), (15, 14), (16, 15), (17, 15), (18, 15)];
+        for (day, expected) in previous {
+            assert_eq!(date(day).previous_business_day(), Ok(date(expected)));
+        }
+
+        assert_eq!(date(13).add_business_days(0), Ok(date(13)));
+        assert_eq!(date(13).add_business_days(5), Ok(date(20)));
+        assert_eq!(date(13).add_business_days(7), Ok(date(22)));
+        assert_eq!(date(16).add_business_days(5), Ok(date(22)));
+        assert_eq!(date(13).add_business_days(-3), Ok(date(8)));
+        assert_eq!(date(17).add_business_days(-1), Ok(date(15)));
+
+        // Counting across multiple weekends.
+        assert_eq!(date(1).business_days_until(&date(18)), 11);
+        assert_eq!(date(1).add_business_days(11), Ok(date(18)));
+        assert_eq!(date(13).business_days_until(&date(8)), -3);
+        assert_eq!(date(16).business_days_until(&date(17)), 0);
+        assert_eq!(date(15).business_days_until(&date(15)), 0);
+
+        // Results outside of the `Temporal` limits are rejected rather than overflowing.
+        let max = IsoDate::TEMPORAL_MAX;
+        let min = IsoDate::TEMPORAL_MIN;
+        assert!(max.next_business_day().is_err());
+        assert!(min.previous_business_day().is_err());
+        assert!(date(13).add_business_days(i32::MAX).is_err());
+        assert!(date(13).add_business_days(i32::MIN).is_err());
+        assert!(max.add_business_days(-1).is_ok());
+        assert!(min.add_business_days(1).is_ok());
+    }
+
+    #[test]
+    fn lenient_date_parsing() {
+        use crate::{error::ErrorKind, parsers::ParseHint};
+
+        let expected = IsoDate::new_unchecked(2024, 3, 15);
+        for month_before_day in [true, false] {
+            let hint = ParseHint { month_before_day };
+            for source in [
+                "2024-03-15",
+                "20240315",
+                "2024-3-15",
+                "15.03.2024",
+                "March 15, 2024",
+                "mar 15 2024",
+                "15 March 2024",
+                " 15 MAR 2024 ",
+            ] {
+                assert_eq!(IsoDate::parse_lenient(source, hint), Ok(expected));
+            }
+            assert_eq!(IsoDate::parse_lenient("+002024-03-15", hint), Ok(expected));
+            assert_eq!(
+                IsoDate::parse_lenient("-000001-03-15", hint),
+                Ok(IsoDate::new_unchecked(-1, 3, 15))
+            );
+        }
+
+        // Slash-separated dates depend on the hint.
+        let us = ParseHint {
+            month_before_day: true,
+        };
+        let eu = ParseHint::default();
+        assert_eq!(IsoDate::parse_lenient("03/15/2024", us), Ok(expected));
+        assert_eq!(IsoDate::parse_lenient("15/03/2024", eu), Ok(expected));
+        assert_eq!(
+            IsoDate::parse_lenient("03/04/2024", us),
+            Ok(IsoDate::new_unchecked(2024, 3, 4))
+        );
+        assert_eq!(
+            IsoDate::parse_lenient("03/04/2024", eu),
+            Ok(IsoDate::new_unchecked(2024, 4, 3))
+        );
+
+        // Invalid months and days are range errors.
+        for source in [
+            "2024-13-01",
+            "15/13/2024",
+            "30.02.2024",
+            "February 30, 2024",
+        ] {
+            let err = IsoDate::parse_lenient(source, eu).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Range);
+        }
+        let err = IsoDate::parse_lenient("275761-01-01", eu).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Range);
+
+        // Unrecognized formats are syntax errors.
+        for source in [
+            "",
+            "2024",
+            "15 Marchember 2024",
+            "2024/03",
+            "15-03-2024",
+            "next tuesday",
+        ] {
+            let err = IsoDate::parse_lenient(source, eu).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax);
+        }
+    }
+
+    #[test]
+    fn range_checks() {
+        use super::RangeBound::{Closed, Open};
+
+        let start = IsoDate::new_unchecked(2024, 3, 1);
+        let end = IsoDate::new_unchecked(2024, 3, 31);
+        let middle = IsoDate::new_unchecked(2024, 3, 15);
+        for (start_bound, end_bound) in [
+            (Open, Open),
+            (Open, Closed),
+            (Closed, Open),
+            (Closed, Closed),
+        ] {
+            assert!(middle.in_range(&start, start_bound, &end, end_bound));
+            assert_eq!(
+                start.in_range(&start, start_bound, &end, end_bound),
+                start_bound == Closed
+            );
+            assert_eq!(
+                end.in_range(&start, start_bound, &end, end_bound),
+                end_bound == Closed
+            );
+            // A range where start equals end only contains that value if both bounds are closed.
+            assert_eq!(
+                middle.in_range(&middle, start_bound, &middle, end_bound),
+                start_bound == Closed && end_bound == Closed
+            );
+        }
+        assert!(!IsoDate::new_unchecked(2024, 2, 29).in_closed_range(&start, &end));
+        assert!(!IsoDate::new_unchecked(2024, 4, 1).in_closed_range(&start, &end));
+        assert!(start.in_half_open_range(&start, &end));
+        assert!(!end.in_half_open_range(&start, &end));
+        assert!(!start.in_open_range(&start, &end));
+
+        assert!(IsoTime::NOON.in_closed_range(&IsoTime::MIDNIGHT, &IsoTime::NOON));
+        assert!(!IsoTime::NOON.in_half_open_range(&IsoTime::MIDNIGHT, &IsoTime::NOON));
+        assert!(IsoTime::MIDNIGHT.in_half_open_range(&IsoTime::MIDNIGHT, &IsoTime::NOON));
+
+        let at = |date, time| IsoDateTime::new_unchecked(date, time);
+        assert!(at(start, IsoTime::NOON)
+            .in_open_range(&at(start, IsoTime::MIDNIGHT), &at(end, IsoTime::MIDNIGHT)));
+        assert!(!at(end, IsoTime::NOON)
+            .in_closed_range(&at(start, IsoTime::MIDNIGHT), &at(end, IsoTime::MIDNIGHT)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn range_check_with_reversed_bounds() {
+        let start = IsoDate::new_unchecked(2024, 3, 31);
+        let end = IsoDate::new_unchecked(2024, 3, 1);
+        IsoDate::new_unchecked(2024, 3, 15).in_closed_range(&start, &end);
+    }
+
+    #[test]
+    fn until_and_since_are_inverse() {
+        let dates = [
+            IsoDate::new_unchecked(2020, 2, 29),
+            IsoDate::new_unchecked(2024, 3, 31),
+            IsoDate::new_unchecked(1969, 12, 31),
+        ];
+        let times = [
+            IsoTime::MIDNIGHT,
+            IsoTime::new_unchecked(13, 45, 10, 123, 456, 789),
+            IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+        ];
+        let date_units = [Unit::Auto, Unit::Year, Unit::Month, Unit::Week, Unit::Day];
+        let time_units = [
+            Unit::Auto,
+            Unit::Hour,
+            Unit::Minute,
+            Unit::Second,
+            Unit::Millisecond,
+            Unit::Microsecond,
+            Unit::Nanosecond,
+        ];
+
+        for (one, two) in dates.iter().flat_map(|a| dates.iter().map(move |b| (a, b))) {
+            for unit in date_units {
+                assert_eq!(one.until(two, unit), two.since(one, unit));
+            }
+            assert_eq!(
+                one.since(two, Unit::Day).unwrap(),
+                one.until(two, Unit::Day).unwrap().negated()
+            );
+            assert!(one.until(two, Unit::Hour).is_err());
+        }
+
+        for (one, two) in times.iter().flat_map(|a| times.iter().map(move |b| (a, b))) {
+            for unit in time_units {
+                assert_eq!(one.until(two, unit), two.since(one, unit));
+            }
+            assert!(one.until(two, Unit::Day).is_err());
+        }
+
+        let datetimes = [
+            IsoDateTime::new_unchecked(dates[0], times[1]),
+            IsoDateTime::new_unchecked(dates[1], times[0]),
+            IsoDateTime::new_unchecked(dates[2], times[2]),
+        ];
+        for (one, two) in datetimes
+            .iter()
+            .flat_map(|a| datetimes.iter().map(move |b| (a, b)))
+        {
+            for unit in date_units.iter().chain(time_units.iter()) {
+                assert_eq!(one.until(two, *unit), two.since(one, *unit));
+            }
+        }
+
+        let duration = IsoDate::new_unchecked(2020, 2, 29)
+            .until(&IsoDate::new_unchecked(2024, 3, 31), Unit::Auto)
+            .unwrap();
+        assert_eq!(duration, DateDuration::new(0, 0, 0, 1492).unwrap());
+    }
+
+    #[test]
+    fn iso_strings() {
+        use crate::{parsers::Precision, UtcOffset};
+        use core::str::FromStr;
+
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(date.to_iso_string(), "2024-03-15");
+        assert_eq!(date.to_iso_string_with_calendar("iso8601"), "2024-03-15");
+        assert_eq!(
+            date.to_iso_string_with_calendar("hebrew"),
+            "2024-03-15[u-ca=hebrew]"
+        );
+
+        let time = IsoTime::new_unchecked(12, 30, 5, 120, 0, 0);
+        assert_eq!(
+            time.to_iso_string_with_precision(Precision::Auto),
+            "12:30:05.12"
+        );
+        assert_eq!(
+            time.to_iso_string_with_precision(Precision::Minute),
+            "12:30"
+        );
+        assert_eq!(
+            time.to_iso_string_with_precision(Precision::Digit(0)),
+            "12:30:05"
+        );
+        assert_eq!(
+            time.to_iso_string_with_precision(Precision::Digit(9)),
+            "12:30:05.120000000"
+        );
+        assert_eq!(
+            IsoTime::MIDNIGHT.to_iso_string_with_precision(Precision::Auto),
+            "00:00:00"
+        );
+        assert_eq!(
+            IsoTime::MIDNIGHT.to_iso_string_with_precision(Precision::Digit(9)),
+            "00:00:00.000000000"
+        );
+
+        let datetime = IsoDateTime::new_unchecked(date, time);
+        assert_eq!(
+            datetime.to_iso_string_with_calendar("iso8601", None),
+            "2024-03-15T12:30:05.12"
+        );
+        assert_eq!(
+            datetime.to_iso_string_with_calendar(
+                "gregory",
+                Some(UtcOffset::from_str("-05:30").unwrap())
+            ),
+            "2024-03-15T12:30:05.12-05:30[u-ca=gregory]"
+        );
+    }
+
+    #[test]
+    fn parse_annotations() {
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(
+            IsoDate::parse_with_calendar_annotation("2024-03-15"),
+            Ok((date, None))
+        );
+        assert_eq!(
+            IsoDate::parse_with_calendar_annotation("2024-03-15[u-ca=persian]"),
+            Ok((date, Some("persian".into())))
+        );
+        assert_eq!(
+            IsoDate::parse_with_calendar_annotation("2024-03-15T10:00[!u-ca=persian]"),
+            Ok((date, Some("persian".into())))
+        );
+        // Of multiple calendar annotations, the first is used unless any are critical.
+        assert_eq!(
+            IsoDate::parse_with_calendar_annotation("2024-03-15[u-ca=persian][u-ca=hebrew]"),
+            Ok((date, Some("persian".into())))
+        );
+        assert!(
+            IsoDate::parse_with_calendar_annotation("2024-03-15[u-ca=persian][!u-ca=hebrew]")
+                .is_err()
+        );
+        assert!(IsoDate::parse_with_calendar_annotation("2024-02-30").is_err());
+
+        let parsed = IsoDateTime::parse_with_annotations("2024-03-15T12:30").unwrap();
+        assert_eq!(
+            parsed.datetime,
+            IsoDateTime::new_unchecked(date, IsoTime::new_unchecked(12, 30, 0, 0, 0, 0))
+        );
+        assert_eq!(parsed.offset_seconds, None);
+        assert_eq!(parsed.time_zone, None);
+        assert_eq!(parsed.calendar, None);
+
+        let parsed = IsoDateTime::parse_with_annotations("2024-03-15T12:30[u-ca=persian]").unwrap();
+        assert_eq!(parsed.time_zone, None);
+        assert_eq!(parsed.calendar.as_deref(), Some("persian"));
+
+        let parsed = IsoDateTime::parse_with_annotations(
+            "2024-03-15T12:30:00-05:30[America/New_York][u-ca=gregory]",
+        )
+        .unwrap();
+        assert_eq!(parsed.offset_seconds, Some(-19_800));
+        assert_eq!(parsed.time_zone.as_deref(), Some("America/New_York"));
+        assert_eq!(parsed.calendar.as_deref(), Some("gregory"));
+
+        let parsed = IsoDateTime::parse_with_annotations("2024-03-15T12:30Z[+01:00]").unwrap();
+        assert_eq!(parsed.offset_seconds, Some(0));
+        assert_eq!(parsed.time_zone.as_deref(), Some("+01:00"));
+    }
+
+    #[test]
+    fn sub_second_nanoseconds() {
+        let time = IsoTime::new_unchecked(12, 30, 5, 123, 456, 789);
+        assert_eq!(time.total_sub_second_nanoseconds(), 123_456_789);
+        assert_eq!(
+            time.with_sub_second_nanoseconds(time.total_sub_second_nanoseconds()),
+            Ok(time)
+        );
+        assert_eq!(
+            time.with_sub_second_nanoseconds(999_999_999),
+            Ok(IsoTime::new_unchecked(12, 30, 5, 999, 999, 999))
+        );
+        assert_eq!(
+            time.with_sub_second_nanoseconds(0),
+            Ok(time.truncate_sub_seconds())
+        );
+        assert_eq!(
+            time.truncate_sub_seconds(),
+            IsoTime::new_unchecked(12, 30, 5, 0, 0, 0)
+        );
+        assert!(time.with_sub_second_nanoseconds(1_000_000_000).is_err());
+        assert!(time.with_sub_second_nanoseconds(u32::MAX).is_err());
+    }
+
+    #[test]
+    fn from_ywd() {
+        use super::DayOfWeek;
+
+        assert_eq!(
+            IsoDate::from_ywd(2015, 1, DayOfWeek::Monday),
+            Ok(IsoDate::new_unchecked(2014, 12, 29))
+        );
+        assert_eq!(
+            IsoDate::from_ywd(2016, 52, DayOfWeek::Friday),
+            Ok(IsoDate::new_unchecked(2016, 12, 30))
+        );
+        assert_eq!(
+            IsoDate::from_ywd(2015, 53, DayOfWeek::Friday),
+            Ok(IsoDate::new_unchecked(2016, 1, 1))
+        );
+        // 2016 only has 52 ISO weeks.
+        assert!(IsoDate::from_ywd(2016, 53, DayOfWeek::Friday).is_err());
+        assert_eq!(
+            IsoDate::from_ywd(2020, 53, DayOfWeek::Sunday),
+            Ok(IsoDate::new_unchecked(2021, 1, 3))
+        );
+        assert_eq!(
+            IsoDate::from_ywd(2024, 11, DayOfWeek::Friday),
+            Ok(IsoDate::new_unchecked(2024, 3, 15))
+        );
+        assert!(IsoDate::from_ywd(2024, 0, DayOfWeek::Monday).is_err());
+        assert!(IsoDate::from_ywd(2024, 53, DayOfWeek::Monday).is_err());
+        assert!(IsoDate::from_ywd(275_760, 52, DayOfWeek::Monday).is_err());
+    }
+
+    #[test]
+    fn datetime_diff_with_settings() {
+        use crate::{
+            options::{DifferenceSettings, RoundingMode},
+            Duration,
+        };
+
+        let start = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 3, 15),
+            IsoTime::new_unchecked(10, 0, 0, 0, 0, 0),
+        );
+        let end = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 3, 16),
+            IsoTime::new_unchecked(11, 0, 0, 0, 0, 0),
+        );
+        let result = start
+            .diff_with_settings(&end, DifferenceSettings::default())
+            .unwrap();
+        assert_eq!(result, Duration::new(0, 0, 0, 1, 1, 0, 0, 0, 0, 0).unwrap());
+        let result = end
+            .diff_with_settings(&start, DifferenceSettings::default())
+            .unwrap();
+        assert_eq!(
+            result,
+            Duration::new(0, 0, 0, -1, -1, 0, 0, 0, 0, 0).unwrap()
+        );
+
+        let end = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 3, 16),
+            IsoTime::new_unchecked(11, 29, 59, 500, 0, 0),
+        );
+        let settings = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Hour)
+            .build();
+        let result = start.diff_with_settings(&end, settings).unwrap();
+        assert_eq!(result, Duration::new(0, 0, 0, 1, 1, 0, 0, 0, 0, 0).unwrap());
+
+        let settings = DifferenceSettings::builder()
+            .with_largest_unit(Unit::Hour)
+            .with_smallest_unit(Unit::Minute)
+            .with_rounding_mode(RoundingMode::HalfExpand)
+            .build();
+        let result = start.diff_with_settings(&end, settings).unwrap();
+        assert_eq!(
+            result,
+            Duration::new(0, 0, 0, 0, 25, 30, 0, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn month_and_year_iteration() {
+        let mut months = 0;
+        IsoDate::for_each_month_in_year(2024, |month, date| {
+            months += 1;
+            assert_eq!(date, IsoDate::new_unchecked(2024, month, 1));
+        });
+        assert_eq!(months, 12);
+
+        let start = IsoDate::new_unchecked(2024, 1, 15);
+        let end = IsoDate::new_unchecked(2025, 2, 10);
+        let mut range = IsoDate::months_in_range(&start, &end);
+        assert_eq!(range.next(), Some(IsoDate::new_unchecked(2024, 1, 1)));
+        assert_eq!(range.last(), Some(IsoDate::new_unchecked(2025, 2, 1)));
+        assert_eq!(IsoDate::months_in_range(&start, &end).count(), 14);
+        assert_eq!(IsoDate::months_in_range(&end, &start).count(), 0);
+
+        let start = IsoDate::new_unchecked(-1, 12, 31);
+        let end = IsoDate::new_unchecked(0, 1, 1);
+        let mut range = IsoDate::months_in_range(&start, &end);
+        assert_eq!(range.next(), Some(IsoDate::new_unchecked(-1, 12, 1)));
+        assert_eq!(range.next(), Some(IsoDate::new_unchecked(0, 1, 1)));
+        assert_eq!(range.next(), None);
+
+        let years = IsoDate::years_in_range(&start, &IsoDate::new_unchecked(2, 6, 1));
+        assert!(years.eq([-1, 0, 1, 2]));
+    }
+
+    #[test]
+    fn quarter_days() {
+        assert_eq!(IsoDate::new_unchecked(2024, 2, 10).days_in_quarter(), 91);
+        assert_eq!(IsoDate::new_unchecked(2023, 2, 10).days_in_quarter(), 90);
+        assert_eq!(IsoDate::new_unchecked(2023, 5, 10).days_in_quarter(), 91);
+        assert_eq!(IsoDate::new_unchecked(2023, 8, 10).days_in_quarter(), 92);
+        assert_eq!(IsoDate::new_unchecked(2023, 11, 10).days_in_quarter(), 92);
+
+        let last = IsoDate::new_unchecked(2023, 3, 31);
+        assert_eq!(last.day_of_quarter(), 90);
+        assert_eq!(last.day_of_quarter(), last.days_in_quarter());
+        assert!(last.is_last_day_of_quarter());
+        assert_eq!(IsoDate::new_unchecked(2024, 3, 31).day_of_quarter(), 91);
+
+        for month in [1, 4, 7, 10] {
+            let first = IsoDate::new_unchecked(2023, month, 1);
+            assert_eq!(first.day_of_quarter(), 1);
+            assert!(first.is_first_day_of_quarter());
+        }
+        assert_eq!(IsoDate::new_unchecked(2023, 5, 15).day_of_quarter(), 45);
+    }
+
+    #[test]
+    fn debug_output() {
+        use alloc::format;
+
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let debug = format!("{date:?}");
+        assert_eq!(debug, "IsoDate(2024-03-15)");
+        let inner = debug
+            .strip_prefix("IsoDate(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap();
+        let (parsed, _) = IsoDate::parse_with_calendar_annotation(inner).unwrap();
+        assert_eq!(parsed, date);
+
+        let time = IsoTime::new_unchecked(14, 30, 0, 123, 456, 789);
+        assert_eq!(format!("{time:?}"), "IsoTime(14:30:00.123456789)");
+
+        let datetime = IsoDateTime::new_unchecked(date, time);
+        let debug = format!("{datetime:?}");
+        assert_eq!(debug, "IsoDateTime(2024-03-15T14:30:00.123456789)");
+        let inner = debug
+            .strip_prefix("IsoDateTime(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap();
+        let parsed = IsoDateTime::parse_with_annotations(inner).unwrap();
+        assert_eq!(parsed.datetime, datetime);
+
+        assert_eq!(
+            format!("{:?}", IsoDate::new_unchecked(-1000, 1, 1)),
+            "IsoDate(-001000-01-01)"
+        );
+    }
+
+    #[test]
+    fn const_constructors() {
+        const LEAP_DAY: IsoDate = IsoDate::new_checked(2024, 2, 29);
+        const LAST_NANO: IsoTime = IsoTime::const_new(23, 59, 59, 999, 999, 999);
+        const MAX: IsoDateTime =
+            IsoDateTime::const_new(IsoDate::new_checked(275_760, 9, 13), IsoTime::MIDNIGHT);
+        const _: IsoDate = IsoDate::new_checked(2024, 1, 1);
+
+        assert_eq!(LEAP_DAY, IsoDate::new_unchecked(2024, 2, 29));
+        assert_eq!(LAST_NANO, IsoTime::new_unchecked(23, 59, 59, 999, 999, 999));
+        assert!(MAX.is_within_limits());
+        assert_eq!(MAX, IsoDateTime::new(MAX.date, MAX.time).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "IsoDate fields are not valid.")]
+    fn const_constructor_panics_at_runtime() {
+        let _ = IsoDate::new_checked(2023, 2, 29);
+    }
+
+    #[test]
+    fn epoch_constants() {
+        assert_eq!(IsoDate::EPOCH.to_epoch_days(), 0);
+        assert_eq!(IsoDateTime::UNIX_EPOCH.to_unix_timestamp_nanos(), Ok(0));
+        assert_eq!(IsoDateTime::UNIX_EPOCH.date, IsoDate::EPOCH);
+
+        let noon = IsoTime::noon();
+        assert!(IsoDateTime::new(IsoDate::TEMPORAL_MIN, noon).is_ok());
+        assert!(IsoDateTime::new(IsoDate::TEMPORAL_MAX, noon).is_ok());
+        assert!(IsoDateTime::new(IsoDate::new_unchecked(-271_821, 4, 18), noon).is_err());
+        assert!(IsoDateTime::new(IsoDate::new_unchecked(275_760, 9, 14), noon).is_err());
+    }
+
+    #[test]
+    fn month_navigation() {
+        let date = IsoDate::new_unchecked(2024, 1, 15);
+        assert_eq!(
+            date.previous_month_end(),
+            Ok(IsoDate::new_unchecked(2023, 12, 31))
+        );
+        assert_eq!(
+            date.next_month_start(),
+            Ok(IsoDate::new_unchecked(2024, 2, 1))
+        );
+        assert_eq!(
+            IsoDate::new_unchecked(2024, 3, 31).previous_month_end(),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert_eq!(
+            IsoDate::new_unchecked(2023, 12, 31).next_month_start(),
+            Ok(IsoDate::new_unchecked(2024, 1, 1))
+        );
+
+        assert_eq!(date.months_ahead(0), Ok(IsoDate::new_unchecked(2024, 1, 1)));
+        assert_eq!(
+            date.months_ahead(25),
+            Ok(IsoDate::new_unchecked(2026, 2, 1))
+        );
+        assert_eq!(
+            date.months_ahead(-1),
+            Ok(IsoDate::new_unchecked(2023, 12, 1))
+        );
+        assert_eq!(
+            date.months_ahead(-13),
+            Ok(IsoDate::new_unchecked(2022, 12, 1))
+        );
+
+        // Results outside of the `Temporal` limits are rejected.
+        assert!(date.months_ahead(i32::MAX).is_err());
+        assert!(date.months_ahead(i32::MIN).is_err());
+        let max = IsoDate::TEMPORAL_MAX;
+        assert!(max.next_month_start().is_err());
+        assert_eq!(
+            max.previous_month_end(),
+            Ok(IsoDate::new_unchecked(275_760, 8, 31))
+        );
+        assert_eq!(
+            max.months_ahead(0),
+            Ok(IsoDate::new_unchecked(275_760, 9, 1))
+        );
+        let min = IsoDate::TEMPORAL_MIN;
+        assert!(min.previous_month_end().is_err());
+        // The first of the minimum month is before the minimum date.
+        assert!(min.months_ahead(0).is_err());
+        assert_eq!(
+            min.next_month_start(),
+            Ok(IsoDate::new_unchecked(-271_821, 5, 1))
+        );
+    }
+
+    #[test]
+    fn time_add_and_sub_are_inverse() {
+        use crate::builtins::core::duration::normalized::NormalizedTimeDuration;
+
+        let ns_per_day = i128::from(crate::NS_PER_DAY);
+        let times = [
+            IsoTime::MIDNIGHT,
+            IsoTime::new_unchecked(13, 45, 12, 345, 678, 901),
+            IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+        ];
+        let durations = [
+            0,
+            1,
+            -1,
+            999_999_999,
+            -86_399_999_999_999,
+            ns_per_day,
+            -ns_per_day,
+            3 * ns_per_day + 12_345_678_901,
+            -(5 * ns_per_day) - 987_654_321,
+            1_000_000 * ns_per_day + 1,
+        ];
+        for time in times {
+            for nanoseconds in durations {
+                let norm = NormalizedTimeDuration(nanoseconds);
+                let (added_days, added) = time.add(norm);
+                let (sub_days, result) = added.sub(norm);
+                assert_eq!(result, time, "{time:?} {nanoseconds}");
+                assert_eq!(added_days + sub_days, 0, "{time:?} {nanoseconds}");
+
+                let (days, added_ns) = time.add_nanoseconds_i128(nanoseconds);
+                assert_eq!(added_ns, added, "{time:?} {nanoseconds}");
+                assert_eq!(days, i128::from(added_days), "{time:?} {nanoseconds}");
+            }
+        }
+
+        // Crossing several midnights reports the overflow days.
+        let (days, result) = IsoTime::new_unchecked(23, 0, 0, 0, 0, 0)
+            .add(NormalizedTimeDuration(2 * ns_per_day + 3_600_000_000_000));
+        assert_eq!((days, result), (3, IsoTime::MIDNIGHT));
+        let (days, result) = IsoTime::MIDNIGHT.sub(NormalizedTimeDuration(2 * ns_per_day + 1));
+        assert_eq!(
+            (days, result),
+            (-3, IsoTime::new_unchecked(23, 59, 59, 999, 999, 999))
+        );
+        // The overflow days of an `i128` of nanoseconds may not fit in an `i64`.
+        assert_eq!(
+            IsoTime::MIDNIGHT.add_nanoseconds_i128(i128::MAX).0,
+            i128::MAX / ns_per_day
+        );
+
+        let time = IsoTime::new_unchecked(13, 45, 12, 345, 678, 901);
+
+        assert_eq!(
+            time.add_seconds(86_400 * 2 + 10_800),
+            (2, IsoTime::new_unchecked(16, 45, 12, 345, 678, 901))
+        );
+        assert_eq!(
+            time.sub_seconds(86_400 + 14 * 3600),
+            (-2, IsoTime::new_unchecked(23, 45, 12, 345, 678, 901))
+        );
+        assert_eq!(time.add_seconds(-1), time.sub_seconds(1));
+        assert_eq!(time.add_seconds(i64::MIN).0, i64::MIN / 86_400 - 1);
+        assert_eq!(time.sub_seconds(i64::MIN).0, -(i64::MIN / 86_400) + 1);
+    }
+
+    #[test]
+    fn from_epoch_ms() {
+        use crate::unix_time::EpochNanoseconds;
+
+        assert_eq!(
+            IsoDate::from_epoch_ms(0.0),
+            Ok(IsoDate::new_unchecked(1970, 1, 1))
+        );
+        assert_eq!(
+            IsoDate::from_epoch_ms(-1.0),
+            Ok(IsoDate::new_unchecked(1969, 12, 31))
+        );
+        assert_eq!(
+            IsoDate::from_epoch_ms(-0.5),
+            Ok(IsoDate::new_unchecked(1969, 12, 31))
+        );
+        assert_eq!(IsoTime::from_epoch_ms(0.0), IsoTime::MIDNIGHT);
+        assert_eq!(
+            IsoTime::from_epoch_ms(-1.0),
+            IsoTime::new_unchecked(23, 59, 59, 999, 0, 0)
+        );
+
+        for epoch_ms in [
+            0i64,
+            -1,
+            1_710_513_000_123,
+            -62_135_596_800_001,
+            8_639_999_999_999_999,
+            -8_639_999_999_999_999,
+        ] {
+            let datetime = IsoDateTime::from_epoch_nanos(
+                &EpochNanoseconds(i128::from(epoch_ms) * 1_000_000),
+                0,
+            )
+            .unwrap();
+            let ms = epoch_ms as f64;
+            assert_eq!(IsoDate::from_epoch_ms(ms), Ok(datetime.date), "{epoch_ms}");
+            assert_eq!(IsoTime::from_epoch_ms(ms), datetime.time, "{epoch_ms}");
+        }
+
+        assert!(IsoDate::from_epoch_ms(f64::NAN).is_err());
+        assert!(IsoDate::from_epoch_ms(f64::INFINITY).is_err());
+        assert!(IsoDate::from_epoch_ms(8_640_000_000_000_000.0 + 86_400_000.0).is_err());
+        assert!(IsoDate::from_epoch_ms(1e300).is_err());
+    }
+
+    #[test]
+    fn datetime_add_and_subtract() {
+        use crate::Duration;
+
+        let datetime = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 1, 31),
+            IsoTime::new_unchecked(10, 30, 0, 0, 0, 0),
+        );
+        let one_month = Duration::new(0, 1, 0, 0, 0, 0, 0, 0, 0, 0).unwrap();
+        let result = datetime
+            .add(&one_month, ArithmeticOverflow::Constrain)
+            .unwrap();
+        assert_eq!(result.date, IsoDate::new_unchecked(2024, 2, 29));
+        assert_eq!(result.time, datetime.time);
+        assert!(datetime
+            .add(&one_month, ArithmeticOverflow::Reject)
+            .is_err());
+
+        let hours = Duration::new(0, 0, 0, 0, 25, 0, 0, 0, 0, 0).unwrap();
+        let result = datetime.add(&hours, ArithmeticOverflow::Reject).unwrap();
+        assert_eq!(
+            result,
+            IsoDateTime::new_unchecked(
+                IsoDate::new_unchecked(2024, 2, 1),
+                IsoTime::new_unchecked(11, 30, 0, 0, 0, 0),
+            )
+        );
+        assert_eq!(
+            result.subtract(&hours, ArithmeticOverflow::Reject),
+            Ok(datetime)
+        );
+
+        let mixed = Duration::new(1, 0, 2, 3, 4, 5, 6, 7, 8, 9).unwrap();
+        let result = datetime.add(&mixed, ArithmeticOverflow::Reject).unwrap();
+        assert_eq!(
+            result.subtract(&mixed, ArithmeticOverflow::Reject),
+            Ok(datetime)
+        );
+
+        let max =
+            IsoDateTime::new_unchecked(IsoDate::new_unchecked(275_760, 9, 13), IsoTime::noon());
+        assert!(max.add(&hours, ArithmeticOverflow::Constrain).is_err());
+    }
+
+    #[test]
+    fn public_balance_utilities() {
+        use super::{balance_iso_time, balance_iso_year_month};
+
+        assert_eq!(balance_iso_year_month(2024, 0), (2023, 12));
+        assert_eq!(balance_iso_year_month(2024, 13), (2025, 1));
+        assert_eq!(balance_iso_year_month(2024, -1), (2023, 11));
+        assert_eq!(balance_iso_year_month(2024, 24), (2025, 12));
+        assert_eq!(balance_iso_year_month(2024, 25), (2026, 1));
+        assert_eq!(balance_iso_year_month(2024, -12), (2022, 12));
+
+        assert_eq!(
+            balance_iso_time(25, 61, 0, 0, 0, -1),
+            (1, IsoTime::new_unchecked(2, 0, 59, 999, 999, 999))
+        );
+        assert_eq!(balance_iso_time(-1, 0, 0, 0, 0, 0).0, -1);
+    }
+
+    #[test]
+    fn date_add_duration() {
+        use crate::Duration;
+
+        let date = IsoDate::new_unchecked(2024, 1, 31);
+        let duration = Duration::new(1, 1, 1, 1, 0, 0, 0, 0, 0, 0).unwrap();
+        let result = date
+            .add_duration(&duration, ArithmeticOverflow::Constrain)
+            .unwrap();
+        assert_eq!(result, IsoDate::new_unchecked(2025, 3, 8));
+        assert!(date
+            .add_duration(&duration, ArithmeticOverflow::Reject)
+            .is_err());
+        assert_eq!(
+            IsoDate::new_unchecked(2025, 3, 8)
+                .subtract_duration(&duration, ArithmeticOverflow::Reject),
+            Ok(date)
+        );
+
+        let with_time = Duration::new(0, 0, 0, 1, 1, 0, 0, 0, 0, 0).unwrap();
+        assert!(date
+            .add_duration(&with_time, ArithmeticOverflow::Constrain)
+            .is_err());
+        let days = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            date.add_duration(&days, ArithmeticOverflow::Reject),
+            Ok(IsoDate::new_unchecked(2024, 2, 1))
+        );
+
+        assert_eq!(
+            date.add_years_months(0, 1, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert_eq!(
+            date.add_years_months(-1, 13, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert!(date
+            .add_years_months(0, 1, ArithmeticOverflow::Reject)
+            .is_err());
+        assert!(IsoDate::TEMPORAL_MAX
+            .add_years_months(0, 1, ArithmeticOverflow::Constrain)
+            .is_err());
+    }
+
+    #[test]
+    fn time_from_f64_components() {
+        assert_eq!(
+            IsoTime::from_components_f64(
+                13.0,
+                45.0,
+                30.0,
+                123.0,
+                456.0,
+                789.0,
+                ArithmeticOverflow::Reject
+            ),
+            Ok(IsoTime::new_unchecked(13, 45, 30, 123, 456, 789))
+        );
+        assert!(IsoTime::from_components_f64(
+            13.5,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            ArithmeticOverflow::Reject
+        )
+        .is_err());
+        assert!(IsoTime::from_components_f64(
+            24.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            ArithmeticOverflow::Reject
+        )
+        .is_err());
+        assert!(IsoTime::from_components_f64(
+            0.0,
+            -1.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            ArithmeticOverflow::Reject
+        )
+        .is_err());
+
+        // Fractional values carry into the next smaller unit.
+        assert_eq!(
+            IsoTime::from_components_f64(
+                13.5,
+                0.25,
+                0.0,
+                0.0,
+                0.0,
+                1.75,
+                ArithmeticOverflow::Constrain
+            ),
+            Ok(IsoTime::new_unchecked(13, 30, 15, 0, 0, 1))
+        );
+        // Negative values balance to a positive time on the previous day.
+        assert_eq!(
+            IsoTime::from_components_f64(
+                0.0,
+                -1.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                ArithmeticOverflow::Constrain
+            ),
+            Ok(IsoTime::new_unchecked(23, 59, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            IsoTime::from_components_f64(
+                25.0,
+                0.0,
+                -0.5,
+                0.0,
+                0.0,
+                0.0,
+                ArithmeticOverflow::Constrain
+            ),
+            Ok(IsoTime::new_unchecked(0, 59, 59, 500, 0, 0))
+        );
+        assert!(IsoTime::from_components_f64(
+            f64::NAN,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            ArithmeticOverflow::Constrain
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn gregorian_easter() {
+        assert_eq!(
+            IsoDate::gregorian_easter(2024),
+            Ok(IsoDate::new_unchecked(2024, 3, 31))
+        );
+        assert_eq!(
+            IsoDate::gregorian_easter(2025),
+            Ok(IsoDate::new_unchecked(2025, 4, 20))
+        );
+        assert_eq!(
+            IsoDate::gregorian_easter(2000),
+            Ok(IsoDate::new_unchecked(2000, 4, 23))
+        );
+        // Extremes of the Easter date range.
+        assert_eq!(
+            IsoDate::gregorian_easter(1818),
+            Ok(IsoDate::new_unchecked(1818, 3, 22))
+        );
+        assert_eq!(
+            IsoDate::gregorian_easter(1943),
+            Ok(IsoDate::new_unchecked(1943, 4, 25))
+        );
+
+        for year in 1900..=2099 {
+            let easter = IsoDate::gregorian_easter(year).unwrap();
+            assert!(easter.is_gregorian_easter());
+            assert!(easter.is_valid());
+            assert_eq!(iso_day_of_week(easter.to_epoch_days().into()), 7, "{year}");
+            assert!(
+                (easter.month, easter.day) >= (3, 22) && (easter.month, easter.day) <= (4, 25),
+                "{year}"
+            );
+        }
+        assert!(!IsoDate::new_unchecked(2024, 4, 1).is_gregorian_easter());
+
+        // Euclidean division keeps Easter on a Sunday for non-positive years.
+        for year in [-271_820, -1, 0, 275_760] {
+            let easter = IsoDate::gregorian_easter(year).unwrap();
+            assert_eq!(iso_day_of_week(easter.to_epoch_days().into()), 7, "{year}");
+        }
+        // Easter of -271821 falls before the earliest supported date.
+        assert!(IsoDate::gregorian_easter(-271_821).is_err());
+    }
+
+    #[test]
+    fn ages_and_birthdays() {
+        let birth = IsoDate::new_unchecked(2000, 2, 29);
+        let age = |year, month, day| {
+            IsoDate::age_in_years(&birth, &IsoDate::new_unchecked(year, month, day))
+        };
+        assert_eq!(age(2024, 2, 28), Ok(23));
+        assert_eq!(age(2024, 2, 29), Ok(24));
+        assert_eq!(age(2023, 2, 27), Ok(22));
+        assert_eq!(age(2023, 2, 28), Ok(23));
+        assert_eq!(age(2000, 2, 29), Ok(0));
+        assert!(age(2000, 2, 28).is_err());
+
+        let birth = IsoDate::new_unchecked(1990, 7, 15);
+        let reference = IsoDate::new_unchecked(2024, 7, 14);
+        assert_eq!(IsoDate::age_in_years(&birth, &reference), Ok(33));
+        assert_eq!(IsoDate::age_in_months(&birth, &reference), Ok(407));
+        assert_eq!(
+            IsoDate::age_in_months(&birth, &IsoDate::new_unchecked(2024, 7, 15)),
+            Ok(408)
+        );
+        assert_eq!(
+            IsoDate::age_in_months(
+                &IsoDate::new_unchecked(2024, 1, 31),
+                &IsoDate::new_unchecked(2024, 2, 29)
+            ),
+            Ok(1)
+        );
+        assert!(IsoDate::age_in_months(&reference, &birth).is_err());
+
+        assert_eq!(
+            IsoDate::next_birthday(&birth, &IsoDate::new_unchecked(2024, 7, 14)),
+            IsoDate::new_unchecked(2024, 7, 15)
+        );
+        assert_eq!(
+            IsoDate::next_birthday(&birth, &IsoDate::new_unchecked(2024, 7, 15)),
+            IsoDate::new_unchecked(2025, 7, 15)
+        );
+        let leap_birth = IsoDate::new_unchecked(2000, 2, 29);
+        assert_eq!(
+            IsoDate::next_birthday(&leap_birth, &IsoDate::new_unchecked(2024, 3, 1)),
+            IsoDate::new_unchecked(2025, 2, 28)
+        );
+        assert_eq!(
+            IsoDate::next_birthday(&leap_birth, &IsoDate::new_unchecked(2023, 6, 1)),
+            IsoDate::new_unchecked(2024, 2, 29)
+        );
+        assert_eq!(
+            IsoDate::next_birthday(&leap_birth, &IsoDate::new_unchecked(1999, 1, 1)),
+            leap_birth
+        );
+    }
+
+    #[test]
+    fn date_time_groups() {
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let time = IsoTime::new_unchecked(20, 0, 45, 0, 0, 0);
+        assert_eq!(date.to_dtg(&time, 'Z'), "152000Z MAR 24");
+        assert_eq!(
+            IsoDate::from_dtg("152000Z MAR 24"),
+            Ok((date, IsoTime::new_unchecked(20, 0, 0, 0, 0, 0), 'Z'))
+        );
+
+        let midnight = IsoDate::new_unchecked(1999, 12, 1).to_dtg(&IsoTime::MIDNIGHT, 'A');
+        assert_eq!(midnight, "010000A DEC 99");
+        assert_eq!(
+            IsoDate::from_dtg(&midnight),
+            Ok((IsoDate::new_unchecked(1999, 12, 1), IsoTime::MIDNIGHT, 'A'))
+        );
+        assert_eq!(
+            IsoDate::from_dtg("010000Z JAN 69").map(|(date, ..)| date.year),
+            Ok(2069)
+        );
+        assert_eq!(
+            IsoDate::from_dtg("010000Z JAN 70").map(|(date, ..)| date.year),
+            Ok(1970)
+        );
+
+        for month in 1..=12 {
+            let date = IsoDate::new_unchecked(2024, month, 9);
+            let time = IsoTime::new_unchecked(7, 5, 0, 0, 0, 0);
+            let dtg = date.to_dtg(&time, 'Z');
+            assert_eq!(IsoDate::from_dtg(&dtg), Ok((date, time, 'Z')), "{dtg}");
+        }
+        assert_eq!(
+            IsoDate::from_dtg("090705Z sep 24").map(|(date, ..)| date.month),
+            Ok(9)
+        );
+
+        let invalid = [
+            "152500Z MAR 24",
+            "152060Z MAR 24",
+            "302000Z FEB 24",
+            "152000J MAR 24",
+            "152000z MAR 24",
+            "152000Z MRZ 24",
+            "152000Z MAR 2024",
+            "15200Z MAR 24",
+            "152000Z  MAR 24",
+            "",
+        ];
+        for s in invalid {
+            assert!(IsoDate::from_dtg(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn datetime_components() {
+        use super::IsoDateTimeComponents;
+        use crate::{
+            builtins::core::calendar::Calendar,
+            partial::{PartialDate, PartialDateTime, PartialTime},
+            PlainDateTime,
+        };
+
+        let datetime = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 2, 15),
+            IsoTime::new_unchecked(13, 45, 30, 123, 456, 789),
+        );
+        let components = datetime.to_components();
+        assert_eq!(components.year, 2024);
+        assert_eq!(components.nanosecond, 789);
+        assert_eq!(
+            components.to_iso_date_time(ArithmeticOverflow::Reject),
+            Ok(datetime)
+        );
+        assert_eq!(IsoDateTimeComponents::from(datetime), components);
+
+        let plain = PlainDateTime::new_unchecked(datetime, Calendar::ISO);
+        for overflow in [ArithmeticOverflow::Constrain, ArithmeticOverflow::Reject] {
+            let modified = IsoDateTimeComponents {
+                day: 31,
+                ..components
+            };
+            let partial = PartialDateTime {
+                date: PartialDate {
+                    day: Some(31),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            assert_eq!(
+                modified.to_iso_date_time(overflow).ok(),
+                plain.with(partial, Some(overflow)).ok().map(|dt| dt.iso)
+            );
+
+            let modified = IsoDateTimeComponents {
+                hour: 7,
+                ..components
+            };
+            let partial = PartialDateTime {
+                time: PartialTime {
+                    hour: Some(7),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            assert_eq!(
+                modified.to_iso_date_time(overflow).ok(),
+                plain.with(partial, Some(overflow)).ok().map(|dt| dt.iso)
+            );
+        }
+
+        let default = IsoDateTimeComponents::default();
+        assert_eq!((default.year, default.month, default.day), (0, 1, 1));
+        assert_eq!(
+            default.to_iso_date_time(ArithmeticOverflow::Reject),
+            Ok(IsoDateTime::new_unchecked(
+                IsoDate::new_unchecked(0, 1, 1),
+                IsoTime::MIDNIGHT
+            ))
+        );
+        let from_date = IsoDateTimeComponents::from(IsoDate::new_unchecked(2024, 3, 15));
+        assert_eq!((from_date.year, from_date.hour), (2024, 0));
+        let from_time = IsoDateTimeComponents::from(IsoTime::new_unchecked(9, 0, 0, 0, 0, 0));
+        assert_eq!(
+            (from_time.year, from_time.month, from_time.day),
+            (1970, 1, 1)
+        );
+        assert_eq!(from_time.hour, 9);
+    }
+
+    #[test]
+    fn date_within_valid_limits() {
+        use super::{iso_date_within_valid_limits, iso_dt_within_valid_limits};
+
+        let dates = [
+            IsoDate::new_unchecked(-271_821, 4, 18),
+            IsoDate::TEMPORAL_MIN,
+            IsoDate::new_unchecked(-271_821, 4, 20),
+            IsoDate::EPOCH,
+            IsoDate::new_unchecked(275_760, 9, 12),
+            IsoDate::TEMPORAL_MAX,
+            IsoDate::new_unchecked(275_760, 9, 14),
+            IsoDate::new_unchecked(1_000_000, 12, 31),
+            IsoDate::new_unchecked(-1_000_000, 1, 1),
+        ];
+        for date in dates {
+            assert_eq!(
+                iso_date_within_valid_limits(date),
+                iso_dt_within_valid_limits(date, &IsoTime::noon()),
+                "{date:?}"
+            );
+        }
+        assert!(iso_date_within_valid_limits(IsoDate::TEMPORAL_MIN));
+        assert!(iso_date_within_valid_limits(IsoDate::TEMPORAL_MAX));
+        assert!(!iso_date_within_valid_limits(IsoDate::new_unchecked(
+            275_760, 9, 14
+        )));
+    }
+
+    #[test]
+    fn year_month_constructors() {
+        assert_eq!(
+            IsoDate::from_year_month(2024, 0, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 1, 1))
+        );
+        assert_eq!(
+            IsoDate::from_year_month(2024, 13, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 12, 1))
+        );
+        assert_eq!(
+            IsoDate::from_year_month(2024, 6, ArithmeticOverflow::Reject),
+            Ok(IsoDate::new_unchecked(2024, 6, 1))
+        );
+        assert!(IsoDate::from_year_month(2024, 0, ArithmeticOverflow::Reject).is_err());
+        assert!(IsoDate::from_year_month(2024, 13, ArithmeticOverflow::Reject).is_err());
+        assert!(IsoDate::from_year_month(-271_821, 4, ArithmeticOverflow::Reject).is_ok());
+        assert!(IsoDate::from_year_month(-271_821, 3, ArithmeticOverflow::Reject).is_err());
+        assert!(IsoDate::from_year_month(275_760, 10, ArithmeticOverflow::Constrain).is_err());
+
+        assert_eq!(
+            IsoDate::last_day_of(2024, 2),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert_eq!(
+            IsoDate::last_day_of(2023, 2),
+            Ok(IsoDate::new_unchecked(2023, 2, 28))
+        );
+        assert!(IsoDate::last_day_of(2024, 13).is_err());
+
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert!(date.is_same_year_month_as(&IsoDate::new_unchecked(2024, 3, 1)));
+        assert!(!date.is_same_year_month_as(&IsoDate::new_unchecked(2023, 3, 15)));
+        assert!(!date.is_same_year_month_as(&IsoDate::new_unchecked(2024, 4, 15)));
+    }
+
+    #[test]
+    fn reference_dates() {
+        assert!(utils::is_leap_year(IsoDate::MONTH_DAY_REFERENCE_YEAR));
+        let leap_day = IsoDate::new_unchecked(1972, 2, 29);
+        assert!(leap_day.is_valid());
+        assert!(leap_day.is_valid_reference_for_month_day());
+        assert!(!IsoDate::new_unchecked(2024, 2, 29).is_valid_reference_for_month_day());
+
+        let reference = IsoDate::from_year_month(2024, 5, ArithmeticOverflow::Reject).unwrap();
+        assert!(reference.is_valid_reference_for_year_month());
+        assert!(!IsoDate::new_unchecked(2024, 5, 2).is_valid_reference_for_year_month());
+    }
+
+    #[test]
+    fn ecma_strings() {
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(date.to_ecma_date_string(), "2024-03-15");
+        // Only years outside of 0 through 9999 are expanded.
+        for (year, expected) in [
+            (-1, "-000001-01-01"),
+            (0, "0000-01-01"),
+            (9999, "9999-01-01"),
+            (10_000, "+010000-01-01"),
+            (-271_821, "-271821-01-01"),
+            (275_760, "+275760-01-01"),
+        ] {
+            let date = IsoDate::new_unchecked(year, 1, 1);
+            assert_eq!(date.to_ecma_date_string(), expected);
+            assert_eq!(date.to_ecma_date_string(), date.to_iso_string());
+        }
+
+        let noon = IsoDateTime::new_unchecked(date, IsoTime::noon());
+        assert_eq!(noon.to_ecma_date_time_string(), "2024-03-15T12:00:00.000Z");
+        let midnight = IsoDateTime::new_unchecked(date, IsoTime::default());
+        assert_eq!(
+            midnight.to_ecma_date_time_string(),
+            "2024-03-15T00:00:00.000Z"
+        );
+        let precise =
+            IsoDateTime::new_unchecked(date, IsoTime::new_unchecked(14, 30, 5, 120, 999, 999));
+        assert_eq!(
+            precise.to_ecma_date_time_string(),
+            "2024-03-15T14:30:05.120Z"
+        );
+
+        assert_eq!(
+            IsoDateTime::from_ecma_date_time_string("2024-03-15T12:00:00.000Z"),
+            Ok(noon)
+        );
+        assert_eq!(
+            IsoDateTime::from_ecma_date_time_string("2024-03-15T14:30:05.120Z"),
+            Ok(IsoDateTime::new_unchecked(
+                date,
+                IsoTime::new_unchecked(14, 30, 5, 120, 0, 0)
+            ))
+        );
+        let expanded = IsoDateTime::from_ecma_date_time_string("+275760-09-13T00:00:00.000Z");
+        assert_eq!(
+            expanded.map(|dt| dt.to_ecma_date_time_string()).as_deref(),
+            Ok("+275760-09-13T00:00:00.000Z")
+        );
+
+        for invalid in [
+            "2024-03-15",
+            "2024-03-15T12:00:00Z",
+            "2024-03-15T12:00:00.000",
+            "2024-03-15T12:00:00.000+00:00",
+            "2024-03-15T12:00:00.000000Z",
+            "2024-03-15 12:00:00.000Z",
+            "-000000-03-15T12:00:00.000Z",
+            "2024-02-30T12:00:00.000Z",
+            "2024-03-15T24:00:00.000Z",
+        ] {
+            assert!(
+                IsoDateTime::from_ecma_date_time_string(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn business_days_in_period() {
+        let march = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(march.business_days_in_month(), 21);
+        assert_eq!(march.business_days_in_year(), 262);
+        assert_eq!(
+            IsoDate::new_unchecked(2023, 6, 1).business_days_in_year(),
+            260
+        );
+
+        assert_eq!(march.business_day_of_month(), Some(11));
+        assert_eq!(
+            IsoDate::new_unchecked(2024, 3, 1).business_day_of_month(),
+            Some(1)
+        );
+        assert_eq!(
+            IsoDate::new_unchecked(2024, 3, 16).business_day_of_month(),
+            None
+        );
+
+        // January 1st, 2024 is a Monday.
+        assert_eq!(
+            IsoDate::from_business_day_of_month(2024, 1, 5),
+            Ok(IsoDate::new_unchecked(2024, 1, 5))
+        );
+        assert_eq!(
+            IsoDate::from_business_day_of_month(2024, 1, 6),
+            Ok(IsoDate::new_unchecked(2024, 1, 8))
+        );
+        // June 1st, 2024 is a Saturday.
+        assert_eq!(
+            IsoDate::from_business_day_of_month(2024, 6, 1),
+            Ok(IsoDate::new_unchecked(2024, 6, 3))
+        );
+        assert_eq!(
+            IsoDate::from_business_day_of_month(2024, 6, 20),
+            Ok(IsoDate::new_unchecked(2024, 6, 28))
+        );
+        assert!(IsoDate::from_business_day_of_month(2024, 6, 21).is_err());
+        assert!(IsoDate::from_business_day_of_month(2024, 6, 0).is_err());
+        assert!(IsoDate::from_business_day_of_month(2024, 13, 1).is_err());
+    }
+
+    #[test]
+    fn seconds_since_midnight_and_epoch() {
+        assert_eq!(
+            IsoTime::from_seconds_since_midnight(86399),
+            Ok(IsoTime::new_unchecked(23, 59, 59, 0, 0, 0))
+        );
+        assert_eq!(
+            IsoTime::from_seconds_since_midnight(0),
+            Ok(IsoTime::default())
+        );
+        assert!(IsoTime::from_seconds_since_midnight(86400).is_err());
+        let time = IsoTime::new_unchecked(14, 30, 15, 999, 0, 0);
+        assert_eq!(time.seconds_since_midnight(), 52215);
+        assert_eq!(
+            IsoTime::from_seconds_since_midnight(time.seconds_since_midnight()),
+            Ok(time.truncate_sub_seconds())
+        );
+
+        assert_eq!(IsoDate::EPOCH.seconds_since_epoch(), 0);
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(date.seconds_since_epoch(), 1_710_460_800);
+        assert_eq!(
+            IsoDate::from_seconds_since_epoch(1_710_460_800 + 86_399),
+            Ok(date)
+        );
+        assert_eq!(
+            IsoDate::from_seconds_since_epoch(-1),
+            Ok(IsoDate::new_unchecked(1969, 12, 31))
+        );
+        assert_eq!(
+            IsoDate::from_seconds_since_epoch(IsoDate::TEMPORAL_MIN.seconds_since_epoch()),
+            Ok(IsoDate::TEMPORAL_MIN)
+        );
+        assert!(
+            IsoDate::from_seconds_since_epoch(IsoDate::TEMPORAL_MIN.seconds_since_epoch() - 1)
+                .is_err()
+        );
+        assert!(IsoDate::from_seconds_since_epoch(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn nearest_dates() {
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let candidates = [
+            IsoDate::new_unchecked(2024, 3, 20),
+            IsoDate::new_unchecked(2024, 3, 10),
+        ];
+        assert_eq!(
+            date.nearest(&candidates),
+            Some(IsoDate::new_unchecked(2024, 3, 10))
+        );
+        assert_eq!(
+            date.nearest_before(&candidates),
+            Some(IsoDate::new_unchecked(2024, 3, 10))
+        );
+        assert_eq!(
+            date.nearest_after(&candidates),
+            Some(IsoDate::new_unchecked(2024, 3, 20))
+        );
+        assert_eq!(date.nearest(&[]), None);
+        assert_eq!(date.nearest_before(&[]), None);
+        assert_eq!(date.nearest_after(&[]), None);
+
+        let candidates = [
+            IsoDate::new_unchecked(2023, 12, 31),
+            date,
+            IsoDate::new_unchecked(2024, 3, 16),
+        ];
+        assert_eq!(date.nearest(&candidates), Some(date));
+        assert_eq!(date.nearest_before(&candidates), Some(date));
+        assert_eq!(date.nearest_after(&candidates), Some(date));
+        let later = IsoDate::new_unchecked(2024, 4, 1);
+        assert_eq!(later.nearest_after(&candidates), None);
+        assert_eq!(
+            later.nearest(&candidates),
+            Some(IsoDate::new_unchecked(2024, 3, 16))
+        );
+    }
+
+    #[test]
+    fn unix_dates() {
+        assert_eq!(IsoDate::EPOCH.to_unix_date(), 0);
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(date.to_unix_date(), 19797);
+        assert_eq!(date.days_since_unix_epoch(), 19797);
+        assert_eq!(IsoDate::from_unix_date(19797), Ok(date));
+        assert_eq!(
+            IsoDate::from_unix_date(-1),
+            Ok(IsoDate::new_unchecked(1969, 12, 31))
+        );
+        assert_eq!(
+            IsoDate::from_unix_date(-100_000_001),
+            Ok(IsoDate::TEMPORAL_MIN)
+        );
+        assert!(IsoDate::from_unix_date(-100_000_002).is_err());
+        assert!(IsoDate::from_unix_date(i32::MAX).is_err());
+    }
+
+    #[test]
+    fn excel_serials() {
+        let cases = [
+            (1, IsoDate::new_unchecked(1900, 1, 1)),
+            (59, IsoDate::new_unchecked(1900, 2, 28)),
+            (61, IsoDate::new_unchecked(1900, 3, 1)),
+            (44927, IsoDate::new_unchecked(2023, 1, 1)),
+            (44931, IsoDate::new_unchecked(2023, 1, 5)),
+            (2_958_465, IsoDate::new_unchecked(9999, 12, 31)),
+        ];
+        for (serial, date) in cases {
+            assert_eq!(date.to_excel_serial(), Ok(serial));
+            assert_eq!(IsoDate::from_excel_serial(serial), Ok(date));
+        }
+        assert!(IsoDate::new_unchecked(1899, 12, 31)
+            .to_excel_serial()
+            .is_err());
+        assert!(IsoDate::new_unchecked(10000, 1, 1)
+            .to_excel_serial()
+            .is_err());
+        assert!(IsoDate::from_excel_serial(0).is_err());
+        assert!(IsoDate::from_excel_serial(60).is_err());
+        assert!(IsoDate::from_excel_serial(2_958_466).is_err());
+    }
+
+    #[test]
+    fn week_dates() {
+        use alloc::vec::Vec;
+
+        for (week_year, weeks, days) in [(2020, 53, 371), (2021, 52, 364)] {
+            let weeks_in_year = IsoDate::week_dates_in_year(week_year).collect::<Vec<_>>();
+            assert_eq!(weeks_in_year.len(), weeks);
+            assert_eq!(
+                weeks_in_year
+                    .iter()
+                    .map(|(_, dates)| dates.len())
+                    .sum::<usize>(),
+                days
+            );
+            for (index, (week, dates)) in weeks_in_year.iter().enumerate() {
+                assert_eq!(usize::from(*week), index + 1);
+                assert_eq!(
+                    IsoDate::from_ywd(week_year, *week, super::DayOfWeek::Monday),
+                    Ok(dates[0])
+                );
+                for pair in dates.windows(2) {
+                    assert_eq!(pair[0].to_epoch_days() + 1, pair[1].to_epoch_days());
+                }
+            }
+            for pair in weeks_in_year.windows(2) {
+                assert_eq!(
+                    pair[0].1[6].to_epoch_days() + 1,
+                    pair[1].1[0].to_epoch_days()
+                );
+            }
+        }
+
+        let march = IsoDate::week_dates_in_month(2024, 3).collect::<Vec<_>>();
+        assert_eq!(
+            march.iter().map(|(week, _)| *week).collect::<Vec<_>>(),
+            [9, 10, 11, 12, 13]
+        );
+        assert_eq!(march[0].1[0], IsoDate::new_unchecked(2024, 2, 26));
+        assert_eq!(march[4].1[6], IsoDate::new_unchecked(2024, 3, 31));
+
+        let january = IsoDate::week_dates_in_month(2021, 1).collect::<Vec<_>>();
+        assert_eq!(
+            january.iter().map(|(week, _)| *week).collect::<Vec<_>>(),
+            [53, 1, 2, 3, 4]
+        );
+        assert_eq!(january[0].1[0], IsoDate::new_unchecked(2020, 12, 28));
+    }
+
+    #[test]
+    fn human_readable_strings() {
+        use crate::parsers::Precision;
+
+        let noon = IsoTime::noon();
+        assert_eq!(noon.to_human_readable_string(), "12:00");
+        assert_eq!(
+            noon.to_time_string_with_precision(Precision::Digit(0)),
+            "12:00:00"
+        );
+        assert_eq!(
+            IsoTime::new_unchecked(9, 5, 30, 0, 0, 0).to_human_readable_string(),
+            "09:05:30"
+        );
+        assert_eq!(
+            IsoTime::new_unchecked(9, 5, 0, 500, 0, 0).to_human_readable_string(),
+            "09:05:00.5"
+        );
+        assert_eq!(
+            IsoTime::new_unchecked(23, 59, 59, 999, 999, 999).to_human_readable_string(),
+            "23:59:59.999999999"
+        );
+
+        assert_eq!(
+            IsoDate::new_unchecked(2024, 3, 15).to_human_readable_string(),
+            "March 15, 2024"
+        );
+        assert_eq!(
+            IsoDate::new_unchecked(1999, 12, 1).to_human_readable_string(),
+            "December 1, 1999"
+        );
+    }
+
+    #[test]
+    fn builder_chaining() {
+        let result = IsoDate::new_unchecked(2024, 1, 31)
+            .builder()
+            .try_map(|date| date.add_years_months(0, 1, ArithmeticOverflow::Constrain))
+            .try_map(|date| date.next_month_start())
+            .try_map(|date| date.add_business_days(10))
+            .try_map(|date| IsoDateTime::new(date, IsoTime::noon()))
+            .map(|datetime| datetime.to_ecma_date_time_string())
+            .unwrap_result();
+        assert_eq!(result.as_deref(), Ok("2024-03-15T12:00:00.000Z"));
+
+        let time = IsoTime::new_unchecked(23, 30, 0, 0, 0, 0)
+            .builder()
+            .map(|time| time.add_seconds(3600).1)
+            .and_then(|time| IsoTime::from_seconds_since_midnight(time.seconds_since_midnight()));
+        assert_eq!(time, Ok(IsoTime::new_unchecked(0, 30, 0, 0, 0, 0)));
+
+        // Operations after the first error are skipped.
+        let result = IsoDate::new_unchecked(2024, 1, 31)
+            .builder()
+            .try_map(|date| date.add_years_months(0, 1, ArithmeticOverflow::Reject))
+            .map(|_| -> IsoDate { unreachable!() })
+            .and_then(|date| IsoDateTime::new(date, IsoTime::noon()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn precision_reduction() {
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let datetime =
+            IsoDateTime::new(date, IsoTime::new_unchecked(14, 30, 45, 500, 0, 0)).unwrap();
+        let at = |hour, minute, second, millisecond| {
+            Ok(IsoDateTime::new_unchecked(
+                date,
+                IsoTime::new_unchecked(hour, minute, second, millisecond, 0, 0),
+            ))
+        };
+        assert_eq!(datetime.floor_to_minute(), at(14, 30, 0, 0));
+        assert_eq!(datetime.floor_to_hour(), at(14, 0, 0, 0));
+        assert_eq!(datetime.floor_to_second(), at(14, 30, 45, 0));
+        assert_eq!(datetime.floor_to_millisecond(), Ok(datetime));
+        assert_eq!(datetime.round_to_nearest_second(), at(14, 30, 46, 0));
+        assert_eq!(datetime.round_to_nearest_minute(), at(14, 31, 0, 0));
+        assert_eq!(datetime.round_to_nearest_hour(), at(15, 0, 0, 0));
+        assert_eq!(datetime.ceil_to_second(), at(14, 30, 46, 0));
+        assert_eq!(datetime.ceil_to_hour(), at(15, 0, 0, 0));
+        assert_eq!(
+            IsoDateTime::new_unchecked(date, IsoTime::noon()).ceil_to_minute(),
+            at(12, 0, 0, 0)
+        );
+
+        // Rounding up carries into the next day.
+        let late = IsoDateTime::new_unchecked(date, IsoTime::new_unchecked(23, 59, 30, 0, 0, 0));
+        assert_eq!(
+            late.round_to_nearest_minute(),
+            Ok(IsoDateTime::new_unchecked(
+                IsoDate::new_unchecked(2024, 3, 16),
+                IsoTime::MIDNIGHT
+            ))
+        );
+        assert_eq!(late.floor_to_minute(), at(23, 59, 0, 0));
+
+        let time = IsoTime::new_unchecked(23, 59, 29, 999, 999, 999);
+        assert_eq!(
+            time.floor_to_microsecond(),
+            IsoTime::new_unchecked(23, 59, 29, 999, 999, 0)
+        );
+        assert_eq!(
+            time.round_to_nearest_second(),
+            (0, IsoTime::new_unchecked(23, 59, 30, 0, 0, 0))
+        );
+        assert_eq!(
+            time.round_to_nearest_minute(),
+            (0, IsoTime::new_unchecked(23, 59, 0, 0, 0, 0))
+        );
+        assert_eq!(time.ceil_to_minute(), (1, IsoTime::MIDNIGHT));
+        assert_eq!(time.ceil_to_hour(), (1, IsoTime::MIDNIGHT));
+        assert_eq!(IsoTime::MIDNIGHT.ceil_to_hour(), (0, IsoTime::MIDNIGHT));
+    }
+
+    #[test]
+    fn weekday_counts() {
+        use super::DayOfWeek;
+        use alloc::vec::Vec;
+
+        let march = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(march.count_weekday_in_month(DayOfWeek::Friday), 5);
+        assert_eq!(march.count_weekday_in_month(DayOfWeek::Sunday), 5);
+        assert_eq!(march.count_weekday_in_month(DayOfWeek::Monday), 4);
+        assert_eq!(
+            march
+                .weekday_positions_in_month(DayOfWeek::Monday)
+                .collect::<Vec<_>>(),
+            [
+                IsoDate::new_unchecked(2024, 3, 4),
+                IsoDate::new_unchecked(2024, 3, 11),
+                IsoDate::new_unchecked(2024, 3, 18),
+                IsoDate::new_unchecked(2024, 3, 25),
+            ]
+        );
+        assert_eq!(
+            march.weekday_positions_in_month(DayOfWeek::Friday).last(),
+            Some(IsoDate::new_unchecked(2024, 3, 29))
+        );
+
+        let february = IsoDate::new_unchecked(2023, 2, 1);
+        assert_eq!(february.count_weekday_in_month(DayOfWeek::Wednesday), 4);
+        assert_eq!(february.count_weekday_in_month(DayOfWeek::Tuesday), 4);
+
+        // 2024 is a leap year that starts on a Monday.
+        assert_eq!(march.count_weekday_in_year(DayOfWeek::Monday), 53);
+        assert_eq!(march.count_weekday_in_year(DayOfWeek::Tuesday), 53);
+        assert_eq!(march.count_weekday_in_year(DayOfWeek::Wednesday), 52);
+        // 2023 is a common year that starts on a Sunday.
+        assert_eq!(february.count_weekday_in_year(DayOfWeek::Sunday), 53);
+        assert_eq!(february.count_weekday_in_year(DayOfWeek::Monday), 52);
+    }
+
+    #[test]
+    fn holidays_and_business_days() {
+        let start = IsoDate::new_unchecked(2024, 3, 4);
+        let end = IsoDate::new_unchecked(2024, 3, 10);
+        let holiday = IsoDate::new_unchecked(2024, 3, 6);
+        let holidays = [
+            IsoDate::new_unchecked(2024, 3, 11),
+            holiday,
+            IsoDate::new_unchecked(2024, 3, 9),
+            holiday,
+            IsoDate::new_unchecked(2024, 3, 3),
+        ];
+
+        assert_eq!(
+            holidays
+                .iter()
+                .filter(IsoDate::closed_range_filter(&start, &end))
+                .count(),
+            3
+        );
+        assert_eq!(
+            IsoDate::holidays_in_range(&start, &end, &holidays),
+            [holiday, IsoDate::new_unchecked(2024, 3, 9)]
+        );
+
+        // A week with one weekday holiday and one weekend holiday.
+        assert_eq!(
+            IsoDate::business_days_between_excluding(&start, &end, &holidays),
+            4
+        );
+        assert_eq!(
+            IsoDate::business_days_between_excluding(&start, &end, &[]),
+            5
+        );
+        // The range spans the start of daylight saving time in the United States on
+        // March 10th, which has no effect on dates.
+        let end = IsoDate::new_unchecked(2024, 3, 15);
+        assert_eq!(
+            IsoDate::business_days_between_excluding(&start, &end, &holidays),
+            8
+        );
+        assert_eq!(
+            IsoDate::business_days_between_excluding(&start, &end, &[]),
+            start.business_days_until(&end) as u32 + 1
+        );
+        assert_eq!(
+            IsoDate::business_days_between_excluding(&end, &start, &[]),
+            0
+        );
+    }
+
+    #[test]
+    fn parse_partial() {
+        use super::PartialIsoDateTime;
+
+        let partial = IsoDateTime::parse_partial("2024-03").unwrap();
+        assert_eq!(
+            partial,
+            PartialIsoDateTime {
+                year: Some(2024),
+                month: Some(3),
+                ..Default::default()
+            }
+        );
+        assert!(partial.to_iso_date(ArithmeticOverflow::Reject).is_err());
+        assert!(partial.to_iso_time(ArithmeticOverflow::Reject).is_err());
+        let defaults = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2000, 1, 31),
+            IsoTime::new_unchecked(9, 15, 0, 0, 0, 0),
+        );
+        assert_eq!(
+            partial.merge_with_defaults(&defaults),
+            Ok(IsoDateTime::new_unchecked(
+                IsoDate::new_unchecked(2024, 3, 31),
+                IsoTime::new_unchecked(9, 15, 0, 0, 0, 0)
+            ))
+        );
+        let february = IsoDateTime::parse_partial("2024-02").unwrap();
+        assert!(february.merge_with_defaults(&defaults).is_err());
+
+        let partial = IsoDateTime::parse_partial("T14:30").unwrap();
+        assert_eq!(
+            partial,
+            PartialIsoDateTime {
+                hour: Some(14),
+                minute: Some(30),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            partial.to_iso_time(ArithmeticOverflow::Reject),
+            Ok(IsoTime::new_unchecked(14, 30, 0, 0, 0, 0))
+        );
+        assert!(partial.to_iso_date(ArithmeticOverflow::Reject).is_err());
+
+        let partial = IsoDateTime::parse_partial("-000001-12-31T23:59:59.12345").unwrap();
+        assert_eq!(
+            partial.to_iso_date(ArithmeticOverflow::Reject),
+            Ok(IsoDate::new_unchecked(-1, 12, 31))
+        );
+        assert_eq!(
+            partial.to_iso_time(ArithmeticOverflow::Reject),
+            Ok(IsoTime::new_unchecked(23, 59, 59, 123, 450, 0))
+        );
+        assert_eq!(IsoDateTime::parse_partial("2024").unwrap().year, Some(2024));
+
+        // Fields are only validated when converted.
+        let partial = IsoDateTime::parse_partial("2024-02-30T25").unwrap();
+        assert!(partial.to_iso_date(ArithmeticOverflow::Reject).is_err());
+        assert_eq!(
+            partial.to_iso_date(ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert!(partial.to_iso_time(ArithmeticOverflow::Reject).is_err());
+
+        for invalid in [
+            "",
+            "T",
+            "24",
+            "2024-3",
+            "2024-03-",
+            "2024-03-15T",
+            "T14:30.5",
+            "T14:30:00.",
+            "T14:30:00.1234567890",
+            "T1430",
+            "-000000-01-01",
+        ] {
+            assert!(IsoDateTime::parse_partial(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn fiscal_years() {
+        // The United States federal fiscal year starts on October 1st.
+        let september = IsoDate::new_unchecked(2024, 9, 30);
+        let october = IsoDate::new_unchecked(2024, 10, 1);
+        assert_eq!(september.fiscal_year(10), Ok(2024));
+        assert_eq!(october.fiscal_year(10), Ok(2025));
+        assert_eq!(september.fiscal_quarter(10), Ok(4));
+        assert_eq!(october.fiscal_quarter(10), Ok(1));
+        assert_eq!(
+            IsoDate::new_unchecked(2025, 1, 15).fiscal_quarter(10),
+            Ok(2)
+        );
+        assert_eq!(
+            september.fiscal_year_start(10),
+            Ok(IsoDate::new_unchecked(2023, 10, 1))
+        );
+        assert_eq!(october.fiscal_year_start(10), Ok(october));
+
+        // The United Kingdom fiscal year is taken to start on April 1st.
+        let april = IsoDate::new_unchecked(2023, 4, 1);
+        let march = IsoDate::new_unchecked(2024, 3, 31);
+        assert_eq!(april.fiscal_year(4), Ok(2024));
+        assert_eq!(march.fiscal_year(4), Ok(2024));
+        assert_eq!(april.fiscal_quarter(4), Ok(1));
+        assert_eq!(IsoDate::new_unchecked(2023, 7, 1).fiscal_quarter(4), Ok(2));
+        assert_eq!(march.fiscal_quarter(4), Ok(4));
+        assert_eq!(march.fiscal_year_start(4), Ok(april));
+
+        // A January start is the calendar year.
+        let date = IsoDate::new_unchecked(2024, 12, 31);
+        assert_eq!(date.fiscal_year(1), Ok(2024));
+        assert_eq!(date.fiscal_quarter(1), Ok(4));
+        assert_eq!(
+            date.fiscal_year_start(1),
+            Ok(IsoDate::new_unchecked(2024, 1, 1))
+        );
+
+        assert!(date.fiscal_year(0).is_err());
+        assert!(date.fiscal_quarter(13).is_err());
+        assert!(date.fiscal_year_start(13).is_err());
+        assert!(IsoDate::TEMPORAL_MIN.fiscal_year_start(4).is_err());
+    }
+
+    #[test]
+    fn http_dates() {
+        // The examples of RFC 7231 section 7.1.1.1.
+        let expected = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(1994, 11, 6),
+            IsoTime::new_unchecked(8, 49, 37, 0, 0, 0),
+        );
+        for source in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+        ] {
+            assert_eq!(
+                IsoDateTime::from_http_date(source),
+                Ok(expected),
+                "{source}"
+            );
+        }
+        assert_eq!(expected.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        let datetime = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 3, 15),
+            IsoTime::new_unchecked(14, 30, 0, 500, 0, 0),
+        );
+        assert_eq!(datetime.to_http_date(), "Fri, 15 Mar 2024 14:30:00 GMT");
+        assert_eq!(
+            IsoDateTime::from_http_date("Friday, 15-Mar-24 14:30:00 GMT"),
+            Ok(IsoDateTime::new_unchecked(
+                datetime.date,
+                IsoTime::new_unchecked(14, 30, 0, 0, 0, 0)
+            ))
+        );
+        assert_eq!(
+            IsoDateTime::from_http_date("Fri Mar 15 14:30:00 2024"),
+            IsoDateTime::from_http_date("Fri, 15 Mar 2024 14:30:00 GMT")
+        );
+        assert_eq!(
+            IsoDateTime::from_http_date("Sat, 31 Dec 2016 23:59:60 GMT")
+                .map(|datetime| datetime.time),
+            Ok(IsoTime::new_unchecked(23, 59, 59, 0, 0, 0))
+        );
+
+        for invalid in [
+            "Thu, 15 Mar 2024 14:30:00 GMT",
+            "Fri, 15 Mar 2024 14:30:00 PST",
+            "Fri, 15 Mar 2024 14:30 GMT",
+            "Fri, 15 mar 2024 14:30:00 GMT",
+            "Fri,  15 Mar 2024 14:30:00 GMT",
+            "Fri, 15 Mar 24 14:30:00 GMT",
+            "Fri, 15 Mar 2024 24:00:00 GMT",
+            "Fri, 30 Feb 2024 14:30:00 GMT",
+            "Fri Mar 15 14:30:00 2024 GMT",
+            "Fri Mar 5 14:30:00 2024",
+            "Friday, 15 Mar 2024 14:30:00 GMT",
+        ] {
+            assert!(IsoDateTime::from_http_date(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn week_grids() {
+        use super::{DayOfWeek, GridLocale};
+
+        assert_eq!(
+            GridLocale::IsoMondayStart.first_day_of_week(),
+            DayOfWeek::Monday
+        );
+        assert_eq!(
+            GridLocale::USSundayStart.first_day_of_week(),
+            DayOfWeek::Sunday
+        );
+
+        // February 1st, 2024 is a Thursday.
+        let grid = IsoDate::to_week_grid(2024, 2, GridLocale::IsoMondayStart);
+        assert_eq!(grid[0][..3], [None; 3]);
+        assert_eq!(grid[0][3], Some(IsoDate::new_unchecked(2024, 2, 1)));
+        assert_eq!(grid[4][3], Some(IsoDate::new_unchecked(2024, 2, 29)));
+        assert_eq!(grid.iter().flatten().flatten().count(), 29);
+        assert_eq!(grid[5], [None; 7]);
+
+        let grid = IsoDate::to_week_grid(2024, 2, GridLocale::USSundayStart);
+        assert_eq!(grid[0][..4], [None; 4]);
+        assert_eq!(grid[0][4], Some(IsoDate::new_unchecked(2024, 2, 1)));
+        assert_eq!(grid[1][0], Some(IsoDate::new_unchecked(2024, 2, 4)));
+        assert_eq!(grid.iter().flatten().flatten().count(), 29);
+
+        // A 31 day month starting on a Sunday fills six ISO rows.
+        let grid = IsoDate::to_week_grid(2024, 9, GridLocale::IsoMondayStart);
+        assert_eq!(grid[0][6], Some(IsoDate::new_unchecked(2024, 9, 1)));
+        assert_eq!(grid[5][0], Some(IsoDate::new_unchecked(2024, 9, 30)));
+        let grid = IsoDate::to_week_grid(2024, 9, GridLocale::USSundayStart);
+        assert_eq!(grid[0][0], Some(IsoDate::new_unchecked(2024, 9, 1)));
+        assert_eq!(grid[5], [None; 7]);
+    }
+
+    #[test]
+    fn quarter_boundaries() {
+        use alloc::vec::Vec;
+
+        let q1_2024 = IsoDate::quarter_start(2024, 1).unwrap();
+        assert_eq!(q1_2024, IsoDate::new_unchecked(2024, 1, 1));
+        assert_eq!(
+            IsoDate::quarter_end(2024, 1),
+            Ok(IsoDate::new_unchecked(2024, 3, 31))
+        );
+        assert_eq!(q1_2024.days_in_quarter(), 91);
+        assert_eq!(
+            IsoDate::quarter_start(2023, 1).unwrap().days_in_quarter(),
+            90
+        );
+        assert_eq!(
+            IsoDate::quarter_start(2024, 4),
+            Ok(IsoDate::new_unchecked(2024, 10, 1))
+        );
+        assert_eq!(
+            IsoDate::quarter_end(2024, 2),
+            Ok(IsoDate::new_unchecked(2024, 6, 30))
+        );
+        assert_eq!(
+            IsoDate::quarter_end(2024, 3),
+            Ok(IsoDate::new_unchecked(2024, 9, 30))
+        );
+        assert!(IsoDate::quarter_start(2024, 5).is_err());
+        assert!(IsoDate::quarter_end(2024, 0).is_err());
+        assert!(IsoDate::quarter_start(-271_821, 2).is_err());
+        assert!(IsoDate::quarter_end(275_760, 3).is_err());
+
+        let quarters = IsoDate::quarters_in_range(2023, 2024).collect::<Vec<_>>();
+        assert_eq!(quarters.len(), 8);
+        assert_eq!(
+            quarters[0],
+            (
+                1,
+                IsoDate::new_unchecked(2023, 1, 1),
+                IsoDate::new_unchecked(2023, 3, 31)
+            )
+        );
+        assert_eq!(
+            quarters[7],
+            (
+                4,
+                IsoDate::new_unchecked(2024, 10, 1),
+                IsoDate::new_unchecked(2024, 12, 31)
+            )
+        );
+        for pair in quarters.windows(2) {
+            assert_eq!(pair[0].2.to_epoch_days() + 1, pair[1].1.to_epoch_days());
+        }
+        assert_eq!(IsoDate::quarters_in_range(2024, 2023).count(), 0);
+    }
+
+    #[test]
+    fn compare() {
+        use core::cmp::Ordering;
+
+        // A simple linear congruential generator for reproducible pairs of dates.
+        let mut state = 0x2545_f491_u64;
+        let mut next_date = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let epoch_days = (state >> 33) as i64 % 200_000_000 - 100_000_000;
+            IsoDate::from_epoch_days_unchecked(epoch_days as i32)
+        };
+        for _ in 0..12 {
+            let (one, two) = (next_date(), next_date());
+            assert_eq!(
+                IsoDate::compare(&one, &two),
+                IsoDate::compare(&two, &one).reverse()
+            );
+            assert_eq!(IsoDate::compare(&one, &one), Ordering::Equal);
+        }
+
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let next = IsoDate::new_unchecked(2024, 3, 16);
+        assert_eq!(IsoDate::compare(&date, &next), Ordering::Less);
+        assert_eq!(
+            IsoTime::compare(&IsoTime::noon(), &IsoTime::MIDNIGHT),
+            Ordering::Greater
+        );
+        assert_eq!(
+            IsoDateTime::compare(
+                &IsoDateTime::new_unchecked(date, IsoTime::noon()),
+                &IsoDateTime::new_unchecked(next, IsoTime::MIDNIGHT)
+            ),
+            Ordering::Less
+        );
+
+        let mut dates = [
+            next,
+            IsoDate::TEMPORAL_MAX,
+            date,
+            IsoDate::TEMPORAL_MIN,
+            date,
+        ];
+        IsoDate::sort_slice(&mut dates);
+        assert_eq!(
+            dates,
+            [
+                IsoDate::TEMPORAL_MIN,
+                date,
+                date,
+                next,
+                IsoDate::TEMPORAL_MAX
+            ]
+        );
+    }
+
+    #[test]
+    fn rfc3339_time_strings() {
+        use crate::parsers::Precision;
+
+        let time = IsoTime::new_unchecked(14, 30, 0, 0, 0, 0);
+        assert_eq!(time.to_rfc3339_time_string(None), "14:30:00");
+        assert_eq!(
+            time.to_rfc3339_time_string(Some(Precision::Minute)),
+            "14:30:00"
+        );
+        assert_eq!(
+            time.to_rfc3339_time_string(Some(Precision::Digit(3))),
+            "14:30:00.000"
+        );
+        assert_eq!(
+            time.to_rfc3339_offset_time_string(0).unwrap(),
+            "14:30:00+00:00"
+        );
+        assert_eq!(
+            time.to_rfc3339_offset_time_string(19_800).unwrap(),
+            "14:30:00+05:30"
+        );
+        assert_eq!(
+            time.to_rfc3339_offset_time_string(-34_200).unwrap(),
+            "14:30:00-09:30"
+        );
+        assert!(time.to_rfc3339_offset_time_string(30).is_err());
+        assert!(time.to_rfc3339_offset_time_string(86_400).is_err());
+
+        assert_eq!(IsoTime::from_rfc3339_time_string("14:30:00"), Ok(time));
+        assert_eq!(IsoTime::from_rfc3339_time_string("T14:30:00Z"), Ok(time));
+
+        let precise = IsoTime::new_unchecked(23, 59, 59, 123, 456, 789);
+        assert_eq!(
+            precise.to_rfc3339_time_string(Some(Precision::Digit(9))),
+            "23:59:59.123456789"
+        );
+        assert_eq!(
+            IsoTime::from_rfc3339_time_string("23:59:59.123456789"),
+            Ok(precise)
+        );
+        assert_eq!(
+            IsoTime::from_rfc3339_time_string("23:59:59.5"),
+            Ok(IsoTime::new_unchecked(23, 59, 59, 500, 0, 0))
+        );
+        assert_eq!(
+            IsoTime::from_rfc3339_time_string("23:59:60"),
+            Ok(IsoTime::new_unchecked(23, 59, 59, 0, 0, 0))
+        );
+
+        assert!(IsoTime::from_rfc3339_time_string("14:30").is_err());
+        assert!(IsoTime::from_rfc3339_time_string("14:30:00.").is_err());
+        assert!(IsoTime::from_rfc3339_time_string("14:30:00.1234567890").is_err());
+        assert!(IsoTime::from_rfc3339_time_string("24:00:00").is_err());
+    }
+
+    #[test]
+    fn date_display() {
+        use alloc::format;
+
+        let cases = [
+            (IsoDate::new_unchecked(2024, 3, 15), "2024-03-15"),
+            (IsoDate::new_unchecked(5, 1, 9), "0005-01-09"),
+            (IsoDate::new_unchecked(0, 1, 1), "0000-01-01"),
+            (IsoDate::new_unchecked(-1, 12, 31), "-000001-12-31"),
+            (IsoDate::new_unchecked(9999, 12, 31), "9999-12-31"),
+            (IsoDate::new_unchecked(10_000, 1, 1), "+010000-01-01"),
+            (IsoDate::TEMPORAL_MIN, "-271821-04-19"),
+            (IsoDate::TEMPORAL_MAX, "+275760-09-13"),
+        ];
+        for (date, expected) in cases {
+            assert_eq!(format!("{date}"), expected);
+        }
+    }
+
+    #[test]
+    fn time_display() {
+        use alloc::{format, string::ToString};
+
+        let cases = [
+            (IsoTime::MIDNIGHT, "00:00:00"),
+            (IsoTime::noon(), "12:00:00"),
+            (
+                IsoTime::new_unchecked(14, 30, 5, 1, 2, 3),
+                "14:30:05.001002003",
+            ),
+            (IsoTime::new_unchecked(14, 30, 5, 1, 0, 0), "14:30:05.001"),
+            (IsoTime::new_unchecked(14, 30, 5, 120, 0, 0), "14:30:05.12"),
+            (
+                IsoTime::new_unchecked(14, 30, 5, 0, 500, 0),
+                "14:30:05.0005",
+            ),
+            (
+                IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+                "23:59:59.999999999",
+            ),
+        ];
+        for (time, expected) in cases {
+            assert_eq!(format!("{time}"), expected);
+            assert_eq!(
+                IsoTime::from_rfc3339_time_string(&time.to_string()),
+                Ok(time)
+            );
+        }
+    }
+
+    #[test]
+    fn date_time_display() {
+        use alloc::format;
+
+        let cases = [
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::new_unchecked(2024, 3, 15),
+                    IsoTime::new_unchecked(14, 30, 5, 1, 2, 3),
+                ),
+                "2024-03-15T14:30:05.001002003",
+            ),
+            (
+                IsoDateTime::new_unchecked(IsoDate::EPOCH, IsoTime::MIDNIGHT),
+                "1970-01-01T00:00:00",
+            ),
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::TEMPORAL_MIN,
+                    IsoTime::new_unchecked(0, 0, 0, 0, 0, 1),
+                ),
+                "-271821-04-19T00:00:00.000000001",
+            ),
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::TEMPORAL_MAX,
+                    IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+                ),
+                "+275760-09-13T23:59:59.999999999",
+            ),
+        ];
+        for (date_time, expected) in cases {
+            assert_eq!(format!("{date_time}"), expected);
+            assert_eq!(
+                format!("{date_time}"),
+                date_time.to_iso_string_with_calendar("iso8601", None)
+            );
+        }
+    }
+
+    #[test]
+    fn date_from_str() {
+        use crate::error::ErrorKind;
+        use alloc::string::ToString;
+
+        let cases = [
+            ("2024-03-15", IsoDate::new_unchecked(2024, 3, 15)),
+            ("2024-02-29", IsoDate::new_unchecked(2024, 2, 29)),
+            ("0000-01-01", IsoDate::new_unchecked(0, 1, 1)),
+            ("+010000-01-01", IsoDate::new_unchecked(10_000, 1, 1)),
+            ("-000500-06-30", IsoDate::new_unchecked(-500, 6, 30)),
+            ("-271821-04-19", IsoDate::TEMPORAL_MIN),
+            ("+275760-09-13", IsoDate::TEMPORAL_MAX),
+            ("20240315", IsoDate::new_unchecked(2024, 3, 15)),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(s.parse::<IsoDate>(), Ok(expected));
+            assert_eq!(expected.to_string().parse::<IsoDate>(), Ok(expected));
+        }
+
+        for s in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-04-31",
+            "-271821-04-18",
+            "+275760-09-14",
+        ] {
+            assert_eq!(s.parse::<IsoDate>().unwrap_err().kind(), ErrorKind::Range);
+        }
+        for s in [
+            "",
+            "2024",
+            "2024-3-15",
+            "2024-03-15T00:00",
+            "2024-03-15T00:00+01:00",
+            "2024-03-15[u-ca=iso8601]",
+            "2024-03-15[foo=bar]",
+            "10000-01-01",
+            "-000000-01-01",
+            "+2024-03-15",
+            "2024-0a-15",
+        ] {
+            assert_eq!(s.parse::<IsoDate>().unwrap_err().kind(), ErrorKind::Syntax);
+        }
+    }
+
+    #[test]
+    fn time_from_str() {
+        use crate::error::ErrorKind;
+        use alloc::string::ToString;
+
+        let cases = [
+            ("00:00:00", IsoTime::MIDNIGHT),
+            ("12:00:00", IsoTime::noon()),
+            ("14:30:05", IsoTime::new_unchecked(14, 30, 5, 0, 0, 0)),
+            ("14:30:05.123", IsoTime::new_unchecked(14, 30, 5, 123, 0, 0)),
+            (
+                "14:30:05.1234",
+                IsoTime::new_unchecked(14, 30, 5, 123, 400, 0),
+            ),
+            (
+                "14:30:05.123456789",
+                IsoTime::new_unchecked(14, 30, 5, 123, 456, 789),
+            ),
+            (
+                "23:59:59.999999999",
+                IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(s.parse::<IsoTime>(), Ok(expected));
+            assert_eq!(expected.to_string().parse::<IsoTime>(), Ok(expected));
+        }
+
+        for s in ["24:00:00", "25:00:00", "12:60:00", "23:59:60"] {
+            assert_eq!(s.parse::<IsoTime>().unwrap_err().kind(), ErrorKind::Range);
+        }
+        for s in [
+            "",
+            "14:30",
+            "T14:30:05",
+            "14:30:05Z",
+            "14:30:05.",
+            "143005",
+            "14:30:05.1234567890",
+        ] {
+            assert_eq!(s.parse::<IsoTime>().unwrap_err().kind(), ErrorKind::Syntax);
+        }
+    }
+
+    #[test]
+    fn date_time_from_str() {
+        use crate::error::ErrorKind;
+        use alloc::string::ToString;
+
+        let cases = [
+            (
+                "2024-03-15T14:30:05.001002003",
+                IsoDateTime::new_unchecked(
+                    IsoDate::new_unchecked(2024, 3, 15),
+                    IsoTime::new_unchecked(14, 30, 5, 1, 2, 3),
+                ),
+            ),
+            (
+                "1970-01-01t00:00:00Z",
+                IsoDateTime::new_unchecked(IsoDate::EPOCH, IsoTime::MIDNIGHT),
+            ),
+            (
+                "+010000-01-01T12:00:00",
+                IsoDateTime::new_unchecked(IsoDate::new_unchecked(10_000, 1, 1), IsoTime::noon()),
+            ),
+            (
+                "-271821-04-19T00:00:00.000000001",
+                IsoDateTime::new_unchecked(
+                    IsoDate::TEMPORAL_MIN,
+                    IsoTime::new_unchecked(0, 0, 0, 0, 0, 1),
+                ),
+            ),
+            (
+                "+275760-09-13T23:59:59.999999999",
+                IsoDateTime::new_unchecked(
+                    IsoDate::TEMPORAL_MAX,
+                    IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+                ),
+            ),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(s.parse::<IsoDateTime>(), Ok(expected));
+            assert_eq!(expected.to_string().parse::<IsoDateTime>(), Ok(expected));
+        }
+
+        for s in [
+            "-271821-04-19T00:00:00",
+            "+275760-09-14T00:00:00",
+            "2024-02-30T00:00:00",
+        ] {
+            assert_eq!(
+                s.parse::<IsoDateTime>().unwrap_err().kind(),
+                ErrorKind::Range
+            );
+        }
+        for s in [
+            "2024-03-15",
+            "2024-03-15T",
+            "2024-03-15 14:30:05",
+            "2024-03-15T14:30:05+01:00",
+            "2024-03-15T14:30:05[u-ca=iso8601]",
+        ] {
+            assert_eq!(
+                s.parse::<IsoDateTime>().unwrap_err().kind(),
+                ErrorKind::Syntax
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map_keys() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let date = IsoDate::new_unchecked(2024, 3, 15);
+        let parsed: IsoDate = "2024-03-15".parse().unwrap();
+        assert_eq!(hash_of(&date), hash_of(&parsed));
+
+        let mut events = HashMap::new();
+        events.insert(date, "launch");
+        events.insert(IsoDate::EPOCH, "epoch");
+        assert_eq!(events.get(&parsed), Some(&"launch"));
+        assert_eq!(
+            events.get(&IsoDate::new_unchecked(1970, 1, 1)),
+            Some(&"epoch")
+        );
+        assert_eq!(events.get(&IsoDate::new_unchecked(2024, 3, 16)), None);
+
+        let times: HashSet<IsoTime> = [IsoTime::noon(), IsoTime::MIDNIGHT, IsoTime::noon()]
+            .into_iter()
+            .collect();
+        assert_eq!(times.len(), 2);
+
+        let date_time = IsoDateTime::new_unchecked(date, IsoTime::noon());
+        let date_times: HashSet<IsoDateTime> = [date_time, "2024-03-15T12:00:00".parse().unwrap()]
+            .into_iter()
+            .collect();
+        assert_eq!(date_times.len(), 1);
+    }
+
+    #[test]
+    fn date_time_ordering() {
+        let base = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 3, 15),
+            IsoTime::new_unchecked(14, 30, 5, 1, 2, 3),
+        );
+        let later = [
+            IsoDateTime::new_unchecked(IsoDate::new_unchecked(2025, 1, 1), IsoTime::MIDNIGHT),
+            IsoDateTime::new_unchecked(IsoDate::new_unchecked(2024, 4, 1), IsoTime::MIDNIGHT),
+            IsoDateTime::new_unchecked(IsoDate::new_unchecked(2024, 3, 16), IsoTime::MIDNIGHT),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(15, 0, 0, 0, 0, 0)),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(14, 31, 0, 0, 0, 0)),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(14, 30, 6, 0, 0, 0)),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(14, 30, 5, 2, 0, 0)),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(14, 30, 5, 1, 3, 0)),
+            IsoDateTime::new_unchecked(base.date, IsoTime::new_unchecked(14, 30, 5, 1, 2, 4)),
+        ];
+        for date_time in later {
+            assert!(base < date_time);
+            assert!(date_time > base);
+            assert_ne!(base, date_time);
         }
+        let copy = base;
+        assert_eq!(base, copy);
+        assert_eq!(base.partial_cmp(&copy), Some(core::cmp::Ordering::Equal));
+    }
 
-        let min_sec = 0..=59;
-        if !min_sec.contains(&self.minute) || !min_sec.contains(&self.second) {
-            return false;
+    #[test]
+    fn date_days_in_month() {
+        let leap = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        let common = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        for (month, (leap_days, common_days)) in (1..=12).zip(leap.into_iter().zip(common)) {
+            assert_eq!(
+                IsoDate::new_unchecked(2024, month, 1).days_in_month(),
+                leap_days
+            );
+            assert_eq!(
+                IsoDate::new_unchecked(2023, month, 1).days_in_month(),
+                common_days
+            );
         }
-
-        let sub_second = 0..=999;
-        sub_second.contains(&self.millisecond)
-            && sub_second.contains(&self.microsecond)
-            && sub_second.contains(&self.nanosecond)
+        assert_eq!(IsoDate::new_unchecked(2000, 2, 1).days_in_month(), 29);
+        assert_eq!(IsoDate::new_unchecked(1900, 2, 1).days_in_month(), 28);
     }
 
-    pub(crate) fn add(&self, norm: NormalizedTimeDuration) -> (i64, Self) {
-        // 1. Set second to second + NormalizedTimeDurationSeconds(norm).
-        let seconds = i64::from(self.second) + norm.seconds();
-        // 2. Set nanosecond to nanosecond + NormalizedTimeDurationSubseconds(norm).
-        let nanos = i32::from(self.nanosecond) + norm.subseconds();
-        // 3. Return BalanceTime(hour, minute, second, millisecond, microsecond, nanosecond).
-        Self::balance(
-            self.hour.into(),
-            self.minute.into(),
-            seconds,
-            self.millisecond.into(),
-            self.microsecond.into(),
-            nanos.into(),
-        )
+    #[test]
+    fn date_is_leap_year() {
+        for (year, expected) in [
+            (2000, true),
+            (1900, false),
+            (2024, true),
+            (2023, false),
+            (0, true),
+            (-1, false),
+            (-4, true),
+        ] {
+            assert_eq!(IsoDate::new_unchecked(year, 1, 1).is_leap_year(), expected);
+        }
     }
 
-    /// `IsoTimeToEpochMs`
-    ///
-    /// Note: This method is library specific and not in spec
-    ///
-    /// Functionally the same as Date's `MakeTime`
-    pub(crate) fn to_epoch_ms(self) -> i64 {
-        ((i64::from(self.hour) * utils::MS_PER_HOUR
-            + i64::from(self.minute) * utils::MS_PER_MINUTE)
-            + i64::from(self.second) * 1000i64)
-            + i64::from(self.millisecond)
+    #[test]
+    fn date_ordinals() {
+        let cases = [
+            (IsoDate::new_unchecked(2024, 1, 1), 1),
+            (IsoDate::new_unchecked(2024, 2, 29), 60),
+            (IsoDate::new_unchecked(2024, 3, 1), 61),
+            (IsoDate::new_unchecked(2023, 3, 1), 60),
+            (IsoDate::new_unchecked(2024, 12, 31), 366),
+            (IsoDate::new_unchecked(2023, 12, 31), 365),
+            (IsoDate::new_unchecked(-1, 12, 31), 365),
+            (IsoDate::TEMPORAL_MIN, 109),
+            (IsoDate::TEMPORAL_MAX, 257),
+        ];
+        for (date, ordinal) in cases {
+            assert_eq!(date.day_of_year(), ordinal);
+            assert_eq!(IsoDate::from_ordinal(date.year, ordinal), Ok(date));
+        }
+
+        assert!(IsoDate::from_ordinal(2024, 0).is_err());
+        assert!(IsoDate::from_ordinal(2024, 367).is_err());
+        assert!(IsoDate::from_ordinal(2023, 366).is_err());
+        assert!(IsoDate::from_ordinal(-271_821, 108).is_err());
+        assert!(IsoDate::from_ordinal(275_760, 258).is_err());
     }
-}
 
-// ==== `IsoDateTime` specific utility functions ====
+    #[test]
+    fn date_day_of_week() {
+        use super::DayOfWeek;
 
-const MAX_EPOCH_DAYS: i64 = 10i64.pow(8) + 1;
+        let cases = [
+            (IsoDate::EPOCH, DayOfWeek::Thursday),
+            (IsoDate::new_unchecked(2024, 3, 4), DayOfWeek::Monday),
+            (IsoDate::new_unchecked(2024, 3, 10), DayOfWeek::Sunday),
+            (IsoDate::new_unchecked(2000, 1, 1), DayOfWeek::Saturday),
+            (IsoDate::new_unchecked(1969, 12, 31), DayOfWeek::Wednesday),
+            (IsoDate::new_unchecked(0, 1, 1), DayOfWeek::Saturday),
+        ];
+        for (date, weekday) in cases {
+            assert_eq!(date.weekday(), weekday);
+            assert_eq!(date.day_of_week(), weekday as u8);
+        }
 
-#[inline]
-/// Utility function to determine if a `DateTime`'s components create a `DateTime` within valid limits
-fn iso_dt_within_valid_limits(date: IsoDate, time: &IsoTime) -> bool {
-    if utils::epoch_days_from_gregorian_date(date.year, date.month, date.day).abs() > MAX_EPOCH_DAYS
-    {
-        return false;
+        assert_eq!(DayOfWeek::from_iso_number(0), None);
+        assert_eq!(DayOfWeek::from_iso_number(8), None);
+        for day in 1..=7 {
+            assert_eq!(DayOfWeek::from_iso_number(day).map(|d| d as u8), Some(day));
+        }
     }
 
-    let ns = to_unchecked_epoch_nanoseconds(date, time);
-    let max = crate::NS_MAX_INSTANT + i128::from(NS_PER_DAY);
-    let min = crate::NS_MIN_INSTANT - i128::from(NS_PER_DAY);
-
-    min < ns && max > ns
-}
+    #[test]
+    fn date_week_of_year() {
+        let cases = [
+            (IsoDate::new_unchecked(2015, 1, 1), 2015, 1, 4),
+            (IsoDate::new_unchecked(2019, 12, 30), 2020, 1, 1),
+            (IsoDate::new_unchecked(2020, 1, 1), 2020, 1, 3),
+            (IsoDate::new_unchecked(2021, 1, 3), 2020, 53, 7),
+            (IsoDate::new_unchecked(2016, 1, 1), 2015, 53, 5),
+            (IsoDate::new_unchecked(2024, 3, 15), 2024, 11, 5),
+            (IsoDate::new_unchecked(2024, 12, 29), 2024, 52, 7),
+            (IsoDate::new_unchecked(2024, 12, 30), 2025, 1, 1),
+        ];
+        for (date, week_year, week, day) in cases {
+            assert_eq!(date.year_of_week(), week_year);
+            assert_eq!(date.week_of_year(), week);
+            assert_eq!(date.day_of_week(), day);
+            assert_eq!(IsoDate::from_iso_week_date(week_year, week, day), Ok(date));
+        }
 
-#[inline]
-/// Utility function to convert a `IsoDate` and `IsoTime` values into epoch nanoseconds
-fn utc_epoch_nanos(date: IsoDate, time: &IsoTime) -> TemporalResult<EpochNanoseconds> {
-    let epoch_nanos = to_unchecked_epoch_nanoseconds(date, time);
-    EpochNanoseconds::try_from(epoch_nanos)
-}
+        assert!(IsoDate::from_iso_week_date(2024, 1, 0).is_err());
+        assert!(IsoDate::from_iso_week_date(2024, 1, 8).is_err());
+        assert!(IsoDate::from_iso_week_date(2024, 53, 1).is_err());
+    }
 
-#[inline]
-fn to_unchecked_epoch_nanoseconds(date: IsoDate, time: &IsoTime) -> i128 {
-    let ms = time.to_epoch_ms();
-    let epoch_ms = utils::epoch_days_to_epoch_ms(date.to_epoch_days() as i64, ms);
-    epoch_ms as i128 * 1_000_000 + time.microsecond as i128 * 1_000 + time.nanosecond as i128
-}
+    #[test]
+    fn date_from_epoch_days() {
+        let cases = [
+            (0, IsoDate::EPOCH),
+            (-1, IsoDate::new_unchecked(1969, 12, 31)),
+            (-719_528, IsoDate::new_unchecked(0, 1, 1)),
+            (19_797, IsoDate::new_unchecked(2024, 3, 15)),
+            (-100_000_001, IsoDate::TEMPORAL_MIN),
+            (100_000_000, IsoDate::TEMPORAL_MAX),
+        ];
+        for (epoch_days, date) in cases {
+            assert_eq!(IsoDate::from_epoch_days(epoch_days), Ok(date));
+            assert_eq!(date.to_epoch_days(), epoch_days);
+        }
 
-// ==== `IsoDate` specific utiltiy functions ====
+        assert!(IsoDate::from_epoch_days(-100_000_002).is_err());
+        assert!(IsoDate::from_epoch_days(100_000_001).is_err());
+        assert!(IsoDate::from_epoch_days(i32::MIN).is_err());
+    }
 
-/// Returns the Epoch days based off the given year, month, and day.
-/// Note: Month should be 1 indexed
-#[inline]
-pub(crate) fn iso_date_to_epoch_days(year: i32, month: i32, day: i32) -> i64 {
-    // 1. Let resolvedYear be year + floor(month / 12).
-    let resolved_year = year + month.div_euclid(12);
-    // 2. Let resolvedMonth be month modulo 12.
-    let resolved_month = month.rem_euclid(12) as u8;
-    // 3. Find a time t such that EpochTimeToEpochYear(t) is resolvedYear,
-    // EpochTimeToMonthInYear(t) is resolvedMonth, and EpochTimeToDate(t) is 1.
-    let epoch_days = utils::epoch_days_from_gregorian_date(resolved_year, resolved_month, 1);
+    #[test]
+    fn date_time_epoch_nanos_round_trip() {
+        use crate::{unix_time::EpochNanoseconds, NS_MAX_INSTANT, NS_MIN_INSTANT};
 
-    // 4. Return EpochTimeToDayNumber(t) + date - 1.
-    epoch_days + day as i64 - 1
-}
+        for ns in [
+            0,
+            -1,
+            1_710_513_005_001_002_003,
+            -86_400_000_000_001,
+            NS_MIN_INSTANT,
+            NS_MAX_INSTANT,
+        ] {
+            let epoch_nanoseconds = EpochNanoseconds::try_from(ns).unwrap();
+            let date_time = IsoDateTime::from_epoch_nanos(&epoch_nanoseconds, 0).unwrap();
+            assert_eq!(date_time.to_epoch_nanos(), Ok(epoch_nanoseconds));
+            assert_eq!(
+                IsoDateTime::from_epoch_nanos(&date_time.to_epoch_nanos().unwrap(), 0),
+                Ok(date_time)
+            );
+        }
 
-#[inline]
-// Determines if the month and day are valid for the given year.
-fn is_valid_date(year: i32, month: u8, day: u8) -> bool {
-    if !(1..=12).contains(&month) {
-        return false;
+        // The earliest valid `IsoDateTime` is a day before the earliest `Instant`.
+        let earliest = IsoDateTime::new_unchecked(
+            IsoDate::TEMPORAL_MIN,
+            IsoTime::new_unchecked(0, 0, 0, 0, 0, 1),
+        );
+        assert!(earliest.to_epoch_nanos().is_err());
     }
-    is_valid_iso_day(year, month, day)
-}
 
-#[inline]
-/// Returns with the `this` surpasses `other`.
-fn iso_date_surpasses(this: &IsoDate, other: &IsoDate, sign: i8) -> bool {
-    this.cmp(other) as i8 * sign == 1
-}
+    #[test]
+    fn date_time_epoch_millis_round_trip() {
+        let cases = [
+            (
+                IsoDateTime::new_unchecked(IsoDate::EPOCH, IsoTime::MIDNIGHT),
+                0.0,
+            ),
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::new_unchecked(2024, 3, 15),
+                    IsoTime::new_unchecked(14, 30, 5, 1, 0, 0),
+                ),
+                1_710_513_005_001.0,
+            ),
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::new_unchecked(1969, 12, 31),
+                    IsoTime::new_unchecked(23, 59, 59, 999, 0, 0),
+                ),
+                -1.0,
+            ),
+            (
+                IsoDateTime::new_unchecked(
+                    IsoDate::new_unchecked(-271_821, 4, 20),
+                    IsoTime::MIDNIGHT,
+                ),
+                -8.64e15,
+            ),
+            (
+                IsoDateTime::new_unchecked(IsoDate::TEMPORAL_MAX, IsoTime::MIDNIGHT),
+                8.64e15,
+            ),
+        ];
+        for (date_time, millis) in cases {
+            assert_eq!(date_time.to_epoch_millis(), millis);
+            assert_eq!(IsoDateTime::from_epoch_millis(millis), Ok(date_time));
+        }
 
-#[inline]
-pub(crate) fn year_month_within_limits(year: i32, month: u8) -> bool {
-    // 1. If isoDate.[[Year]] < -271821 or isoDate.[[Year]] > 275760, then
-    if !(-271821..=275760).contains(&year) {
-        // a. Return false.
-        return false;
-    // 2. If isoDate.[[Year]] = -271821 and isoDate.[[Month]] < 4, then
-    } else if year == -271821 && month < 4 {
-        // a. Return false.
-        return false;
-    // 3. If isoDate.[[Year]] = 275760 and isoDate.[[Month]] > 9, then
-    } else if year == 275760 && month > 9 {
-        // a. Return false.
-        return false;
+        let precise =
+            IsoDateTime::new_unchecked(IsoDate::EPOCH, IsoTime::new_unchecked(0, 0, 0, 1, 2, 3));
+        assert_eq!(precise.to_epoch_millis(), 1.0);
+
+        for millis in [0.5, f64::NAN, f64::INFINITY, 8.64e15 + 1.0, -8.64e15 - 1.0] {
+            assert!(IsoDateTime::from_epoch_millis(millis).is_err());
+        }
     }
-    // 4. Return true.
-    true
-}
 
-fn balance_iso_year_month_with_clamp(year: i64, month: i64) -> (i32, u8) {
-    // 1. Assert: year and month are integers.
-    // 2. Set year to year + floor((month - 1) / 12).
-    let y = year + (month - 1).div_euclid(12);
-    // 3. Set month to ((month - 1) modulo 12) + 1.
-    let m = (month - 1).rem_euclid(12) + 1;
-    // 4. Return the Record { [[Year]]: year, [[Month]]: month  }.
-    (y.clamp(i32::MIN as i64, i32::MAX as i64) as i32, m as u8)
-}
+    #[test]
+    fn date_time_epoch_seconds() {
+        let epoch = IsoDateTime::new_unchecked(IsoDate::EPOCH, IsoTime::MIDNIGHT);
+        assert_eq!(epoch.to_epoch_seconds(), 0.0);
+        assert_eq!(IsoDateTime::from_epoch_seconds(0.0), Ok(epoch));
 
-#[inline]
-fn balance_iso_year_month(year: i32, month: i32) -> (i32, u8) {
-    // 1. Assert: year and month are integers.
-    // 2. Set year to year + floor((month - 1) / 12).
-    let y = year + (month - 1).div_euclid(12);
-    // 3. Set month to ((month - 1) modulo 12) + 1.
-    let m = (month - 1).rem_euclid(12) + 1;
-    // 4. Return the Record { [[Year]]: year, [[Month]]: month  }.
-    (y, m as u8)
-}
+        let y2k38 = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2038, 1, 19),
+            IsoTime::new_unchecked(3, 14, 7, 0, 0, 0),
+        );
+        assert_eq!(y2k38.to_epoch_seconds(), 2_147_483_647.0);
+        assert_eq!(IsoDateTime::from_epoch_seconds(2_147_483_647.0), Ok(y2k38));
+        assert_eq!(
+            IsoDateTime::from_epoch_seconds(2_147_483_648.0),
+            Ok(IsoDateTime::new_unchecked(
+                y2k38.date,
+                IsoTime::new_unchecked(3, 14, 8, 0, 0, 0)
+            ))
+        );
 
-/// Note: month is 1 based.
-#[inline]
-pub(crate) fn constrain_iso_day(year: i32, month: u8, day: u8) -> u8 {
-    let days_in_month = utils::iso_days_in_month(year, month);
-    day.clamp(1, days_in_month)
-}
+        let before_epoch = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(1969, 12, 31),
+            IsoTime::new_unchecked(23, 59, 59, 500, 0, 0),
+        );
+        assert_eq!(before_epoch.to_epoch_seconds(), -0.5);
+        assert_eq!(
+            IsoDateTime::from_epoch_seconds(-0.5),
+            Ok(IsoDateTime::new_unchecked(
+                before_epoch.date,
+                IsoTime::new_unchecked(23, 59, 59, 0, 0, 0)
+            ))
+        );
+        assert_eq!(
+            IsoDateTime::from_epoch_seconds(1.75),
+            Ok(IsoDateTime::new_unchecked(
+                IsoDate::EPOCH,
+                IsoTime::new_unchecked(0, 0, 1, 0, 0, 0)
+            ))
+        );
 
-#[inline]
-pub(crate) fn is_valid_iso_day(year: i32, month: u8, day: u8) -> bool {
-    let days_in_month = utils::iso_days_in_month(year, month);
-    (1..=days_in_month).contains(&day)
-}
+        assert!(IsoDateTime::from_epoch_seconds(f64::NAN).is_err());
+        assert!(IsoDateTime::from_epoch_seconds(8.64e12 + 1.0).is_err());
+    }
 
-// ==== `IsoTime` specific utilities ====
+    #[test]
+    fn time_total_nanoseconds() {
+        let cases = [
+            (IsoTime::MIDNIGHT, 0),
+            (IsoTime::new_unchecked(0, 0, 0, 0, 0, 999), 999),
+            (IsoTime::new_unchecked(0, 0, 0, 0, 999, 0), 999_000),
+            (IsoTime::new_unchecked(0, 0, 0, 999, 0, 0), 999_000_000),
+            (IsoTime::new_unchecked(0, 0, 59, 0, 0, 0), 59_000_000_000),
+            (IsoTime::new_unchecked(0, 59, 0, 0, 0, 0), 3_540_000_000_000),
+            (
+                IsoTime::new_unchecked(23, 0, 0, 0, 0, 0),
+                82_800_000_000_000,
+            ),
+            (IsoTime::noon(), 43_200_000_000_000),
+            (
+                IsoTime::new_unchecked(23, 59, 59, 999, 999, 999),
+                86_399_999_999_999,
+            ),
+        ];
+        for (time, ns) in cases {
+            assert_eq!(time.to_total_nanoseconds(), ns);
+            assert_eq!(IsoTime::from_total_nanoseconds(ns), Ok(time));
+        }
 
-#[inline]
-fn is_valid_time(hour: u8, minute: u8, second: u8, ms: u16, mis: u16, ns: u16) -> bool {
-    if !(0..=23).contains(&hour) {
-        return false;
+        assert!(IsoTime::from_total_nanoseconds(86_400_000_000_000).is_err());
+        assert!(IsoTime::from_total_nanoseconds(u64::MAX).is_err());
     }
 
-    let min_sec = 0..=59;
-    if !min_sec.contains(&minute) || !min_sec.contains(&second) {
-        return false;
+    #[test]
+    fn date_until_and_since_units() {
+        let earlier = IsoDate::new_unchecked(2023, 1, 15);
+        let later = IsoDate::new_unchecked(2024, 3, 20);
+        let cases = [
+            (Unit::Year, DateDuration::new(1, 2, 0, 5).unwrap()),
+            (Unit::Month, DateDuration::new(0, 14, 0, 5).unwrap()),
+            (Unit::Week, DateDuration::new(0, 0, 61, 3).unwrap()),
+            (Unit::Day, DateDuration::new(0, 0, 0, 430).unwrap()),
+        ];
+        for (unit, expected) in cases {
+            assert_eq!(earlier.until(&later, unit), Ok(expected));
+            assert_eq!(later.since(&earlier, unit), Ok(expected));
+            assert_eq!(later.until(&earlier, unit), Ok(expected.negated()));
+            assert_eq!(earlier.since(&later, unit), Ok(expected.negated()));
+            assert_eq!(earlier.until(&earlier, unit), Ok(DateDuration::default()));
+            assert_eq!(earlier.since(&earlier, unit), Ok(DateDuration::default()));
+        }
+
+        for unit in [Unit::Hour, Unit::Minute, Unit::Second, Unit::Nanosecond] {
+            assert!(earlier.until(&later, unit).is_err());
+            assert!(earlier.since(&later, unit).is_err());
+        }
     }
 
-    let sub_second = 0..=999;
-    sub_second.contains(&ms) && sub_second.contains(&mis) && sub_second.contains(&ns)
-}
+    #[test]
+    fn date_add_and_subtract() {
+        let date = IsoDate::new_unchecked(2024, 11, 30);
+        let cases = [
+            (
+                DateDuration::new(0, 2, 0, 0),
+                IsoDate::new_unchecked(2025, 1, 30),
+            ),
+            (
+                DateDuration::new(1, 14, 0, 0),
+                IsoDate::new_unchecked(2027, 1, 30),
+            ),
+            (
+                DateDuration::new(0, 0, 0, 32),
+                IsoDate::new_unchecked(2025, 1, 1),
+            ),
+            (
+                DateDuration::new(0, 0, 5, 0),
+                IsoDate::new_unchecked(2025, 1, 4),
+            ),
+            (DateDuration::new(0, 0, 0, 0), date),
+        ];
+        for (duration, expected) in cases {
+            let duration = duration.unwrap();
+            assert_eq!(
+                date.add(&duration, ArithmeticOverflow::Reject),
+                Ok(expected)
+            );
+            assert_eq!(
+                expected.subtract(&duration, ArithmeticOverflow::Reject),
+                Ok(date)
+            );
+        }
 
-// NOTE(nekevss): Considering the below: Balance can probably be altered from f64.
-#[inline]
-fn div_mod(dividend: i64, divisor: i64) -> (i64, i64) {
-    (dividend.div_euclid(divisor), dividend.rem_euclid(divisor))
-}
+        let january = IsoDate::new_unchecked(2024, 1, 31);
+        let one_month = DateDuration::new(0, 1, 0, 0).unwrap();
+        assert_eq!(
+            january.add(&one_month, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
+        assert!(january.add(&one_month, ArithmeticOverflow::Reject).is_err());
+        assert_eq!(
+            IsoDate::new_unchecked(2024, 3, 31).subtract(&one_month, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 2, 29))
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::{iso_date_to_epoch_days, IsoDate};
+        // Weeks and days are added after the day has been regulated.
+        let month_and_day = DateDuration::new(0, 1, 0, 1).unwrap();
+        assert_eq!(
+            january.add(&month_and_day, ArithmeticOverflow::Constrain),
+            Ok(IsoDate::new_unchecked(2024, 3, 1))
+        );
 
-    const MAX_DAYS_BASE: i64 = 100_000_000;
+        let days = DateDuration::new(0, 0, 0, 1).unwrap();
+        assert!(IsoDate::TEMPORAL_MAX
+            .add(&days, ArithmeticOverflow::Constrain)
+            .is_err());
+        assert!(IsoDate::TEMPORAL_MIN
+            .subtract(&days, ArithmeticOverflow::Constrain)
+            .is_err());
+    }
 
     #[test]
-    fn icu4x_max_conversion_test() {
-        // Test that the max ISO date does not panic on conversion
-        let _ = IsoDate::new_unchecked(275_760, 9, 13).to_icu4x();
-        // Test that the min ISO date does not panic on conversion
-        let _ = IsoDate::new_unchecked(-271_821, 4, 20).to_icu4x();
+    fn test_month_limits() {
+        assert_eq!(iso_date_epoch_days(1970, 1, 1), 0);
+        assert_eq!(iso_date_epoch_days(1969, 12, 31), -1);
     }
 
     #[test]
-    fn iso_date_to_epoch_days_limits() {
-        // Succeeds
-        assert_eq!(iso_date_to_epoch_days(-271_821, 4, 20).abs(), MAX_DAYS_BASE);
-        // Succeeds
+    fn time_until_and_since_with_settings() {
+        use crate::options::{DifferenceSettings, RoundingIncrement, RoundingMode};
+
+        let start = IsoTime::new_unchecked(10, 0, 0, 0, 0, 0);
+        let end = IsoTime::new_unchecked(12, 34, 56, 789, 0, 0);
+
         assert_eq!(
-            iso_date_to_epoch_days(-271_821, 4, 19).abs(),
-            MAX_DAYS_BASE + 1
+            start.until_with_settings(&end, DifferenceSettings::default()),
+            start.until(&end, Unit::Auto)
         );
-        // Fails
         assert_eq!(
-            iso_date_to_epoch_days(-271_821, 4, 18).abs(),
-            MAX_DAYS_BASE + 2
+            end.since_with_settings(&start, DifferenceSettings::default()),
+            end.since(&start, Unit::Auto)
         );
-        // Succeeds
-        assert_eq!(iso_date_to_epoch_days(275_760, 9, 13).abs(), MAX_DAYS_BASE);
-        // Succeeds
+
+        let minutes = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Minute)
+            .build();
         assert_eq!(
-            iso_date_to_epoch_days(275_760, 9, 14).abs(),
-            MAX_DAYS_BASE + 1
+            start.until_with_settings(&end, minutes),
+            TimeDuration::new(2, 34, 0, 0, 0, 0)
         );
-        // Fails
         assert_eq!(
-            iso_date_to_epoch_days(275_760, 9, 15).abs(),
-            MAX_DAYS_BASE + 2
+            end.since_with_settings(&start, minutes),
+            TimeDuration::new(2, 34, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            end.until_with_settings(&start, minutes),
+            TimeDuration::new(-2, -34, 0, 0, 0, 0)
         );
-    }
 
-    #[test]
-    fn test_month_limits() {
-        assert_eq!(iso_date_to_epoch_days(1970, 1, 1), 0);
-        assert_eq!(iso_date_to_epoch_days(1969, 12, 31), -1);
+        let half_expand = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Minute)
+            .with_rounding_mode(RoundingMode::HalfExpand)
+            .build();
+        assert_eq!(
+            start.until_with_settings(&end, half_expand),
+            TimeDuration::new(2, 35, 0, 0, 0, 0)
+        );
+
+        let quarter_hours = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Minute)
+            .with_rounding_mode(RoundingMode::HalfExpand)
+            .with_increment(RoundingIncrement::try_new(15).unwrap())
+            .build();
+        assert_eq!(
+            start.until_with_settings(&end, quarter_hours),
+            TimeDuration::new(2, 30, 0, 0, 0, 0)
+        );
+
+        // `since` negates the rounding mode, so `Ceil` still rounds away from zero.
+        let ceil_hours = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Hour)
+            .with_rounding_mode(RoundingMode::Ceil)
+            .build();
+        assert_eq!(
+            start.until_with_settings(&end, ceil_hours),
+            TimeDuration::new(3, 0, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            end.since_with_settings(&start, ceil_hours),
+            TimeDuration::new(3, 0, 0, 0, 0, 0)
+        );
+
+        let largest_minute = DifferenceSettings::builder()
+            .with_largest_unit(Unit::Minute)
+            .with_smallest_unit(Unit::Second)
+            .build();
+        assert_eq!(
+            start.until_with_settings(&end, largest_minute),
+            TimeDuration::new(0, 154, 56, 0, 0, 0)
+        );
+
+        // Differences never wrap around midnight.
+        let late = IsoTime::new_unchecked(23, 0, 0, 0, 0, 0);
+        let early = IsoTime::new_unchecked(1, 0, 0, 0, 0, 0);
+        assert_eq!(
+            late.until_with_settings(&early, DifferenceSettings::default()),
+            TimeDuration::new(-22, 0, 0, 0, 0, 0)
+        );
+
+        let days = DifferenceSettings::builder()
+            .with_smallest_unit(Unit::Day)
+            .build();
+        assert!(start.until_with_settings(&end, days).is_err());
     }
 }
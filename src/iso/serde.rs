@@ -0,0 +1,239 @@
+//! `serde` support for the ISO types.
+//!
+//! Human-readable formats use the ISO 8601 strings, while other formats use compact
+//! tuples of the fields: `(year, month, day)` for `IsoDate`, `(hour, minute, second,
+//! millisecond, microsecond, nanosecond)` for `IsoTime`, and `(date, time)` for
+//! `IsoDateTime`.
+
+use alloc::string::ToString;
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{IsoDate, IsoDateTime, IsoTime};
+use crate::options::ArithmeticOverflow;
+
+impl Serialize for IsoDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_iso_string())
+        } else {
+            (self.year, self.month, self.day).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(FromStrVisitor::new("an ISO 8601 date string"));
+        }
+        let (year, month, day) = <(i32, u8, u8)>::deserialize(deserializer)?;
+        Self::new_with_overflow(year, month, day, ArithmeticOverflow::Reject)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for IsoTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            (
+                self.hour,
+                self.minute,
+                self.second,
+                self.millisecond,
+                self.microsecond,
+                self.nanosecond,
+            )
+                .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(FromStrVisitor::new("an ISO 8601 time string"));
+        }
+        let (hour, minute, second, millisecond, microsecond, nanosecond) =
+            <(u8, u8, u8, u16, u16, u16)>::deserialize(deserializer)?;
+        Self::new(
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+            ArithmeticOverflow::Reject,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for IsoDateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            (self.date, self.time).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoDateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return deserializer
+                .deserialize_str(FromStrVisitor::new("an ISO 8601 date-time string"));
+        }
+        let (date, time) = <(IsoDate, IsoTime)>::deserialize(deserializer)?;
+        Self::new(date, time).map_err(de::Error::custom)
+    }
+}
+
+/// A `Visitor` that parses a string with the `FromStr` implementation of `T`.
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    use crate::iso::{IsoDate, IsoDateTime, IsoTime};
+
+    #[test]
+    fn human_readable_round_trip() {
+        let date_time = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(-500, 6, 30),
+            IsoTime::new_unchecked(14, 30, 5, 1, 2, 3),
+        );
+        let json = serde_json::to_string(&date_time).unwrap();
+        assert_eq!(json, r#""-000500-06-30T14:30:05.001002003""#);
+        assert_eq!(
+            serde_json::from_str::<IsoDateTime>(&json).unwrap(),
+            date_time
+        );
+
+        let json = serde_json::to_string(&date_time.date).unwrap();
+        assert_eq!(json, r#""-000500-06-30""#);
+        assert_eq!(
+            serde_json::from_str::<IsoDate>(&json).unwrap(),
+            date_time.date
+        );
+
+        let json = serde_json::to_string(&date_time.time).unwrap();
+        assert_eq!(json, r#""14:30:05.001002003""#);
+        assert_eq!(
+            serde_json::from_str::<IsoTime>(&json).unwrap(),
+            date_time.time
+        );
+
+        assert!(serde_json::from_str::<IsoDate>(r#""2023-02-29""#).is_err());
+        assert!(serde_json::from_str::<IsoDate>("[2024, 3, 15]").is_err());
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let date = IsoDate::new_unchecked(-500, 6, 30);
+        let time = IsoTime::new_unchecked(14, 30, 5, 1, 2, 3);
+        let date_tokens = [
+            Token::Tuple { len: 3 },
+            Token::I32(-500),
+            Token::U8(6),
+            Token::U8(30),
+            Token::TupleEnd,
+        ];
+        let time_tokens = [
+            Token::Tuple { len: 6 },
+            Token::U8(14),
+            Token::U8(30),
+            Token::U8(5),
+            Token::U16(1),
+            Token::U16(2),
+            Token::U16(3),
+            Token::TupleEnd,
+        ];
+        assert_tokens(&date.compact(), &date_tokens);
+        assert_tokens(&time.compact(), &time_tokens);
+
+        let date_time_tokens: alloc::vec::Vec<Token> = [Token::Tuple { len: 2 }]
+            .into_iter()
+            .chain(date_tokens)
+            .chain(time_tokens)
+            .chain([Token::TupleEnd])
+            .collect();
+        assert_tokens(
+            &IsoDateTime::new_unchecked(date, time).compact(),
+            &date_time_tokens,
+        );
+    }
+
+    #[test]
+    fn compact_rejects_invalid_fields() {
+        assert_de_tokens_error::<serde_test::Compact<IsoDate>>(
+            &[
+                Token::Tuple { len: 3 },
+                Token::I32(2023),
+                Token::U8(2),
+                Token::U8(29),
+                Token::TupleEnd,
+            ],
+            "RangeError: not a valid ISO date.",
+        );
+        assert_de_tokens_error::<serde_test::Compact<IsoDate>>(
+            &[
+                Token::Tuple { len: 3 },
+                Token::I32(275_760),
+                Token::U8(9),
+                Token::U8(14),
+                Token::TupleEnd,
+            ],
+            "RangeError: Date is not within ISO date time limits.",
+        );
+        assert_de_tokens_error::<serde_test::Compact<IsoTime>>(
+            &[
+                Token::Tuple { len: 6 },
+                Token::U8(24),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U16(0),
+                Token::U16(0),
+                Token::U16(0),
+                Token::TupleEnd,
+            ],
+            "RangeError: IsoTime is not valid",
+        );
+    }
+}
@@ -124,6 +124,8 @@ extern crate core;
 extern crate std;
 
 pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod iso;
 pub mod options;
 pub mod parsers;
@@ -141,8 +143,7 @@ pub mod tzdb;
 
 #[doc(hidden)]
 pub(crate) mod rounding;
-#[doc(hidden)]
-pub(crate) mod utils;
+pub mod utils;
 
 use core::cmp::Ordering;
 
@@ -159,7 +160,7 @@ pub use error::TemporalError;
 
 #[cfg(feature = "sys")]
 #[doc(inline)]
-pub use sys::Temporal;
+pub use sys::{LocalDateTime, Temporal};
 
 pub mod partial {
     //! Partial Date/Time component records.
@@ -185,8 +186,8 @@ pub use crate::builtins::{
     calendar::{Calendar, MonthCode},
     core::timezone::{TimeZone, UtcOffset},
     core::DateDuration,
-    Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
-    TimeDuration, ZonedDateTime,
+    Duration, Instant, NormalizedTimeDuration, PlainDate, PlainDateTime, PlainMonthDay, PlainTime,
+    PlainYearMonth, TimeDuration, ZonedDateTime,
 };
 
 /// A library specific trait for unwrapping assertions.
@@ -132,6 +132,53 @@ pub struct DifferenceSettings {
     pub increment: Option<RoundingIncrement>,
 }
 
+impl DifferenceSettings {
+    /// Returns a `DifferenceSettingsBuilder` for constructing `DifferenceSettings`.
+    ///
+    /// Any option left unset resolves to the default for the `until`/`since`
+    /// operation it is provided to.
+    pub fn builder() -> DifferenceSettingsBuilder {
+        DifferenceSettingsBuilder::default()
+    }
+}
+
+/// A builder for `DifferenceSettings`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DifferenceSettingsBuilder {
+    settings: DifferenceSettings,
+}
+
+impl DifferenceSettingsBuilder {
+    /// Sets the largest unit of the difference.
+    pub fn with_largest_unit(mut self, unit: Unit) -> Self {
+        self.settings.largest_unit = Some(unit);
+        self
+    }
+
+    /// Sets the smallest unit of the difference.
+    pub fn with_smallest_unit(mut self, unit: Unit) -> Self {
+        self.settings.smallest_unit = Some(unit);
+        self
+    }
+
+    /// Sets the rounding mode of the difference.
+    pub fn with_rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.settings.rounding_mode = Some(mode);
+        self
+    }
+
+    /// Sets the rounding increment of the difference.
+    pub fn with_increment(mut self, increment: RoundingIncrement) -> Self {
+        self.settings.increment = Some(increment);
+        self
+    }
+
+    /// Returns the configured `DifferenceSettings`.
+    pub fn build(self) -> DifferenceSettings {
+        self.settings
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct RoundingOptions {
@@ -155,6 +202,27 @@ impl Default for RoundingOptions {
     }
 }
 
+impl RoundingOptions {
+    /// Creates `RoundingOptions` that round to the nearest `unit`, using the
+    /// default `HalfExpand` rounding mode.
+    pub fn to_nearest(unit: Unit) -> Self {
+        Self {
+            smallest_unit: Some(unit),
+            rounding_mode: Some(RoundingMode::HalfExpand),
+            ..Default::default()
+        }
+    }
+
+    /// Creates `RoundingOptions` that truncate to `unit`.
+    pub fn truncate(unit: Unit) -> Self {
+        Self {
+            smallest_unit: Some(unit),
+            rounding_mode: Some(RoundingMode::Trunc),
+            ..Default::default()
+        }
+    }
+}
+
 /// Internal options object that represents the resolved rounding options.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct ResolvedRoundingOptions {
@@ -493,6 +561,37 @@ impl Unit {
             Hour | Minute | Second | Millisecond | Microsecond | Nanosecond
         )
     }
+
+    /// Returns the `Unit` for one of the singular or plural unit strings accepted
+    /// by Temporal, e.g. `"hour"` or `"hours"`.
+    ///
+    /// Matching is case-sensitive, and `"auto"` is not accepted.
+    pub fn from_plural(s: &str) -> TemporalResult<Self> {
+        match s {
+            "auto" => Err(ParseUnitError),
+            s => Self::from_str(s),
+        }
+        .map_err(|_| TemporalError::range().with_message("Invalid unit string."))
+    }
+
+    /// Returns the plural form of this `Unit`, e.g. `"hours"`.
+    #[inline]
+    #[must_use]
+    pub fn to_plural_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Year => "years",
+            Self::Month => "months",
+            Self::Week => "weeks",
+            Self::Day => "days",
+            Self::Hour => "hours",
+            Self::Minute => "minutes",
+            Self::Second => "seconds",
+            Self::Millisecond => "milliseconds",
+            Self::Microsecond => "microseconds",
+            Self::Nanosecond => "nanoseconds",
+        }
+    }
 }
 
 trait UnwrapUnit {
@@ -997,3 +1096,33 @@ impl FromStr for DisplayTimeZone {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+
+    #[test]
+    fn unit_from_plural() {
+        let units = [
+            ("year", "years", Unit::Year),
+            ("month", "months", Unit::Month),
+            ("week", "weeks", Unit::Week),
+            ("day", "days", Unit::Day),
+            ("hour", "hours", Unit::Hour),
+            ("minute", "minutes", Unit::Minute),
+            ("second", "seconds", Unit::Second),
+            ("millisecond", "milliseconds", Unit::Millisecond),
+            ("microsecond", "microseconds", Unit::Microsecond),
+            ("nanosecond", "nanoseconds", Unit::Nanosecond),
+        ];
+        for (singular, plural, unit) in units {
+            assert_eq!(Unit::from_plural(singular), Ok(unit));
+            assert_eq!(Unit::from_plural(plural), Ok(unit));
+            assert_eq!(unit.to_plural_str(), plural);
+        }
+
+        for invalid in ["Year", "HOURS", "auto", "yr", "", " day"] {
+            assert!(Unit::from_plural(invalid).is_err(), "{invalid}");
+        }
+    }
+}
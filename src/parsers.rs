@@ -12,8 +12,24 @@ use ixdtf::parsers::{
 };
 use writeable::{impl_display_with_writeable, LengthHint, Writeable};
 
+mod dtg;
+mod duration;
+mod ecma;
+mod http_date;
+mod lenient;
+mod partial;
+mod rfc3339;
 mod timezone;
 
+pub use lenient::ParseHint;
+
+pub(crate) use dtg::{format_dtg, parse_dtg};
+pub(crate) use duration::parse_iso_duration;
+pub(crate) use ecma::parse_ecma_date_time;
+pub(crate) use http_date::{format_http_date, parse_http_date};
+pub(crate) use lenient::parse_lenient_date;
+pub(crate) use partial::parse_partial_date_time;
+pub(crate) use rfc3339::{parse_extended_time, parse_rfc3339_time};
 pub(crate) use timezone::{parse_allowed_timezone_formats, parse_identifier};
 
 // TODO: Move `Writeable` functionality to `ixdtf` crate
@@ -30,15 +46,11 @@ impl<'a> IxdtfStringBuilder<'a> {
     }
 
     pub fn with_time(mut self, time: IsoTime, precision: Precision) -> Self {
-        let nanosecond = (time.millisecond as u32 * 1_000_000)
-            + (time.microsecond as u32 * 1000)
-            + time.nanosecond as u32;
-
         self.inner.time = Some(FormattableTime {
             hour: time.hour,
             minute: time.minute,
             second: time.second,
-            nanosecond,
+            nanosecond: time.total_sub_second_nanoseconds(),
             precision,
             include_sep: true,
         });
@@ -81,7 +93,7 @@ impl<'a> IxdtfStringBuilder<'a> {
         self
     }
 
-    pub fn with_calendar(mut self, calendar: &'static str, show: DisplayCalendar) -> Self {
+    pub fn with_calendar(mut self, calendar: &'a str, show: DisplayCalendar) -> Self {
         self.inner.calendar = Some(FormattableCalendar { show, calendar });
         self
     }
@@ -585,6 +597,7 @@ impl Writeable for FormattableDuration {
                     let unit_below_minute = self.date.is_none() && hours == 0 && minutes == 0;
 
                     let write_second = seconds != 0
+                        || ns != 0
                         || unit_below_minute
                         || matches!(self.precision, Precision::Digit(_));
 
@@ -637,6 +650,19 @@ fn checked_write_u64_with_suffix<W: core::fmt::Write + ?Sized>(
     sink.write_char(suffix)
 }
 
+/// Parses `digits` as an unsigned decimal integer, returning `err()` if any byte is
+/// not an ASCII digit or the value overflows a `u32`.
+fn parse_ascii_digits(digits: &[u8], err: fn() -> TemporalError) -> TemporalResult<u32> {
+    digits.iter().try_fold(0u32, |acc, digit| {
+        if !digit.is_ascii_digit() {
+            return Err(err());
+        }
+        acc.checked_mul(10)
+            .and_then(|acc| acc.checked_add(u32::from(digit - b'0')))
+            .ok_or_else(err)
+    })
+}
+
 // TODO: Determine if these should be separate structs, i.e. TemporalDateTimeParser/TemporalInstantParser, or
 // maybe on global `TemporalParser` around `IxdtfParser` that handles the Temporal idiosyncracies.
 #[derive(PartialEq)]
@@ -648,7 +674,7 @@ enum ParseVariant {
 }
 
 #[inline]
-fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfParseRecord> {
+fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfParseRecord<'_>> {
     fn cast_handler<'a>(
         _: &mut IxdtfParser<'a>,
         handler: impl FnMut(Annotation<'a>) -> Option<Annotation<'a>>,
@@ -706,7 +732,7 @@ fn parse_ixdtf(source: &[u8], variant: ParseVariant) -> TemporalResult<IxdtfPars
 
 /// A utility function for parsing a `DateTime` string
 #[inline]
-pub(crate) fn parse_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord> {
+pub(crate) fn parse_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_>> {
     let record = parse_ixdtf(source, ParseVariant::DateTime)?;
 
     if record.offset == Some(UtcOffsetRecordOrZ::Z) {
@@ -717,8 +743,14 @@ pub(crate) fn parse_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord>
     Ok(record)
 }
 
+/// A utility function for parsing a `DateTime` string with any offset and annotations
+#[inline]
+pub(crate) fn parse_annotated_date_time(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_>> {
+    parse_ixdtf(source, ParseVariant::DateTime)
+}
+
 #[inline]
-pub(crate) fn parse_zoned_date_time(source: &str) -> TemporalResult<IxdtfParseRecord> {
+pub(crate) fn parse_zoned_date_time(source: &str) -> TemporalResult<IxdtfParseRecord<'_>> {
     let record = parse_ixdtf(source.as_bytes(), ParseVariant::DateTime)?;
 
     // TODO: Support rejecting subminute precision in time zone annootations
@@ -758,7 +790,7 @@ pub(crate) fn parse_instant(source: &[u8]) -> TemporalResult<IxdtfParseInstantRe
 
 /// A utility function for parsing a `YearMonth` string
 #[inline]
-pub(crate) fn parse_year_month(source: &[u8]) -> TemporalResult<IxdtfParseRecord> {
+pub(crate) fn parse_year_month(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_>> {
     let ym_record = parse_ixdtf(source, ParseVariant::YearMonth);
 
     if let Ok(ym) = ym_record {
@@ -780,7 +812,7 @@ pub(crate) fn parse_year_month(source: &[u8]) -> TemporalResult<IxdtfParseRecord
 
 /// A utilty function for parsing a `MonthDay` String.
 #[inline]
-pub(crate) fn parse_month_day(source: &[u8]) -> TemporalResult<IxdtfParseRecord> {
+pub(crate) fn parse_month_day(source: &[u8]) -> TemporalResult<IxdtfParseRecord<'_>> {
     let md_record = parse_ixdtf(source, ParseVariant::MonthDay);
     // Error needs to be a RangeError
     md_record.map_err(|e| TemporalError::range().with_message(format!("{e}")))
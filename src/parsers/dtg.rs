@@ -0,0 +1,95 @@
+//! Formatting and parsing of military Date-Time Group (DTG) strings, e.g. `152000Z MAR 24`.
+
+use alloc::{format, string::String};
+
+use crate::{TemporalError, TemporalResult};
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// The fields of a parsed Date-Time Group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DtgRecord {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) zone: char,
+}
+
+/// Formats the provided fields as a `DDHHMMZ MMM YY` Date-Time Group.
+///
+/// Only the last two digits of the year are written.
+pub(crate) fn format_dtg(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    zone: char,
+) -> String {
+    let month = MONTH_ABBREVIATIONS[usize::from(month - 1)];
+    format!(
+        "{day:02}{hour:02}{minute:02}{zone} {month} {:02}",
+        year.rem_euclid(100)
+    )
+}
+
+/// Parses a `DDHHMMZ MMM YY` Date-Time Group into its fields.
+///
+/// The two digit year is resolved to 1970 through 2069. The fields are not validated
+/// beyond the hour and minute being in range.
+pub(crate) fn parse_dtg(source: &str) -> TemporalResult<DtgRecord> {
+    let mut parts = source.split(' ');
+    let (Some(group), Some(month), Some(year), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let group = group.as_bytes();
+    let (&[d1, d2, h1, h2, m1, m2, zone], &[y1, y2]) = (group, year.as_bytes()) else {
+        return Err(invalid());
+    };
+    let day = parse_two_digits(d1, d2)?;
+    let hour = parse_two_digits(h1, h2)?;
+    let minute = parse_two_digits(m1, m2)?;
+    if hour > 23 || minute > 59 {
+        return Err(TemporalError::range().with_message("DTG time is not valid."));
+    }
+    // Military time zone letters run from A to Z, skipping J for local time.
+    if !zone.is_ascii_uppercase() || zone == b'J' {
+        return Err(TemporalError::syntax().with_message("DTG time zone letter is not valid."));
+    }
+
+    let month = MONTH_ABBREVIATIONS
+        .iter()
+        .position(|abbreviation| month.eq_ignore_ascii_case(abbreviation))
+        .map(|index| index as u8 + 1)
+        .ok_or_else(|| TemporalError::syntax().with_message("Unrecognized DTG month."))?;
+
+    let year = i32::from(parse_two_digits(y1, y2)?);
+    let year = if year >= 70 { 1900 + year } else { 2000 + year };
+
+    Ok(DtgRecord {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        zone: char::from(zone),
+    })
+}
+
+fn invalid() -> TemporalError {
+    TemporalError::syntax().with_message("Invalid DTG string.")
+}
+
+fn parse_two_digits(tens: u8, ones: u8) -> TemporalResult<u8> {
+    if !tens.is_ascii_digit() || !ones.is_ascii_digit() {
+        return Err(invalid());
+    }
+    Ok((tens - b'0') * 10 + (ones - b'0'))
+}
@@ -0,0 +1,263 @@
+//! A recursive-descent parser for ISO 8601 duration strings, e.g. `-P1Y2M3W4DT5H6M7.89S`.
+//!
+//! The grammar is the `TemporalDurationString` grammar of the Temporal specification:
+//!
+//! ```text
+//! Duration     ::= Sign? DurationDesignator (DurationDate | DurationTime)
+//! DurationDate ::= Years? Months? Weeks? Days? DurationTime?   (at least one part)
+//! DurationTime ::= TimeDesignator Hours? Minutes? Seconds?     (at least one part)
+//! Part         ::= DecimalDigits Fraction? Designator
+//! Fraction     ::= ('.' | ',') DecimalDigit{1,9}
+//! ```
+//!
+//! Designators are case-insensitive, and only the last time part may have a fraction.
+
+use crate::{Sign, TemporalError, TemporalResult};
+
+/// The maximum value of a duration field, i.e. the largest integer that is exactly
+/// representable as an `f64`.
+const MAX_SAFE_INTEGER: u64 = 9_007_199_254_740_991;
+
+const NS_PER_MINUTE: u64 = 60_000_000_000;
+
+/// The unsigned fields of a parsed ISO 8601 duration string.
+///
+/// A fraction on the hours or minutes is balanced down into the smaller units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DurationParseRecord {
+    pub(crate) sign: Sign,
+    pub(crate) years: u64,
+    pub(crate) months: u64,
+    pub(crate) weeks: u64,
+    pub(crate) days: u64,
+    pub(crate) hours: u64,
+    pub(crate) minutes: u64,
+    pub(crate) seconds: u64,
+    pub(crate) milliseconds: u64,
+    pub(crate) microseconds: u64,
+    pub(crate) nanoseconds: u64,
+}
+
+/// Parses an ISO 8601 duration string into its fields.
+pub(crate) fn parse_iso_duration(source: &[u8]) -> TemporalResult<DurationParseRecord> {
+    let mut cursor = Cursor {
+        source,
+        position: 0,
+    };
+    let record = parse_duration(&mut cursor)?;
+    if cursor.peek().is_some() {
+        return Err(invalid());
+    }
+    Ok(record)
+}
+
+/// A position in the source being parsed.
+struct Cursor<'a> {
+    source: &'a [u8],
+    position: usize,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.source.get(self.position).copied()
+    }
+
+    /// Advances past the next byte if it is `designator`, ignoring ASCII case.
+    fn eat_designator(&mut self, designator: u8) -> bool {
+        if self
+            .peek()
+            .is_some_and(|byte| byte.eq_ignore_ascii_case(&designator))
+        {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+}
+
+/// A `DecimalDigits Fraction? Designator` part.
+struct Part {
+    value: u64,
+    /// The fraction in nanoseconds, i.e. scaled to nine digits.
+    fraction: Option<u64>,
+    designator: u8,
+}
+
+fn parse_duration(cursor: &mut Cursor<'_>) -> TemporalResult<DurationParseRecord> {
+    let sign = match cursor.peek() {
+        Some(b'-') => {
+            cursor.position += 1;
+            Sign::Negative
+        }
+        Some(b'+') => {
+            cursor.position += 1;
+            Sign::Positive
+        }
+        _ => Sign::Positive,
+    };
+    if !cursor.eat_designator(b'P') {
+        return Err(invalid());
+    }
+
+    let mut record = DurationParseRecord {
+        sign,
+        years: 0,
+        months: 0,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        milliseconds: 0,
+        microseconds: 0,
+        nanoseconds: 0,
+    };
+    let has_date = parse_duration_date(cursor, &mut record)?;
+    let has_time = if cursor.eat_designator(b'T') {
+        parse_duration_time(cursor, &mut record)?;
+        true
+    } else {
+        false
+    };
+    if !has_date && !has_time {
+        return Err(invalid());
+    }
+    Ok(record)
+}
+
+/// Parses the optional date parts, returning whether any were present.
+fn parse_duration_date(
+    cursor: &mut Cursor<'_>,
+    record: &mut DurationParseRecord,
+) -> TemporalResult<bool> {
+    let fields = [
+        (b'Y', &mut record.years),
+        (b'M', &mut record.months),
+        (b'W', &mut record.weeks),
+        (b'D', &mut record.days),
+    ];
+    let mut next_field = 0;
+    while let Some(part) = parse_part(cursor)? {
+        let Some(index) = fields[next_field..]
+            .iter()
+            .position(|(designator, _)| *designator == part.designator)
+        else {
+            return Err(invalid());
+        };
+        if part.fraction.is_some() {
+            return Err(TemporalError::range()
+                .with_message("Only the smallest time unit of a duration may be fractional."));
+        }
+        *fields[next_field + index].1 = part.value;
+        next_field += index + 1;
+        if next_field == fields.len() {
+            break;
+        }
+    }
+    Ok(next_field != 0)
+}
+
+/// Parses the time parts following the time designator, at least one of which must be present.
+fn parse_duration_time(
+    cursor: &mut Cursor<'_>,
+    record: &mut DurationParseRecord,
+) -> TemporalResult<()> {
+    let mut next_field = 0;
+    let mut fraction = None;
+    while let Some(part) = parse_part(cursor)? {
+        // A fraction must be on the last part.
+        if fraction.is_some() {
+            return Err(TemporalError::range()
+                .with_message("Only the smallest time unit of a duration may be fractional."));
+        }
+        let Some(index) = [b'H', b'M', b'S'][next_field..]
+            .iter()
+            .position(|designator| *designator == part.designator)
+        else {
+            return Err(invalid());
+        };
+        next_field += index + 1;
+        match next_field {
+            1 => record.hours = part.value,
+            2 => record.minutes = part.value,
+            _ => record.seconds = part.value,
+        }
+        fraction = part.fraction.map(|fraction| (next_field, fraction));
+        if next_field == 3 {
+            break;
+        }
+    }
+    if next_field == 0 {
+        return Err(invalid());
+    }
+
+    // Balance a fractional hour or minute down into the smaller units. The fraction is
+    // in billionths of its unit, so scaling it by the seconds in the unit gives nanoseconds.
+    let fraction_ns = match fraction {
+        Some((1, fraction)) => fraction * 3_600,
+        Some((2, fraction)) => fraction * 60,
+        Some((_, fraction)) => fraction,
+        None => 0,
+    };
+    let (minutes, fraction_ns) = (fraction_ns / NS_PER_MINUTE, fraction_ns % NS_PER_MINUTE);
+    record.minutes += minutes;
+    record.seconds += fraction_ns / 1_000_000_000;
+    record.milliseconds = fraction_ns / 1_000_000 % 1_000;
+    record.microseconds = fraction_ns / 1_000 % 1_000;
+    record.nanoseconds = fraction_ns % 1_000;
+    Ok(())
+}
+
+/// Parses a `DecimalDigits Fraction? Designator` part, or returns `None` if the
+/// cursor is not at a digit.
+fn parse_part(cursor: &mut Cursor<'_>) -> TemporalResult<Option<Part>> {
+    if !cursor.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+        return Ok(None);
+    }
+    let mut value: u64 = 0;
+    while let Some(digit @ b'0'..=b'9') = cursor.peek() {
+        cursor.position += 1;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u64::from(digit - b'0')))
+            .filter(|value| *value <= MAX_SAFE_INTEGER)
+            .ok_or_else(|| {
+                TemporalError::range()
+                    .with_message("Duration field exceeds the maximum safe integer.")
+            })?;
+    }
+
+    let fraction = if let Some(b'.' | b',') = cursor.peek() {
+        cursor.position += 1;
+        let start = cursor.position;
+        let mut fraction: u64 = 0;
+        while let Some(digit @ b'0'..=b'9') = cursor.peek() {
+            cursor.position += 1;
+            fraction = fraction * 10 + u64::from(digit - b'0');
+            if cursor.position - start > 9 {
+                return Err(TemporalError::range()
+                    .with_message("Duration fractions may have at most nine digits."));
+            }
+        }
+        let digits = cursor.position - start;
+        if digits == 0 {
+            return Err(invalid());
+        }
+        Some(fraction * 10u64.pow(9 - digits as u32))
+    } else {
+        None
+    };
+
+    let designator = cursor.peek().ok_or_else(invalid)?.to_ascii_uppercase();
+    cursor.position += 1;
+    Ok(Some(Part {
+        value,
+        fraction,
+        designator,
+    }))
+}
+
+// NOTE: Invalid duration strings are a RangeError in the specification.
+fn invalid() -> TemporalError {
+    TemporalError::range().with_message("Invalid ISO 8601 duration string.")
+}
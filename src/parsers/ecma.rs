@@ -0,0 +1,64 @@
+//! Parsing of the ECMAScript date time string format, i.e. the output of
+//! `Date.prototype.toISOString()`, e.g. `2024-03-15T14:30:00.000Z`.
+
+use crate::{TemporalError, TemporalResult};
+
+use super::parse_ascii_digits;
+
+/// The fields of a parsed ECMAScript date time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct EcmaDateTimeRecord {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+}
+
+/// Parses a `YYYY-MM-DDTHH:mm:ss.sssZ` string into its fields.
+///
+/// The year may instead be an expanded `±YYYYYY` year, though `-000000` is
+/// rejected. The fields are not validated beyond being made up of digits.
+pub(crate) fn parse_ecma_date_time(source: &str) -> TemporalResult<EcmaDateTimeRecord> {
+    let source = source.as_bytes();
+    let (year, rest) = match source {
+        [sign @ (b'+' | b'-'), rest @ ..] if rest.len() > 6 => {
+            let (digits, rest) = rest.split_at(6);
+            let year = parse_ascii_digits(digits, invalid)? as i32;
+            if *sign == b'-' && year == 0 {
+                return Err(TemporalError::syntax()
+                    .with_message("Negative zero is not a valid expanded year."));
+            }
+            let year = if *sign == b'-' { -year } else { year };
+            (year, rest)
+        }
+        _ if source.len() > 4 => {
+            let (digits, rest) = source.split_at(4);
+            (parse_ascii_digits(digits, invalid)? as i32, rest)
+        }
+        _ => return Err(invalid()),
+    };
+
+    let &[b'-', mo1, mo2, b'-', d1, d2, b'T', ref time @ ..] = rest else {
+        return Err(invalid());
+    };
+    let &[h1, h2, b':', mi1, mi2, b':', s1, s2, b'.', ms1, ms2, ms3, b'Z'] = time else {
+        return Err(invalid());
+    };
+
+    Ok(EcmaDateTimeRecord {
+        year,
+        month: parse_ascii_digits(&[mo1, mo2], invalid)? as u8,
+        day: parse_ascii_digits(&[d1, d2], invalid)? as u8,
+        hour: parse_ascii_digits(&[h1, h2], invalid)? as u8,
+        minute: parse_ascii_digits(&[mi1, mi2], invalid)? as u8,
+        second: parse_ascii_digits(&[s1, s2], invalid)? as u8,
+        millisecond: parse_ascii_digits(&[ms1, ms2, ms3], invalid)? as u16,
+    })
+}
+
+fn invalid() -> TemporalError {
+    TemporalError::syntax().with_message("Invalid ECMAScript date time string.")
+}
@@ -0,0 +1,210 @@
+//! Formatting and parsing of HTTP dates as defined in RFC 7231 section 7.1.1.1,
+//! e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+
+use alloc::{format, string::String};
+
+use crate::{TemporalError, TemporalResult};
+
+use super::parse_ascii_digits;
+
+const WEEKDAY_ABBREVIATIONS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The fields of a parsed HTTP date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HttpDateRecord {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    /// The ISO day of the week, 1 (Monday) through 7 (Sunday).
+    pub(crate) day_of_week: u8,
+}
+
+/// Formats the provided fields as an RFC 7231 IMF-fixdate, e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+pub(crate) fn format_http_date(
+    year: i32,
+    month: u8,
+    day: u8,
+    day_of_week: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> String {
+    let weekday = WEEKDAY_ABBREVIATIONS[usize::from(day_of_week - 1)];
+    let month = MONTH_ABBREVIATIONS[usize::from(month - 1)];
+    format!("{weekday}, {day:02} {month} {year:04} {hour:02}:{minute:02}:{second:02} GMT")
+}
+
+/// Parses an HTTP date in the IMF-fixdate, obsolete RFC 850, or ANSI C `asctime()`
+/// format into its fields.
+///
+/// `UTC` is accepted as well as `GMT`, and the two digit year of the RFC 850 format
+/// is resolved to 1970 through 2069. The fields are not validated beyond the time
+/// being in range.
+pub(crate) fn parse_http_date(source: &str) -> TemporalResult<HttpDateRecord> {
+    if let Some((weekday, rest)) = source.split_once(", ") {
+        if let Some(day_of_week) = position_of(&WEEKDAY_ABBREVIATIONS, weekday) {
+            return parse_imf_fixdate(rest, day_of_week);
+        }
+        let day_of_week = position_of(&WEEKDAY_NAMES, weekday).ok_or_else(invalid)?;
+        return parse_rfc_850(rest, day_of_week);
+    }
+    parse_asctime(source)
+}
+
+/// Parses the `06 Nov 1994 08:49:37 GMT` portion of an IMF-fixdate.
+fn parse_imf_fixdate(source: &str, day_of_week: u8) -> TemporalResult<HttpDateRecord> {
+    let mut parts = source.split(' ');
+    let (Some(day), Some(month), Some(year), Some(time), Some(zone), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return Err(invalid());
+    };
+    check_zone(zone)?;
+    build_record(
+        parse_fixed_digits(year, 4)? as i32,
+        month,
+        parse_fixed_digits(day, 2)? as u8,
+        time,
+        day_of_week,
+    )
+}
+
+/// Parses the `06-Nov-94 08:49:37 GMT` portion of an RFC 850 date.
+fn parse_rfc_850(source: &str, day_of_week: u8) -> TemporalResult<HttpDateRecord> {
+    let mut parts = source.split(' ');
+    let (Some(date), Some(time), Some(zone), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    check_zone(zone)?;
+    let mut date = date.split('-');
+    let (Some(day), Some(month), Some(year), None) =
+        (date.next(), date.next(), date.next(), date.next())
+    else {
+        return Err(invalid());
+    };
+    let year = parse_fixed_digits(year, 2)? as i32;
+    let year = if year >= 70 { 1900 + year } else { 2000 + year };
+    build_record(
+        year,
+        month,
+        parse_fixed_digits(day, 2)? as u8,
+        time,
+        day_of_week,
+    )
+}
+
+/// Parses an ANSI C `asctime()` date, e.g. `Sun Nov  6 08:49:37 1994`.
+fn parse_asctime(source: &str) -> TemporalResult<HttpDateRecord> {
+    // The day of the month is padded with a space rather than a zero, so the date
+    // cannot be split on spaces.
+    let (Some(weekday), Some(&b' '), Some(month), Some(&b' '), Some(day), Some(rest)) = (
+        source.get(..3),
+        source.as_bytes().get(3),
+        source.get(4..7),
+        source.as_bytes().get(7),
+        source.get(8..10),
+        source.get(10..),
+    ) else {
+        return Err(invalid());
+    };
+    let day_of_week = position_of(&WEEKDAY_ABBREVIATIONS, weekday).ok_or_else(invalid)?;
+    let day = match day.strip_prefix(' ') {
+        Some(day) => parse_fixed_digits(day, 1)?,
+        None => parse_fixed_digits(day, 2)?,
+    };
+    let mut parts = rest.split(' ');
+    let (Some(""), Some(time), Some(year), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    build_record(
+        parse_fixed_digits(year, 4)? as i32,
+        month,
+        day as u8,
+        time,
+        day_of_week,
+    )
+}
+
+fn build_record(
+    year: i32,
+    month: &str,
+    day: u8,
+    time: &str,
+    day_of_week: u8,
+) -> TemporalResult<HttpDateRecord> {
+    let month = position_of(&MONTH_ABBREVIATIONS, month)
+        .ok_or_else(|| TemporalError::syntax().with_message("Unrecognized HTTP date month."))?;
+    let mut time = time.split(':');
+    let (Some(hour), Some(minute), Some(second), None) =
+        (time.next(), time.next(), time.next(), time.next())
+    else {
+        return Err(invalid());
+    };
+    let hour = parse_fixed_digits(hour, 2)? as u8;
+    let minute = parse_fixed_digits(minute, 2)? as u8;
+    let second = parse_fixed_digits(second, 2)? as u8;
+    // A leap second of 60 is permitted.
+    if hour > 23 || minute > 59 || second > 60 {
+        return Err(TemporalError::range().with_message("HTTP date time is not valid."));
+    }
+    Ok(HttpDateRecord {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        day_of_week,
+    })
+}
+
+fn check_zone(zone: &str) -> TemporalResult<()> {
+    match zone {
+        "GMT" | "UTC" => Ok(()),
+        _ => Err(TemporalError::syntax().with_message("HTTP date must be in GMT.")),
+    }
+}
+
+/// Returns the 1-based position of `value` in `names`.
+fn position_of(names: &[&str], value: &str) -> Option<u8> {
+    names
+        .iter()
+        .position(|name| *name == value)
+        .map(|index| index as u8 + 1)
+}
+
+fn invalid() -> TemporalError {
+    TemporalError::syntax().with_message("Invalid HTTP date string.")
+}
+
+fn parse_fixed_digits(digits: &str, len: usize) -> TemporalResult<u32> {
+    if digits.len() != len {
+        return Err(invalid());
+    }
+    parse_ascii_digits(digits.as_bytes(), invalid)
+}
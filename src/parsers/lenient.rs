@@ -0,0 +1,150 @@
+//! Lenient parsing of user-facing date strings.
+
+use crate::{TemporalError, TemporalResult};
+
+use super::parse_ascii_digits;
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// A hint for disambiguating numeric date formats in lenient parsing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParseHint {
+    /// Whether slash-separated dates are read as `MM/DD/YYYY` rather than `DD/MM/YYYY`.
+    pub month_before_day: bool,
+}
+
+/// Parses a date in one of the supported lenient formats into its year, month, and day.
+///
+/// The returned values are not validated beyond fitting their respective types.
+pub(crate) fn parse_lenient_date(source: &str, hint: ParseHint) -> TemporalResult<(i32, u8, u8)> {
+    let source = source.trim();
+    let Some(first) = source.chars().next() else {
+        return Err(unrecognized());
+    };
+
+    // Long-form dates: "March 15, 2024" or "15 March 2024".
+    if source.chars().any(|c| c.is_ascii_alphabetic()) {
+        let mut parts = source
+            .split(|c: char| c.is_ascii_whitespace() || c == ',')
+            .filter(|part| !part.is_empty());
+        let (Some(first_part), Some(second_part), Some(year), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(unrecognized());
+        };
+        let (month, day) = if first.is_ascii_alphabetic() {
+            (first_part, second_part)
+        } else {
+            (second_part, first_part)
+        };
+        return Ok((
+            parse_year(year)?,
+            parse_month_name(month)?,
+            parse_component(day)?,
+        ));
+    }
+
+    // Slash-separated dates: "03/15/2024" or "15/03/2024".
+    if source.contains('/') {
+        let [first_part, second_part, year] = split_three(source, '/')?;
+        let (month, day) = if hint.month_before_day {
+            (first_part, second_part)
+        } else {
+            (second_part, first_part)
+        };
+        return Ok((
+            parse_year(year)?,
+            parse_component(month)?,
+            parse_component(day)?,
+        ));
+    }
+
+    // Dot-separated dates: "15.03.2024".
+    if source.contains('.') {
+        let [day, month, year] = split_three(source, '.')?;
+        return Ok((
+            parse_year(year)?,
+            parse_component(month)?,
+            parse_component(day)?,
+        ));
+    }
+
+    // Year-first dates with dashes, including ISO 8601 extended: "2024-03-15" or "+002024-3-15".
+    let (sign, unsigned) = match first {
+        '+' => (1, &source[1..]),
+        '-' => (-1, &source[1..]),
+        _ => (1, source),
+    };
+    if unsigned.contains('-') {
+        let [year, month, day] = split_three(unsigned, '-')?;
+        return Ok((
+            sign * parse_year(year)?,
+            parse_component(month)?,
+            parse_component(day)?,
+        ));
+    }
+
+    // ISO 8601 basic format: "20240315".
+    if source.len() == 8 && source.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok((
+            parse_year(&source[..4])?,
+            parse_component(&source[4..6])?,
+            parse_component(&source[6..])?,
+        ));
+    }
+
+    Err(unrecognized())
+}
+
+fn unrecognized() -> TemporalError {
+    TemporalError::syntax().with_message("Unrecognized date format.")
+}
+
+fn split_three(source: &str, separator: char) -> TemporalResult<[&str; 3]> {
+    let mut parts = source.split(separator);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(first), Some(second), Some(third), None) => Ok([first, second, third]),
+        _ => Err(unrecognized()),
+    }
+}
+
+fn parse_digits(source: &str, max_len: usize) -> TemporalResult<u32> {
+    if source.is_empty() || source.len() > max_len {
+        return Err(unrecognized());
+    }
+    parse_ascii_digits(source.as_bytes(), unrecognized)
+}
+
+fn parse_year(source: &str) -> TemporalResult<i32> {
+    // NOTE: Six digits is the maximum for an expanded ISO year, so the cast is lossless.
+    Ok(parse_digits(source, 6)? as i32)
+}
+
+fn parse_component(source: &str) -> TemporalResult<u8> {
+    Ok(parse_digits(source, 2)? as u8)
+}
+
+fn parse_month_name(source: &str) -> TemporalResult<u8> {
+    MONTH_NAMES
+        .iter()
+        .position(|name| {
+            source.eq_ignore_ascii_case(name)
+                || source.eq_ignore_ascii_case(&name[..3])
+                || (source.eq_ignore_ascii_case("sept") && *name == "september")
+        })
+        .map(|index| index as u8 + 1)
+        .ok_or_else(|| TemporalError::syntax().with_message("Unrecognized month name."))
+}
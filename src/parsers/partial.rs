@@ -0,0 +1,101 @@
+//! Parsing of incomplete ISO 8601 date-time strings, e.g. `2024-03` or `T14:30`.
+
+use crate::{iso::PartialIsoDateTime, TemporalError, TemporalResult};
+
+use super::parse_ascii_digits;
+
+/// Parses a date, a date and time, or a time prefixed with `T` into whichever fields
+/// are present.
+///
+/// The date is `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`, where the year may instead be an
+/// expanded `±YYYYYY` year. The time is `HH`, `HH:MM`, or `HH:MM:SS` with an optional
+/// fraction of up to nine digits. The fields are not validated beyond being made up
+/// of digits.
+pub(crate) fn parse_partial_date_time(source: &str) -> TemporalResult<PartialIsoDateTime> {
+    let source = source.as_bytes();
+    let (date, time) = match source.iter().position(|byte| matches!(byte, b'T' | b't')) {
+        Some(index) => (&source[..index], Some(&source[index + 1..])),
+        None => (source, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return Err(invalid());
+    }
+
+    let mut result = PartialIsoDateTime::default();
+    if !date.is_empty() {
+        parse_date(date, &mut result)?;
+    }
+    if let Some(time) = time {
+        parse_time(time, &mut result)?;
+    }
+    Ok(result)
+}
+
+fn parse_date(source: &[u8], result: &mut PartialIsoDateTime) -> TemporalResult<()> {
+    let (year, rest) = match source {
+        [sign @ (b'+' | b'-'), rest @ ..] if rest.len() >= 6 => {
+            let (digits, rest) = rest.split_at(6);
+            let year = parse_ascii_digits(digits, invalid)? as i32;
+            if *sign == b'-' && year == 0 {
+                return Err(TemporalError::syntax()
+                    .with_message("Negative zero is not a valid expanded year."));
+            }
+            (if *sign == b'-' { -year } else { year }, rest)
+        }
+        _ if source.len() >= 4 => {
+            let (digits, rest) = source.split_at(4);
+            (parse_ascii_digits(digits, invalid)? as i32, rest)
+        }
+        _ => return Err(invalid()),
+    };
+    result.year = Some(year);
+
+    match *rest {
+        [] => {}
+        [b'-', m1, m2] => result.month = Some(parse_ascii_digits(&[m1, m2], invalid)? as u8),
+        [b'-', m1, m2, b'-', d1, d2] => {
+            result.month = Some(parse_ascii_digits(&[m1, m2], invalid)? as u8);
+            result.day = Some(parse_ascii_digits(&[d1, d2], invalid)? as u8);
+        }
+        _ => return Err(invalid()),
+    }
+    Ok(())
+}
+
+fn parse_time(source: &[u8], result: &mut PartialIsoDateTime) -> TemporalResult<()> {
+    let (time, fraction) = match source.iter().position(|byte| matches!(byte, b'.' | b',')) {
+        Some(index) => (&source[..index], Some(&source[index + 1..])),
+        None => (source, None),
+    };
+
+    match *time {
+        [h1, h2] => result.hour = Some(parse_ascii_digits(&[h1, h2], invalid)? as u8),
+        [h1, h2, b':', m1, m2] => {
+            result.hour = Some(parse_ascii_digits(&[h1, h2], invalid)? as u8);
+            result.minute = Some(parse_ascii_digits(&[m1, m2], invalid)? as u8);
+        }
+        [h1, h2, b':', m1, m2, b':', s1, s2] => {
+            result.hour = Some(parse_ascii_digits(&[h1, h2], invalid)? as u8);
+            result.minute = Some(parse_ascii_digits(&[m1, m2], invalid)? as u8);
+            result.second = Some(parse_ascii_digits(&[s1, s2], invalid)? as u8);
+        }
+        _ => return Err(invalid()),
+    }
+
+    let Some(fraction) = fraction else {
+        return Ok(());
+    };
+    // A fraction is only valid after the seconds.
+    if result.second.is_none() || fraction.is_empty() || fraction.len() > 9 {
+        return Err(invalid());
+    }
+    let nanoseconds = parse_ascii_digits(fraction, invalid)? * 10u32.pow(9 - fraction.len() as u32);
+    result.millisecond = Some((nanoseconds / 1_000_000) as u16);
+    result.microsecond = Some((nanoseconds / 1_000 % 1_000) as u16);
+    result.nanosecond = Some((nanoseconds % 1_000) as u16);
+    Ok(())
+}
+
+fn invalid() -> TemporalError {
+    TemporalError::syntax().with_message("Invalid partial date-time string.")
+}
@@ -0,0 +1,63 @@
+//! Parsing of RFC 3339 partial time strings, e.g. `14:30:00.5` or `T14:30:00Z`, and
+//! the equivalent ISO 8601 extended time strings.
+
+use crate::{TemporalError, TemporalResult};
+
+use super::parse_ascii_digits;
+
+/// The fields of a parsed RFC 3339 partial time or ISO 8601 extended time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Rfc3339TimeRecord {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) nanosecond: u32,
+}
+
+/// Parses a `HH:MM:SS[.fraction]` string into its fields.
+///
+/// The time may be prefixed by `T` and suffixed by `Z`, and the fraction may have
+/// one to nine digits. The fields are not validated beyond being made up of digits.
+pub(crate) fn parse_rfc3339_time(source: &str) -> TemporalResult<Rfc3339TimeRecord> {
+    let source = source.as_bytes();
+    let source = match source {
+        [b'T' | b't', rest @ ..] => rest,
+        _ => source,
+    };
+    let source = match source {
+        [rest @ .., b'Z' | b'z'] => rest,
+        _ => source,
+    };
+    parse_time_fields(source)
+}
+
+/// Parses a `HH:MM:SS[.fraction]` ISO 8601 extended time string into its fields.
+///
+/// Unlike [`parse_rfc3339_time`], neither a `T` prefix nor a `Z` suffix is accepted.
+pub(crate) fn parse_extended_time(source: &str) -> TemporalResult<Rfc3339TimeRecord> {
+    parse_time_fields(source.as_bytes())
+}
+
+fn parse_time_fields(source: &[u8]) -> TemporalResult<Rfc3339TimeRecord> {
+    let &[h1, h2, b':', m1, m2, b':', s1, s2, ref fraction @ ..] = source else {
+        return Err(invalid());
+    };
+    let nanosecond = match fraction {
+        [] => 0,
+        [b'.', digits @ ..] if (1..=9).contains(&digits.len()) => {
+            parse_ascii_digits(digits, invalid)? * 10u32.pow(9 - digits.len() as u32)
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(Rfc3339TimeRecord {
+        hour: parse_ascii_digits(&[h1, h2], invalid)? as u8,
+        minute: parse_ascii_digits(&[m1, m2], invalid)? as u8,
+        second: parse_ascii_digits(&[s1, s2], invalid)? as u8,
+        nanosecond,
+    })
+}
+
+fn invalid() -> TemporalError {
+    TemporalError::syntax().with_message("Invalid RFC 3339 time string.")
+}
@@ -143,7 +143,7 @@ impl Roundable for i64 {
 }
 
 /// Applies the unsigned rounding mode.
-fn apply_unsigned_rounding_mode<T: Roundable>(
+pub(crate) fn apply_unsigned_rounding_mode<T: Roundable>(
     dividend: T,
     divisor: T,
     unsigned_rounding_mode: UnsignedRoundingMode,
@@ -1,5 +1,12 @@
 use crate::builtins::Now;
 use crate::builtins::NowBuilder;
+#[cfg(feature = "compiled_data")]
+use crate::builtins::TZ_PROVIDER;
+use crate::iso::IsoDateTime;
+use crate::options::DisplayOffset;
+use crate::parsers::{IxdtfStringBuilder, Precision};
+use crate::provider::TimeZoneProvider;
+use crate::Sign;
 use crate::TemporalResult;
 
 use crate::unix_time::EpochNanoseconds;
@@ -64,3 +71,240 @@ pub(crate) fn get_system_nanoseconds() -> TemporalResult<EpochNanoseconds> {
         .map_err(|e| TemporalError::general(e.to_string()))
         .map(|d| EpochNanoseconds::try_from(d.as_nanos()))?
 }
+
+/// A date-time in the system's local time zone, stored alongside its UTC offset.
+///
+/// `LocalDateTime` is a lightweight alternative to `ZonedDateTime` for callers that
+/// only need the current local date and time.
+///
+/// **Note:** Conversions from UTC use the system's *current* UTC offset rather than
+/// the offset in effect at the converted date-time, so they do not account for
+/// daylight saving time or other historical offset changes.
+///
+/// Available with the `sys` feature flag, which also enables `std`.
+#[cfg(feature = "sys")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalDateTime {
+    inner: IsoDateTime,
+    offset_seconds: i32,
+}
+
+#[cfg(feature = "sys")]
+impl LocalDateTime {
+    /// Returns the current `LocalDateTime` using the provided `TimeZoneProvider`.
+    pub fn now_with_provider(provider: &impl TimeZoneProvider) -> TemporalResult<Self> {
+        let nanoseconds = get_system_nanoseconds()?;
+        let offset_seconds = system_offset_seconds(&nanoseconds, provider)?;
+        let inner =
+            IsoDateTime::from_epoch_nanos(&nanoseconds, i64::from(offset_seconds) * 1_000_000_000)?;
+        Ok(Self {
+            inner,
+            offset_seconds,
+        })
+    }
+
+    /// Creates a `LocalDateTime` from a UTC `IsoDateTime` using the provided
+    /// `TimeZoneProvider` and the system's current UTC offset.
+    pub fn from_utc_with_provider(
+        utc: &IsoDateTime,
+        provider: &impl TimeZoneProvider,
+    ) -> TemporalResult<Self> {
+        let offset_seconds = system_offset_seconds(&get_system_nanoseconds()?, provider)?;
+        Ok(Self {
+            inner: offset_datetime(utc, i64::from(offset_seconds)),
+            offset_seconds,
+        })
+    }
+
+    /// Returns the local `IsoDateTime`.
+    #[inline]
+    pub fn datetime(&self) -> IsoDateTime {
+        self.inner
+    }
+
+    /// Returns the UTC offset of this `LocalDateTime` in seconds.
+    #[inline]
+    pub fn local_offset_seconds(&self) -> i32 {
+        self.offset_seconds
+    }
+
+    /// Returns this `LocalDateTime` as a UTC `IsoDateTime`.
+    #[inline]
+    pub fn to_utc(&self) -> IsoDateTime {
+        offset_datetime(&self.inner, -i64::from(self.offset_seconds))
+    }
+}
+
+#[cfg(all(feature = "sys", feature = "compiled_data"))]
+impl LocalDateTime {
+    /// Returns the current `LocalDateTime`.
+    ///
+    /// Enable with the `compiled_data` and `sys` feature flags.
+    pub fn now() -> TemporalResult<Self> {
+        let provider = TZ_PROVIDER
+            .lock()
+            .map_err(|_| TemporalError::general("Unable to acquire lock"))?;
+        Self::now_with_provider(&*provider)
+    }
+
+    /// Creates a `LocalDateTime` from a UTC `IsoDateTime` using the system's
+    /// current UTC offset.
+    ///
+    /// Enable with the `compiled_data` and `sys` feature flags.
+    pub fn from_utc(utc: &IsoDateTime) -> TemporalResult<Self> {
+        let provider = TZ_PROVIDER
+            .lock()
+            .map_err(|_| TemporalError::general("Unable to acquire lock"))?;
+        Self::from_utc_with_provider(utc, &*provider)
+    }
+}
+
+#[cfg(feature = "sys")]
+impl core::fmt::Display for LocalDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.offset_seconds < 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        // NOTE: Offsets are displayed with minute precision.
+        let minutes = self.offset_seconds.unsigned_abs() / 60;
+        let ixdtf = IxdtfStringBuilder::default()
+            .with_date(self.inner.date)
+            .with_time(self.inner.time, Precision::Auto)
+            .with_minute_offset(
+                sign,
+                (minutes / 60) as u8,
+                (minutes % 60) as u8,
+                DisplayOffset::Auto,
+            )
+            .build();
+        f.write_str(&ixdtf)
+    }
+}
+
+/// Returns the system time zone's current UTC offset in seconds.
+#[cfg(feature = "sys")]
+fn system_offset_seconds(
+    nanoseconds: &EpochNanoseconds,
+    provider: &impl TimeZoneProvider,
+) -> TemporalResult<i32> {
+    let offset = get_system_timezone()?.get_offset_nanos_for(nanoseconds.as_i128(), provider)?;
+    // NOTE: UTC offsets are always less than a day, so the cast is lossless.
+    Ok((offset / 1_000_000_000) as i32)
+}
+
+/// Returns `datetime` shifted by `seconds`.
+#[cfg(feature = "sys")]
+fn offset_datetime(datetime: &IsoDateTime, seconds: i64) -> IsoDateTime {
+    IsoDateTime::balance(
+        datetime.date.year,
+        datetime.date.month.into(),
+        datetime.date.day.into(),
+        datetime.time.hour.into(),
+        datetime.time.minute.into(),
+        i64::from(datetime.time.second) + seconds,
+        datetime.time.millisecond.into(),
+        datetime.time.microsecond.into(),
+        datetime.time.nanosecond.into(),
+    )
+}
+
+#[cfg(all(test, feature = "sys"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{get_system_timezone, LocalDateTime};
+    use crate::iso::{IsoDate, IsoDateTime, IsoTime};
+    use crate::provider::{TimeZoneOffset, TimeZoneProvider, TransitionDirection};
+    use crate::unix_time::EpochNanoseconds;
+    use crate::{TemporalResult, TimeZone};
+
+    /// A provider where every named time zone is fixed at UTC+02:00.
+    struct FixedOffsetProvider;
+
+    impl TimeZoneProvider for FixedOffsetProvider {
+        fn check_identifier(&self, _: &str) -> bool {
+            true
+        }
+
+        fn get_named_tz_epoch_nanoseconds(
+            &self,
+            _: &str,
+            local_datetime: IsoDateTime,
+        ) -> TemporalResult<Vec<EpochNanoseconds>> {
+            let ns = local_datetime.as_nanoseconds()?.as_i128() - 7_200_000_000_000;
+            Ok(alloc::vec![EpochNanoseconds::try_from(ns)?])
+        }
+
+        fn get_named_tz_offset_nanoseconds(
+            &self,
+            _: &str,
+            _: i128,
+        ) -> TemporalResult<TimeZoneOffset> {
+            Ok(TimeZoneOffset {
+                transition_epoch: None,
+                offset: 7_200,
+            })
+        }
+
+        fn get_named_tz_transition(
+            &self,
+            _: &str,
+            _: i128,
+            _: TransitionDirection,
+        ) -> TemporalResult<Option<EpochNanoseconds>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn local_datetime_with_provider() {
+        let provider = FixedOffsetProvider;
+        let local = LocalDateTime::now_with_provider(&provider).unwrap();
+        // Offset time zones do not consult the provider.
+        if let TimeZone::IanaIdentifier(_) = get_system_timezone().unwrap() {
+            assert_eq!(local.local_offset_seconds(), 7_200);
+        }
+
+        let utc = local.to_utc();
+        let difference = local.datetime().to_unix_timestamp_nanos().unwrap()
+            - utc.to_unix_timestamp_nanos().unwrap();
+        assert_eq!(
+            difference,
+            i128::from(local.local_offset_seconds()) * 1_000_000_000
+        );
+        let round_trip = LocalDateTime::from_utc_with_provider(&utc, &provider).unwrap();
+        assert_eq!(round_trip.to_utc(), utc);
+
+        let utc = IsoDateTime::new_unchecked(
+            IsoDate::new_unchecked(2024, 12, 31),
+            IsoTime::new_unchecked(23, 30, 0, 0, 0, 0),
+        );
+        let local = LocalDateTime::from_utc_with_provider(&utc, &provider).unwrap();
+        assert_eq!(local.to_utc(), utc);
+        if local.local_offset_seconds() == 7_200 {
+            assert_eq!(local.datetime().date, IsoDate::new_unchecked(2025, 1, 1));
+            assert_eq!(
+                local.datetime().time,
+                IsoTime::new_unchecked(1, 30, 0, 0, 0, 0)
+            );
+        }
+    }
+
+    #[cfg(feature = "compiled_data")]
+    #[test]
+    fn local_datetime_to_utc() {
+        let local = LocalDateTime::now().unwrap();
+        let utc = local.to_utc();
+        let difference = local.datetime().to_unix_timestamp_nanos().unwrap()
+            - utc.to_unix_timestamp_nanos().unwrap();
+        assert_eq!(
+            difference,
+            i128::from(local.local_offset_seconds()) * 1_000_000_000
+        );
+
+        let round_trip = LocalDateTime::from_utc(&utc).unwrap();
+        assert_eq!(round_trip.to_utc(), utc);
+    }
+}
@@ -1,13 +1,18 @@
 //! Utility date and time equations for Temporal
+//!
+//! The public functions in this module are ISO 8601 helpers that may be
+//! useful when implementing calendar logic outside of `temporal_rs`. All
+//! epoch time values are in milliseconds.
 
 use alloc::format;
 use alloc::string::String;
 
 use crate::MS_PER_DAY;
 
+pub(crate) mod algorithms;
 mod neri_schneider;
 
-pub(crate) use neri_schneider::epoch_days_from_gregorian_date;
+pub(crate) use neri_schneider::{epoch_days_from_gregorian_date, ymd_from_epoch_days};
 
 // NOTE: Potentially add more of tests.
 
@@ -18,8 +23,12 @@ pub(crate) const MS_PER_MINUTE: i64 = 60_000;
 
 /// `EpochDaysToEpochMS`
 ///
+/// Returns the epoch milliseconds for the provided epoch days and
+/// milliseconds into the day.
+///
 /// Functionally the same as Date's abstract operation `MakeDate`
-pub(crate) fn epoch_days_to_epoch_ms(day: i64, time: i64) -> i64 {
+#[inline]
+pub fn epoch_days_to_epoch_ms(day: i64, time: i64) -> i64 {
     (day * MS_PER_DAY as i64) + time
 }
 
@@ -27,7 +36,7 @@ pub(crate) fn epoch_days_to_epoch_ms(day: i64, time: i64) -> i64 {
 ///
 /// returns a String representation of y suitable for inclusion in an ISO 8601 string
 pub(crate) fn pad_iso_year(year: i32) -> String {
-    if (0..9999).contains(&year) {
+    if (0..=9999).contains(&year) {
         return format!("{:04}", year);
     }
     let year_sign = if year > 0 { "+" } else { "-" };
@@ -49,6 +58,7 @@ pub(crate) fn epoch_ms_to_ms_in_day(t: i64) -> u32 {
 }
 
 /// Mathematically determine the days in a year.
+#[cfg(feature = "tzdb")]
 pub(crate) fn mathematical_days_in_year(y: i32) -> i32 {
     if y % 4 != 0 {
         365
@@ -63,28 +73,49 @@ pub(crate) fn mathematical_days_in_year(y: i32) -> i32 {
     }
 }
 
-pub(crate) fn epoch_time_to_epoch_year(t: i64) -> i32 {
+/// Returns the ISO year for the provided epoch milliseconds.
+#[inline]
+pub fn epoch_time_to_epoch_year(t: i64) -> i32 {
     let epoch_days = epoch_ms_to_epoch_days(t);
     let (rata_die, shift_constant) = neri_schneider::rata_die_for_epoch_days(epoch_days);
     neri_schneider::year(rata_die, shift_constant)
 }
 
+/// Returns the ISO month, 1 based, for the provided epoch milliseconds.
+#[inline]
+pub fn epoch_time_to_month_in_year(t: i64) -> u8 {
+    ymd_from_epoch_milliseconds(t).1
+}
+
+/// Returns the ISO day of the month for the provided epoch milliseconds.
+#[inline]
+pub fn epoch_time_to_date(t: i64) -> u8 {
+    ymd_from_epoch_milliseconds(t).2
+}
+
+/// Returns whether the provided ISO year is a leap year.
+#[inline]
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
 /// Returns either 1 (true) or 0 (false)
+#[cfg(feature = "tzdb")]
 pub(crate) fn mathematical_in_leap_year(t: i64) -> i32 {
     mathematical_days_in_year(epoch_time_to_epoch_year(t)) - 365
 }
 
 /// Returns the epoch day number for a given year.
-pub(crate) fn epoch_days_for_year(y: i32) -> i32 {
+///
+/// NOTE: The calculation is done in `i64` as the epoch seconds and milliseconds
+/// derived from this value will overflow an `i32` for years past 2038.
+#[cfg(any(test, feature = "tzdb"))]
+pub(crate) fn epoch_days_for_year(y: i32) -> i64 {
+    let y = i64::from(y);
     365 * (y - 1970) + (y - 1969).div_euclid(4) - (y - 1901).div_euclid(100)
         + (y - 1601).div_euclid(400)
 }
 
-// TODO: test limits
-pub(crate) fn epoch_time_for_year(y: i32) -> i64 {
-    i64::from(MS_PER_DAY) * i64::from(epoch_days_for_year(y))
-}
-
 pub(crate) const fn epoch_ms_to_epoch_days(ms: i64) -> i32 {
     (ms.div_euclid(MS_PER_DAY as i64)) as i32
 }
@@ -122,7 +153,9 @@ pub(crate) fn epoch_ms_to_month_in_year(t: i64) -> u8 {
 
 #[cfg(feature = "tzdb")]
 pub(crate) fn epoch_time_to_day_in_year(t: i64) -> i32 {
-    epoch_time_to_day_number(t) - (epoch_days_for_year(epoch_time_to_epoch_year(t)))
+    // NOTE: The difference is always within 0..=365, so the cast is safe.
+    (i64::from(epoch_time_to_day_number(t)) - epoch_days_for_year(epoch_time_to_epoch_year(t)))
+        as i32
 }
 
 #[cfg(feature = "tzdb")]
@@ -149,13 +182,20 @@ pub(crate) fn epoch_seconds_to_day_of_month(t: i64) -> u16 {
 
 /// 12.2.31 `ISODaysInMonth ( year, month )`
 ///
+/// Returns the number of days in the provided ISO year and month.
+///
 /// NOTE: month is 1 based
-pub(crate) fn iso_days_in_month(year: i32, month: u8) -> u8 {
+///
+/// # Panics
+///
+/// Panics if `month` is not within 1..=12.
+#[inline]
+pub const fn iso_days_in_month(year: i32, month: u8) -> u8 {
     match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
-        2 => 28 + mathematical_in_leap_year(epoch_time_for_year(year)) as u8,
-        _ => unreachable!("ISODaysInMonth panicking is an implementation error."),
+        2 => 28 + is_leap_year(year) as u8,
+        _ => panic!("month must be within 1..=12"),
     }
 }
 
@@ -166,3 +206,57 @@ pub(crate) fn iso_days_in_month(year: i32, month: u8) -> u8 {
 // 12.2.40 `ToISODayOfWeek ( year, month, day )`
 
 // ==== End Calendar Equations ====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2025));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-100));
+        assert!(is_leap_year(-400));
+    }
+
+    #[test]
+    fn days_in_month() {
+        assert_eq!(iso_days_in_month(1900, 2), 28);
+        assert_eq!(iso_days_in_month(2000, 2), 29);
+        assert_eq!(iso_days_in_month(-400, 2), 29);
+        assert_eq!(iso_days_in_month(-1, 2), 28);
+        assert_eq!(iso_days_in_month(2000, 4), 30);
+        assert_eq!(iso_days_in_month(2000, 12), 31);
+    }
+
+    #[test]
+    #[should_panic(expected = "month must be within 1..=12")]
+    fn days_in_invalid_month() {
+        iso_days_in_month(2000, 13);
+    }
+
+    #[test]
+    fn epoch_time_components() {
+        // 1900-03-01T00:00:00Z
+        let t = epoch_days_to_epoch_ms(-25508, 0);
+        assert_eq!(epoch_time_to_epoch_year(t), 1900);
+        assert_eq!(epoch_time_to_month_in_year(t), 3);
+        assert_eq!(epoch_time_to_date(t), 1);
+
+        // 2000-02-29T12:00:00Z
+        let t = epoch_days_to_epoch_ms(11016, 43_200_000);
+        assert_eq!(epoch_time_to_epoch_year(t), 2000);
+        assert_eq!(epoch_time_to_month_in_year(t), 2);
+        assert_eq!(epoch_time_to_date(t), 29);
+
+        // -000001-12-31T00:00:00Z
+        let t = epoch_days_to_epoch_ms(epoch_days_from_gregorian_date(-1, 12, 31), 0);
+        assert_eq!(epoch_time_to_epoch_year(t), -1);
+        assert_eq!(epoch_time_to_month_in_year(t), 12);
+        assert_eq!(epoch_time_to_date(t), 31);
+    }
+}
@@ -0,0 +1,31 @@
+//! Higher-level Gregorian calendar algorithms
+//!
+//! This module contains calendar computations that are not part of the
+//! Temporal specification, but are built on top of the ISO calendar.
+
+/// Returns the month and day of Easter Sunday in the provided proleptic
+/// Gregorian year.
+///
+/// This is the Anonymous Gregorian algorithm, also known as the
+/// Meeus/Jones/Butcher algorithm. Euclidean division is used throughout so
+/// that the calculation holds for non-positive years.
+pub const fn gregorian_easter(year: i32) -> (u8, u8) {
+    // Golden number minus one, i.e. the year's position in the 19-year Metonic cycle.
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    // Epact related value, i.e. days from March 21st to the Paschal full moon.
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    // Days from the Paschal full moon to the following Sunday.
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    (month as u8, day as u8)
+}
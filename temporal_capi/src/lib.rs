@@ -11,6 +11,11 @@
 //! across compatible semver versions. In contrast, the `extern "C"` APIs and all the language bindings are stable within
 //! the same major semver version.
 //!
+//! The `extern "C"` APIs are prefixed with `temporal_rs_`, and plain records such as `IsoDate`,
+//! `IsoTime`, and `IsoDateTime` are passed by value with a C-compatible layout. Fallible APIs
+//! return a result struct containing either the value or a `TemporalError` rather than panicking
+//! across the FFI boundary.
+//!
 //! [`temporal_rs`]: http://crates.io/crates/temporal_rs
 
 #![no_std]
@@ -0,0 +1,49 @@
+//! Compiles and runs `tests/ffi/temporal_ffi_test.c` against a static library
+//! built with the `ffi` feature.
+//!
+//! Run with `cargo test --features ffi --test ffi`. A C compiler is required, which
+//! may be set with the `CC` environment variable and otherwise defaults to `cc`.
+
+#![cfg(all(feature = "ffi", unix))]
+
+use std::{env, path::Path, process::Command};
+
+#[test]
+fn c_test_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    let status = Command::new(env!("CARGO"))
+        .current_dir(manifest_dir)
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "staticlib",
+        ])
+        .arg("--target-dir")
+        .arg(out_dir.join("target"))
+        .status()
+        .expect("cargo must be available to build the static library");
+    assert!(status.success(), "building the static library failed");
+
+    let program = out_dir.join("temporal_ffi_test");
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .arg(manifest_dir.join("tests/ffi/temporal_ffi_test.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(out_dir.join("target/debug"))
+        .args(["-ltemporal_rs", "-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .expect("a C compiler must be available to build the test program");
+    assert!(status.success(), "compiling the C test program failed");
+
+    let status = Command::new(&program)
+        .status()
+        .expect("the C test program must run");
+    assert!(status.success(), "the C test program failed");
+}
@@ -0,0 +1,74 @@
+/* Exercises the C interface of the `ffi` feature. Run through `tests/ffi.rs`. */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "temporal_ffi.h"
+
+#define CHECK(condition)                                                   \
+    do {                                                                   \
+        if (!(condition)) {                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,         \
+                    __LINE__, #condition);                                 \
+            return EXIT_FAILURE;                                           \
+        }                                                                  \
+    } while (0)
+
+int main(void) {
+    TemporalDateC date;
+    CHECK(temporal_date_new(2024, 2, 29, &date) == TEMPORAL_OK);
+    CHECK(date.year == 2024 && date.month == 2 && date.day == 29);
+    CHECK(temporal_date_new(2023, 2, 29, &date) == TEMPORAL_ERROR_RANGE);
+    CHECK(temporal_date_new(2024, 2, 29, NULL) == TEMPORAL_ERROR_NULL_POINTER);
+
+    TemporalDateC start = {2024, 1, 31};
+    TemporalDateC end;
+    CHECK(temporal_date_add_days(start, 30, &end) == TEMPORAL_OK);
+    CHECK(end.year == 2024 && end.month == 3 && end.day == 1);
+    CHECK(temporal_date_add_days(start, -31, &end) == TEMPORAL_OK);
+    CHECK(end.year == 2023 && end.month == 12 && end.day == 31);
+    CHECK(temporal_date_add_days(start, 200000000, &end) == TEMPORAL_ERROR_RANGE);
+
+    int64_t days = 0;
+    TemporalDateC later = {2025, 1, 31};
+    CHECK(temporal_date_days_between(start, later, &days) == TEMPORAL_OK);
+    CHECK(days == 366);
+    CHECK(temporal_date_days_between(later, start, &days) == TEMPORAL_OK);
+    CHECK(days == -366);
+
+    char buffer[32];
+    size_t written = 0;
+    TemporalDateC expanded = {-500, 6, 30};
+    CHECK(temporal_date_format(start, buffer, sizeof(buffer), &written) == TEMPORAL_OK);
+    CHECK(strcmp(buffer, "2024-01-31") == 0 && written == 10);
+    CHECK(temporal_date_format(expanded, buffer, sizeof(buffer), &written) == TEMPORAL_OK);
+    CHECK(strcmp(buffer, "-000500-06-30") == 0 && written == 13);
+    CHECK(temporal_date_format(start, buffer, 10, &written) == TEMPORAL_ERROR_BUFFER_TOO_SMALL);
+    CHECK(written == 10);
+
+    CHECK(temporal_date_parse("2024-03-15", &date) == TEMPORAL_OK);
+    CHECK(date.year == 2024 && date.month == 3 && date.day == 15);
+    CHECK(temporal_date_parse("+999999-01-01", &date) == TEMPORAL_ERROR_RANGE);
+    CHECK(temporal_date_parse("2024-3-15", &date) == TEMPORAL_ERROR_SYNTAX);
+    CHECK(temporal_date_parse(NULL, &date) == TEMPORAL_ERROR_NULL_POINTER);
+
+    TemporalTimeC time;
+    CHECK(temporal_time_new(23, 59, 59, 999, 999, 999, &time) == TEMPORAL_OK);
+    CHECK(time.hour == 23 && time.nanosecond == 999);
+    CHECK(temporal_time_new(24, 0, 0, 0, 0, 0, &time) == TEMPORAL_ERROR_RANGE);
+
+    TemporalDateTimeC date_time;
+    int64_t millis = 0;
+    CHECK(temporal_datetime_from_epoch_millis(1710513000123, &date_time) == TEMPORAL_OK);
+    CHECK(date_time.date.year == 2024 && date_time.date.month == 3 && date_time.date.day == 15);
+    CHECK(date_time.time.hour == 14 && date_time.time.minute == 30);
+    CHECK(date_time.time.millisecond == 123);
+    CHECK(temporal_datetime_to_epoch_millis(date_time, &millis) == TEMPORAL_OK);
+    CHECK(millis == 1710513000123);
+    CHECK(temporal_datetime_from_epoch_millis(-1, &date_time) == TEMPORAL_OK);
+    CHECK(date_time.date.year == 1969 && date_time.time.millisecond == 999);
+    CHECK(temporal_datetime_from_epoch_millis(INT64_MAX, &date_time) == TEMPORAL_ERROR_RANGE);
+
+    return EXIT_SUCCESS;
+}